@@ -0,0 +1,63 @@
+// backend/src/api/debug_routes.rs
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::Level;
+
+use super::errors::{api_error, ApiError};
+use super::game_routes::require_dev_mode;
+use crate::log_buffer::{self, LogEntry};
+use crate::orchestrator::GameOrchestrator;
+
+/// Query parameters for the debug log endpoint
+#[derive(Debug, Deserialize)]
+pub struct LogsQuery {
+    /// Minimum severity to include (trace, debug, info, warn, error); defaults to trace
+    #[serde(default)]
+    pub level: Option<String>,
+}
+
+/// Response for the debug log endpoint
+#[derive(Debug, Serialize)]
+pub struct LogsResponse {
+    pub level: String,
+    /// Buffered log lines, oldest first
+    pub entries: Vec<LogEntry>,
+}
+
+/// GET /api/debug/logs - Recent log lines from the in-memory buffer (dev mode only)
+pub async fn get_debug_logs(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Query(query): Query<LogsQuery>,
+) -> Result<Json<LogsResponse>, ApiError> {
+    require_dev_mode(&orchestrator)?;
+
+    let level = match query.level.as_deref() {
+        Some(level) => level.parse::<Level>().map_err(|_| {
+            api_error(
+                StatusCode::BAD_REQUEST,
+                "INVALID_LEVEL",
+                format!("Unknown log level: {}", level),
+            )
+        })?,
+        None => Level::TRACE,
+    };
+
+    let buffer = log_buffer::global().ok_or_else(|| {
+        api_error(
+            StatusCode::NOT_FOUND,
+            "LOG_BUFFER_DISABLED",
+            "Log buffer is not installed",
+        )
+    })?;
+
+    Ok(Json(LogsResponse {
+        level: level.to_string(),
+        entries: buffer.entries(level),
+    }))
+}
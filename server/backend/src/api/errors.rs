@@ -0,0 +1,87 @@
+// backend/src/api/errors.rs
+
+use axum::{
+    async_trait,
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    http::StatusCode,
+    Json,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Structured error body returned by every API endpoint
+#[derive(Debug, Serialize)]
+pub struct ErrorResponse {
+    /// Stable machine-readable error code (e.g. `GAME_NOT_FOUND`)
+    pub code: String,
+    /// Human-readable description
+    pub message: String,
+    /// Optional structured context (e.g. a list of validation issues)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+}
+
+/// Error type returned by API handlers
+pub type ApiError = (StatusCode, Json<ErrorResponse>);
+
+/// Build an API error with the given status, code and message
+pub fn api_error(status: StatusCode, code: &str, message: impl Into<String>) -> ApiError {
+    (
+        status,
+        Json(ErrorResponse {
+            code: code.to_string(),
+            message: message.into(),
+            details: None,
+        }),
+    )
+}
+
+/// Build an API error carrying structured details
+pub fn api_error_with_details(
+    status: StatusCode,
+    code: &str,
+    message: impl Into<String>,
+    details: serde_json::Value,
+) -> ApiError {
+    let (status, Json(mut body)) = api_error(status, code, message);
+    body.details = Some(details);
+    (status, Json(body))
+}
+
+/// JSON body extractor that reports rejections as an `ErrorResponse`
+/// instead of axum's default plain-text body
+pub struct ApiJson<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for ApiJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match Json::<T>::from_request(req, state).await {
+            Ok(Json(value)) => Ok(ApiJson(value)),
+            Err(rejection) => Err(json_rejection_error(rejection)),
+        }
+    }
+}
+
+fn json_rejection_error(rejection: JsonRejection) -> ApiError {
+    let status = rejection.status();
+    let code = match &rejection {
+        JsonRejection::JsonSyntaxError(_) => "MALFORMED_JSON",
+        JsonRejection::JsonDataError(_) => "INVALID_JSON_DATA",
+        JsonRejection::MissingJsonContentType(_) => "MISSING_JSON_CONTENT_TYPE",
+        _ if status == StatusCode::PAYLOAD_TOO_LARGE => "PAYLOAD_TOO_LARGE",
+        _ => "INVALID_REQUEST_BODY",
+    };
+
+    tracing::warn!(status = %status, code = code, "API: Rejected request body");
+    api_error(status, code, rejection.body_text())
+}
+
+/// Fallback for unknown routes
+pub async fn route_not_found() -> ApiError {
+    api_error(StatusCode::NOT_FOUND, "ROUTE_NOT_FOUND", "No such endpoint")
+}
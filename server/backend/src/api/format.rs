@@ -0,0 +1,83 @@
+// backend/src/api/format.rs
+
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Query},
+    http::{header, request::Parts, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+
+use super::errors::api_error;
+
+#[derive(Debug, Default, Deserialize)]
+struct PrettyQuery {
+    #[serde(default)]
+    pretty: bool,
+}
+
+/// JSON layout requested by the client via `?pretty=true`
+///
+/// Compact by default to save bandwidth; an unparseable `pretty` value also means compact.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonFormat {
+    pub pretty: bool,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for JsonFormat
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let pretty = Query::<PrettyQuery>::try_from_uri(&parts.uri)
+            .map(|Query(query)| query.pretty)
+            .unwrap_or(false);
+        Ok(Self { pretty })
+    }
+}
+
+impl JsonFormat {
+    /// Wrap a response body in the requested layout
+    pub fn json<T>(self, value: T) -> FormattedJson<T> {
+        FormattedJson {
+            value,
+            pretty: self.pretty,
+        }
+    }
+}
+
+/// JSON response serialized compact or pretty-printed
+pub struct FormattedJson<T> {
+    value: T,
+    pretty: bool,
+}
+
+impl<T: Serialize> IntoResponse for FormattedJson<T> {
+    fn into_response(self) -> Response {
+        if !self.pretty {
+            return Json(self.value).into_response();
+        }
+
+        match serde_json::to_vec_pretty(&self.value) {
+            Ok(body) => (
+                [(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static("application/json"),
+                )],
+                body,
+            )
+                .into_response(),
+            Err(e) => api_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "SERIALIZATION_FAILED",
+                format!("Failed to serialize response: {}", e),
+            )
+            .into_response(),
+        }
+    }
+}
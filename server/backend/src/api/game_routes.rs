@@ -1,15 +1,27 @@
 // backend/src/api/game_routes.rs
 
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
     Json,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use uuid::Uuid;
 
-use crate::game::GameStateJS;
-use crate::orchestrator::{GameInitiation, GameOrchestrator, GameStatusResponse};
+use super::admin_routes::require_admin;
+use super::errors::{api_error, api_error_with_details, ApiError, ApiJson};
+use super::format::{FormattedJson, JsonFormat};
+use crate::game::{
+    convert_card_to_js, convert_indexes_to_js_cards, draw_multiple_cards_once, force_reshuffle,
+    get_initial_hands, pass_turn, playable_cards, set_active_color, validate_game_params,
+    validate_game_params_all, CardHistogram, CardOrigin, Color, Direction, DiscardVisibility,
+    GameReplay, GameState, GameStateJS, GameStateRaw, OpponentInfo, PlayerId,
+};
+use crate::orchestrator::{
+    GameInitiation, GameOptions, GameOrchestrator, GameStatus, GameStatusResponse, ProofEstimate,
+    SeedSource, MAX_GAME_NAME_LEN,
+};
 // use zunnogame_script::ProofOutput;
 
 /// Request body for starting a new game
@@ -17,13 +29,226 @@ use crate::orchestrator::{GameInitiation, GameOrchestrator, GameStatusResponse};
 pub struct StartGameRequest {
     pub num_players: u8,
     pub cards_per_player: u8,
+    /// Optional human-readable label
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Deck theme identifier, defaults to "classic"
+    #[serde(default)]
+    pub theme: Option<String>,
+    /// Salt per-player hand commitments, defaults to true
+    #[serde(default)]
+    pub salted_hands: Option<bool>,
+    /// Build the per-card Merkle tree, defaults to true; false gives a cheaper deal-only proof
+    #[serde(default)]
+    pub build_merkle: Option<bool>,
+    /// Reject out-of-turn moves, defaults to false
+    #[serde(default)]
+    pub enforce_turns: Option<bool>,
+    /// Cards a player may draw per turn when turns are enforced (unlimited when omitted)
+    #[serde(default)]
+    pub max_draws_per_turn: Option<u8>,
+    /// Require a draw before passing, defaults to false
+    #[serde(default)]
+    pub require_draw_before_pass: Option<bool>,
+    /// `os` deals from local randomness with no proof, for offline play; defaults to `vrf`
+    #[serde(default)]
+    pub seed_source: Option<SeedSource>,
+    /// `top_only` limits the discard endpoint to the top card; defaults to `full`
+    #[serde(default)]
+    pub discard_visibility: Option<DiscardVisibility>,
+    /// Seat that takes the first turn; defaults to the seed value modulo `num_players`
+    #[serde(default)]
+    pub starting_player: Option<PlayerId>,
+    /// Dealt game whose VRF value seeds this one as `keccak(master_value || session_id)`,
+    /// instead of a new VRF request
+    #[serde(default)]
+    pub master_session: Option<String>,
+}
+
+/// Upper bound on cards drawn by a single request
+pub const MAX_DRAW_COUNT: u8 = 20;
+
+/// Header carrying the seat token returned when the game was started
+pub const PLAYER_TOKEN_HEADER: &str = "x-player-token";
+
+/// Request body for drawing cards
+#[derive(Debug, Deserialize)]
+pub struct DrawCardRequest {
+    #[serde(default = "default_draw_count")]
+    pub count: u8,
+    /// Client-chosen id making retries of this draw safe
+    #[serde(default)]
+    pub action_id: Option<Uuid>,
+}
+
+fn default_draw_count() -> u8 {
+    1
+}
+
+/// Response for drawing cards
+#[derive(Debug, Serialize)]
+pub struct DrawCardResponse {
+    pub session_id: String,
+    pub player_id: PlayerId,
+    /// JavaScript card strings in draw order
+    pub cards: Vec<String>,
+    pub draw_pile_count: usize,
+    pub message: String,
+}
+
+/// Query parameters for proof cost estimates
+#[derive(Debug, Deserialize)]
+pub struct EstimateQuery {
+    pub num_players: u8,
+    pub cards_per_player: u8,
+}
+
+/// Query parameters for the discard pile endpoint
+#[derive(Debug, Deserialize)]
+pub struct DiscardQuery {
+    #[serde(default = "default_discard_count")]
+    pub count: usize,
+}
+
+fn default_discard_count() -> usize {
+    1
+}
+
+/// Response for discard pile queries
+#[derive(Debug, Serialize)]
+pub struct DiscardPileResponse {
+    pub session_id: String,
+    /// Most recent discards in pile order (top card last)
+    pub cards: Vec<String>,
+    /// Total number of cards in the discard pile
+    pub pile_size: usize,
+}
+
+/// Response for the debug reshuffle endpoint
+#[derive(Debug, Serialize)]
+pub struct ReshuffleResponse {
+    pub session_id: String,
+    pub reshuffle_count: u32,
+    pub draw_pile_count: usize,
+    pub discard_pile_count: usize,
+}
+
+/// Card encoding used in responses
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CardFormat {
+    /// JavaScript card strings (e.g. "5R")
+    #[default]
+    Js,
+    /// Raw `u8` deck indices
+    Raw,
+}
+
+/// Query parameters for game state and hand endpoints
+#[derive(Debug, Default, Deserialize)]
+pub struct GameStateQuery {
+    #[serde(default)]
+    pub format: CardFormat,
+    /// Add the proof CID and pins to the game state response
+    #[serde(default)]
+    pub include_proof: bool,
+    /// Add the deal animation timing and card-by-card deal order
+    #[serde(default)]
+    pub include_deal: bool,
+}
+
+/// Game state in the requested card format
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum GameStateView {
+    Js(GameStateJS),
+    Raw(GameStateRaw),
+}
+
+impl GameStateView {
+    pub fn new(game_state: &GameState, format: CardFormat) -> Self {
+        match format {
+            CardFormat::Js => GameStateView::Js(GameStateJS::from(game_state)),
+            CardFormat::Raw => GameStateView::Raw(GameStateRaw::from(game_state)),
+        }
+    }
+}
+
+/// Cards in the requested card format
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum CardList {
+    Js(Vec<String>),
+    Raw(Vec<u8>),
+}
+
+impl CardList {
+    pub fn new(cards: Vec<u8>, format: CardFormat) -> Self {
+        match format {
+            CardFormat::Js => CardList::Js(convert_indexes_to_js_cards(&cards)),
+            CardFormat::Raw => CardList::Raw(cards),
+        }
+    }
+}
+
+/// A single card in the requested card format
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum CardValue {
+    Js(String),
+    Raw(u8),
+}
+
+impl CardValue {
+    pub fn new(card: u8, format: CardFormat) -> Self {
+        match format {
+            CardFormat::Js => CardValue::Js(convert_card_to_js(card)),
+            CardFormat::Raw => CardValue::Raw(card),
+        }
+    }
+}
+
+/// One card leaving the deck during the deal
+#[derive(Debug, Serialize)]
+pub struct DealEvent {
+    pub player_id: PlayerId,
+    pub card: CardValue,
+}
+
+/// What a frontend needs to animate the deal in sync with other clients
+#[derive(Debug, Serialize)]
+pub struct DealAnimation {
+    /// Suggested time per dealt card
+    pub deal_timing_ms: u64,
+    /// Every dealt card in the order it was dealt: round-robin, one per seat at a time
+    pub events: Vec<DealEvent>,
 }
 
 /// Response for get game state
 #[derive(Debug, Serialize)]
 pub struct GameStateApiResponse {
     pub session_id: String,
-    pub game_state: GameStateJS,
+    pub game_state: GameStateView,
+    /// Only with `include_proof=true`, and only for games that have a proof
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proof_cid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proof_pinned_by: Option<Vec<String>>,
+    /// Only with `include_deal=true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deal: Option<DealAnimation>,
+}
+
+/// Response for player hand queries
+#[derive(Debug, Serialize)]
+pub struct PlayerHandResponse {
+    pub session_id: String,
+    pub player_id: PlayerId,
+    pub cards: CardList,
+    /// Whether each card was dealt or drawn, position for position; omitted for games
+    /// dealt before origins were tracked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origins: Option<Vec<CardOrigin>>,
 }
 
 /// Response for proof retrieval
@@ -31,21 +256,182 @@ pub struct GameStateApiResponse {
 pub struct ProofResponse {
     pub session_id: String,
     pub proof_cid: String,
+    /// IPFS providers holding a pin of the proof
+    pub pinned_by: Vec<String>,
+}
+
+/// Query parameters for the opponents endpoint
+#[derive(Debug, Default, Deserialize)]
+pub struct OpponentsQuery {
+    /// Only the N seats on either side of the requester; every opponent when omitted
+    #[serde(default)]
+    pub window: Option<usize>,
+}
+
+/// Response for opponent hand counts, as seen from one seat
+#[derive(Debug, Serialize)]
+pub struct OpponentsResponse {
+    pub session_id: String,
+    pub player_id: PlayerId,
+    pub direction: Direction,
+    /// Other players in turn order, starting with the next seat; with `window`, the seats
+    /// just before the requester follow the upcoming ones
+    pub opponents: Vec<OpponentInfo>,
+}
+
+/// Response for playable card queries
+#[derive(Debug, Serialize)]
+pub struct PlayableCardsResponse {
+    pub session_id: String,
+    pub player_id: PlayerId,
+    /// Positions within the player's hand that can legally be played
+    pub positions: Vec<usize>,
+    /// JavaScript card strings for the playable positions
+    pub cards: Vec<String>,
+}
+
+/// Everything one player can legitimately see, for bots deciding a move in one call
+#[derive(Debug, Serialize)]
+pub struct PlayerInfoResponse {
+    pub session_id: String,
+    pub player_id: PlayerId,
+    /// The player's current hand
+    pub hand: CardList,
+    /// Whether each card in `hand` was dealt or drawn, when tracked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hand_origins: Option<Vec<CardOrigin>>,
+    /// Positions within `hand` that can legally be played
+    pub playable_positions: Vec<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_discard: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_color: Option<Color>,
+    /// Hand counts only; opponents' cards are never included
+    pub opponents: Vec<OpponentInfo>,
+    pub draw_pile_count: usize,
+    pub current_player: PlayerId,
+    pub is_my_turn: bool,
+    pub direction: Direction,
+    pub finished: bool,
 }
 
 /// POST /api/game/start - Initiate a new game
 pub async fn start_game(
     State(orchestrator): State<Arc<GameOrchestrator>>,
-    Json(req): Json<StartGameRequest>,
-) -> Result<Json<GameInitiation>, (StatusCode, String)> {
+    ApiJson(req): ApiJson<StartGameRequest>,
+) -> Result<Json<GameInitiation>, ApiError> {
     tracing::info!(
         num_players = req.num_players,
         cards_per_player = req.cards_per_player,
         "API: Start game request received"
     );
 
+    if let Err(issues) = validate_game_params_all(req.num_players, req.cards_per_player) {
+        let message = issues
+            .iter()
+            .map(|issue| issue.to_string())
+            .collect::<Vec<_>>()
+            .join("; ");
+        return Err(api_error_with_details(
+            StatusCode::BAD_REQUEST,
+            "INVALID_GAME_PARAMS",
+            message,
+            serde_json::json!({ "issues": issues }),
+        ));
+    }
+
+    let mut options = GameOptions::default();
+    if let Some(name) = req.name.as_deref().map(str::trim) {
+        if name.chars().count() > MAX_GAME_NAME_LEN {
+            return Err(api_error(
+                StatusCode::BAD_REQUEST,
+                "INVALID_GAME_NAME",
+                format!("Game name must be at most {} characters", MAX_GAME_NAME_LEN),
+            ));
+        }
+        if !name.is_empty() {
+            options.name = Some(name.to_string());
+        }
+    }
+    if let Some(theme) = req.theme {
+        options.theme = theme;
+    }
+    if let Some(salted_hands) = req.salted_hands {
+        options.salted_hands = salted_hands;
+    }
+    if let Some(build_merkle) = req.build_merkle {
+        options.build_merkle = build_merkle;
+    }
+    if let Some(enforce_turns) = req.enforce_turns {
+        options.enforce_turns = enforce_turns;
+    }
+    if let Some(limit) = req.max_draws_per_turn {
+        if limit == 0 {
+            return Err(api_error(
+                StatusCode::BAD_REQUEST,
+                "INVALID_DRAW_LIMIT",
+                "max_draws_per_turn must be at least 1",
+            ));
+        }
+        options.max_draws_per_turn = Some(limit);
+    }
+    if let Some(require_draw) = req.require_draw_before_pass {
+        options.require_draw_before_pass = require_draw;
+    }
+    if let Some(seed_source) = req.seed_source {
+        if seed_source == SeedSource::Os && orchestrator.config().tournament_seed.is_some() {
+            return Err(api_error(
+                StatusCode::BAD_REQUEST,
+                "SEED_SOURCE_UNAVAILABLE",
+                "seed_source=os is not allowed while a tournament seed is configured",
+            ));
+        }
+        options.seed_source = seed_source;
+    }
+    if let Some(discard_visibility) = req.discard_visibility {
+        options.discard_visibility = discard_visibility;
+    }
+    if let Some(starting_player) = req.starting_player {
+        if starting_player >= req.num_players {
+            return Err(api_error(
+                StatusCode::BAD_REQUEST,
+                "INVALID_STARTING_PLAYER",
+                format!(
+                    "starting_player must be a seat between 0 and {}",
+                    req.num_players - 1
+                ),
+            ));
+        }
+        options.starting_player = Some(starting_player);
+    }
+    if let Some(master_session) = req.master_session {
+        if options.seed_source != SeedSource::Vrf || orchestrator.config().tournament_seed.is_some()
+        {
+            return Err(api_error(
+                StatusCode::BAD_REQUEST,
+                "SEED_SOURCE_UNAVAILABLE",
+                "master_session needs seed_source=vrf and no tournament seed",
+            ));
+        }
+        let can_be_master = orchestrator
+            .get_game_state(&master_session)
+            .await
+            .is_ok_and(|master| master.can_be_master());
+        if !can_be_master {
+            return Err(api_error(
+                StatusCode::BAD_REQUEST,
+                "INVALID_MASTER_SESSION",
+                format!(
+                    "Master session {} must be a dealt game seeded by VRF",
+                    master_session
+                ),
+            ));
+        }
+        options.master_session = Some(master_session);
+    }
+
     match orchestrator
-        .initiate_game(req.num_players, req.cards_per_player)
+        .initiate_game(req.num_players, req.cards_per_player, options)
         .await
     {
         Ok(initiation) => {
@@ -57,8 +443,9 @@ pub async fn start_game(
         }
         Err(e) => {
             tracing::error!(error = %e, "API: Failed to initiate game");
-            Err((
+            Err(api_error(
                 StatusCode::INTERNAL_SERVER_ERROR,
+                "GAME_START_FAILED",
                 format!("Failed to start game: {}", e),
             ))
         }
@@ -69,18 +456,23 @@ pub async fn start_game(
 pub async fn get_game_status(
     State(orchestrator): State<Arc<GameOrchestrator>>,
     Path(session_id): Path<String>,
-) -> Result<Json<GameStatusResponse>, (StatusCode, String)> {
+    format: JsonFormat,
+) -> Result<(StatusCode, FormattedJson<GameStatusResponse>), ApiError> {
     tracing::debug!(session_id = %session_id, "API: Get game status");
 
     match orchestrator.get_game_status(&session_id).await {
-        Ok(status) => Ok(Json(status)),
+        Ok(status) => Ok((game_status_code(&status.status), format.json(status))),
         Err(e) => {
             tracing::warn!(
                 session_id = %session_id,
                 error = %e,
                 "API: Game not found"
             );
-            Err((StatusCode::NOT_FOUND, format!("Game not found: {}", e)))
+            Err(api_error(
+                StatusCode::NOT_FOUND,
+                "GAME_NOT_FOUND",
+                format!("Game not found: {}", e),
+            ))
         }
     }
 }
@@ -89,24 +481,175 @@ pub async fn get_game_status(
 pub async fn get_game_state(
     State(orchestrator): State<Arc<GameOrchestrator>>,
     Path(session_id): Path<String>,
-) -> Result<Json<GameStateApiResponse>, (StatusCode, String)> {
+    Query(query): Query<GameStateQuery>,
+    format: JsonFormat,
+) -> Result<FormattedJson<GameStateApiResponse>, ApiError> {
     tracing::debug!(session_id = %session_id, "API: Get game state");
 
     match orchestrator.get_game_state(&session_id).await {
         Ok(game_state) => {
-            let game_state_js = GameStateJS::from(&game_state);
-            Ok(Json(GameStateApiResponse {
+            let has_proof =
+                query.include_proof && game_state.verifiable && game_state.proof_cid.is_some();
+            let deal = match query.include_deal {
+                true => Some(deal_animation(
+                    &game_state,
+                    query.format,
+                    orchestrator.config().deal_timing_ms,
+                )?),
+                false => None,
+            };
+
+            Ok(format.json(GameStateApiResponse {
                 session_id,
-                game_state: game_state_js,
+                game_state: GameStateView::new(&game_state, query.format),
+                proof_cid: game_state.proof_cid.clone().filter(|_| has_proof),
+                proof_pinned_by: has_proof.then(|| game_state.proof_pinned_by.clone()),
+                deal,
             }))
         }
+        Err(_) => Err(game_state_unavailable(&orchestrator, &session_id).await),
+    }
+}
+
+/// Response for forking a game
+#[derive(Debug, Serialize)]
+pub struct ForkGameResponse {
+    /// Session id of the new, independent game
+    pub session_id: String,
+    pub forked_from: String,
+    pub game_state: GameStateView,
+}
+
+/// POST /api/game/:session_id/fork - Copy the current state into a new session
+///
+/// The fork keeps the original seed metadata and proof but its moves never touch the
+/// original game, and vice versa.
+pub async fn fork_game(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path(session_id): Path<String>,
+    Query(query): Query<GameStateQuery>,
+) -> Result<(StatusCode, Json<ForkGameResponse>), ApiError> {
+    tracing::debug!(session_id = %session_id, "API: Fork game");
+
+    match orchestrator.fork_game(&session_id).await {
+        Ok((fork_id, game_state)) => Ok((
+            StatusCode::CREATED,
+            Json(ForkGameResponse {
+                session_id: fork_id,
+                forked_from: session_id,
+                game_state: GameStateView::new(&game_state, query.format),
+            }),
+        )),
+        Err(_) => Err(game_state_unavailable(&orchestrator, &session_id).await),
+    }
+}
+
+/// Response for starting a rematch
+#[derive(Debug, Serialize)]
+pub struct RematchResponse {
+    #[serde(flatten)]
+    pub initiation: GameInitiation,
+    pub rematch_of: String,
+}
+
+/// POST /api/game/:session_id/rematch - Start a new game with the same seats as a finished one
+///
+/// The rematch keeps `num_players`, `cards_per_player` and the table options, so every player
+/// keeps their seat number; the seed is fresh, so the deal differs. The finished game is left
+/// untouched.
+pub async fn rematch_game(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path(session_id): Path<String>,
+) -> Result<(StatusCode, Json<RematchResponse>), ApiError> {
+    tracing::debug!(session_id = %session_id, "API: Rematch");
+
+    let game_state = match orchestrator.get_game_state(&session_id).await {
+        Ok(game_state) => game_state,
+        Err(_) => return Err(game_state_unavailable(&orchestrator, &session_id).await),
+    };
+    if !game_state.is_finished() {
+        return Err(api_error(
+            StatusCode::CONFLICT,
+            "GAME_NOT_FINISHED",
+            format!("Game {} must be finished before a rematch", session_id),
+        ));
+    }
+    if game_state.tournament {
+        return Err(api_error(
+            StatusCode::CONFLICT,
+            "REMATCH_UNAVAILABLE",
+            "Tournament games are dealt from a fixed seed; a rematch would repeat the deal",
+        ));
+    }
+
+    let options = GameOptions::for_rematch(&session_id, &game_state);
+    match orchestrator
+        .initiate_game(
+            game_state.player_count() as u8,
+            game_state.cards_per_player,
+            options,
+        )
+        .await
+    {
+        Ok(initiation) => {
+            tracing::info!(
+                session_id = %initiation.session_id,
+                rematch_of = %session_id,
+                "API: Rematch initiated"
+            );
+            // Players already hold their seat tokens; don't hand them out again
+            Ok((
+                StatusCode::CREATED,
+                Json(RematchResponse {
+                    initiation: GameInitiation {
+                        player_tokens: Vec::new(),
+                        ..initiation
+                    },
+                    rematch_of: session_id,
+                }),
+            ))
+        }
+        Err(e) => {
+            tracing::error!(session_id = %session_id, error = %e, "API: Failed to start rematch");
+            Err(api_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "GAME_START_FAILED",
+                format!("Failed to start rematch: {}", e),
+            ))
+        }
+    }
+}
+
+/// Error for a state request on a game that has no playable state
+///
+/// Unknown sessions are 404; known games that are still in progress or failed are 409 with
+/// the current status in `details`, matching what the status endpoint reports.
+async fn game_state_unavailable(orchestrator: &GameOrchestrator, session_id: &str) -> ApiError {
+    match orchestrator.get_game_status(session_id).await {
+        Ok(status) => {
+            tracing::debug!(
+                session_id = %session_id,
+                status = ?status.status,
+                "API: Game state requested before game is ready"
+            );
+            api_error_with_details(
+                StatusCode::CONFLICT,
+                "GAME_NOT_READY",
+                format!("Game {} has no playable state yet", session_id),
+                serde_json::json!({ "status": status.status }),
+            )
+        }
         Err(e) => {
             tracing::warn!(
                 session_id = %session_id,
                 error = %e,
-                "API: Game not ready or not found"
+                "API: Game not found"
             );
-            Err((StatusCode::NOT_FOUND, format!("Game not ready: {}", e)))
+            api_error(
+                StatusCode::NOT_FOUND,
+                "GAME_NOT_FOUND",
+                format!("Game not found: {}", e),
+            )
         }
     }
 }
@@ -115,18 +658,30 @@ pub async fn get_game_state(
 pub async fn get_game_proof(
     State(orchestrator): State<Arc<GameOrchestrator>>,
     Path(session_id): Path<String>,
-) -> Result<Json<ProofResponse>, (StatusCode, String)> {
+    format: JsonFormat,
+) -> Result<FormattedJson<ProofResponse>, ApiError> {
     tracing::debug!(session_id = %session_id, "API: Get game proof");
 
     // Get game state to verify it's ready
     match orchestrator.get_game_state(&session_id).await {
+        Ok(game_state) if !game_state.verifiable => Err(api_error(
+            StatusCode::CONFLICT,
+            "GAME_UNVERIFIABLE",
+            format!(
+                "Game {} was dealt from OS randomness and has no proof",
+                session_id
+            ),
+        )),
         Ok(game_state) => {
-            let proof_cid = game_state.proof_cid.clone();
+            let Some(proof_cid) = game_state.proof_cid.clone() else {
+                return Err(proof_not_available(&session_id));
+            };
             let proof_response = ProofResponse {
                 session_id,
                 proof_cid,
+                pinned_by: game_state.proof_pinned_by.clone(),
             };
-            Ok(Json(proof_response))
+            Ok(format.json(proof_response))
         }
         Err(e) => {
             tracing::warn!(
@@ -134,7 +689,604 @@ pub async fn get_game_proof(
                 error = %e,
                 "API: Game not found"
             );
-            Err((StatusCode::NOT_FOUND, format!("Game not found: {}", e)))
+            Err(api_error(
+                StatusCode::NOT_FOUND,
+                "GAME_NOT_FOUND",
+                format!("Game not found: {}", e),
+            ))
+        }
+    }
+}
+
+/// GET /api/game/:session_id/player/:player_id/hand - Get a player's hand
+pub async fn get_player_hand(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path((session_id, player_id)): Path<(String, PlayerId)>,
+    Query(query): Query<GameStateQuery>,
+) -> Result<Json<PlayerHandResponse>, ApiError> {
+    tracing::debug!(
+        session_id = %session_id,
+        player_id = player_id,
+        "API: Get player hand"
+    );
+
+    let game_state = orchestrator
+        .get_game_state(&session_id)
+        .await
+        .map_err(|e| game_not_ready(&session_id, e))?;
+
+    match get_initial_hands(&game_state, player_id) {
+        Ok(hand) => Ok(Json(PlayerHandResponse {
+            origins: game_state.hand_origins(player_id).map(<[_]>::to_vec),
+            session_id,
+            player_id,
+            cards: CardList::new(hand, query.format),
+        })),
+        Err(e) => Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_PLAYER",
+            format!("Invalid player: {}", e),
+        )),
+    }
+}
+
+/// GET /api/game/:session_id/player/:player_id/playable - List legally playable cards
+pub async fn get_playable_cards(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path((session_id, player_id)): Path<(String, PlayerId)>,
+    headers: HeaderMap,
+) -> Result<Json<PlayableCardsResponse>, ApiError> {
+    tracing::debug!(
+        session_id = %session_id,
+        player_id = player_id,
+        "API: Get playable cards"
+    );
+
+    let game_state = orchestrator
+        .get_game_state(&session_id)
+        .await
+        .map_err(|e| game_not_ready(&session_id, e))?;
+    require_player(&game_state, player_id, &headers)?;
+
+    match playable_cards(&game_state, player_id) {
+        Ok(positions) => {
+            let hand = &game_state.player_hands[player_id as usize];
+            let cards = positions
+                .iter()
+                .map(|&position| convert_card_to_js(hand[position]))
+                .collect();
+
+            Ok(Json(PlayableCardsResponse {
+                session_id,
+                player_id,
+                positions,
+                cards,
+            }))
+        }
+        Err(e) => Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_PLAYER",
+            format!("Invalid player: {}", e),
+        )),
+    }
+}
+
+/// GET /api/game/:session_id/player/:player_id/info - The player's whole information set
+pub async fn get_player_info(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path((session_id, player_id)): Path<(String, PlayerId)>,
+    Query(query): Query<GameStateQuery>,
+    headers: HeaderMap,
+) -> Result<Json<PlayerInfoResponse>, ApiError> {
+    tracing::debug!(
+        session_id = %session_id,
+        player_id = player_id,
+        "API: Get player info"
+    );
+
+    let game_state = orchestrator
+        .get_game_state(&session_id)
+        .await
+        .map_err(|e| game_not_ready(&session_id, e))?;
+    require_player(&game_state, player_id, &headers)?;
+
+    let playable_positions = playable_cards(&game_state, player_id).map_err(|e| {
+        api_error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_PLAYER",
+            format!("Invalid player: {}", e),
+        )
+    })?;
+
+    Ok(Json(PlayerInfoResponse {
+        session_id,
+        player_id,
+        hand: CardList::new(
+            game_state.player_hands[player_id as usize].clone(),
+            query.format,
+        ),
+        hand_origins: game_state.hand_origins(player_id).map(<[_]>::to_vec),
+        playable_positions,
+        top_discard: game_state.top_discard().map(convert_card_to_js),
+        active_color: game_state.active_color(),
+        opponents: game_state.opponents_of(player_id),
+        draw_pile_count: game_state.draw_pile.len(),
+        current_player: game_state.current_player,
+        is_my_turn: game_state.current_player == player_id,
+        direction: game_state.direction,
+        finished: game_state.is_finished(),
+    }))
+}
+
+/// POST /api/game/:session_id/player/:player_id/draw - Draw cards into a player's hand
+pub async fn draw_cards(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path((session_id, player_id)): Path<(String, PlayerId)>,
+    ApiJson(req): ApiJson<DrawCardRequest>,
+) -> Result<Json<DrawCardResponse>, ApiError> {
+    tracing::debug!(
+        session_id = %session_id,
+        player_id = player_id,
+        count = req.count,
+        "API: Draw cards"
+    );
+
+    // The library accepts zero, but an empty draw from a client is almost always a bug
+    if req.count == 0 || req.count > MAX_DRAW_COUNT {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_COUNT",
+            format!("count must be between 1 and {}", MAX_DRAW_COUNT),
+        ));
+    }
+
+    let result = orchestrator
+        .update_game_state(&session_id, |game_state| {
+            let drawn = draw_multiple_cards_once(game_state, player_id, req.count, req.action_id)?;
+            Ok(DrawCardResponse {
+                session_id: session_id.clone(),
+                player_id,
+                message: format!("Successfully drew {} cards", drawn.len()),
+                cards: convert_indexes_to_js_cards(&drawn),
+                draw_pile_count: game_state.draw_pile.len(),
+            })
+        })
+        .await;
+
+    match result {
+        Some(Ok(response)) => Ok(Json(response)),
+        Some(Err(e)) => Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "DRAW_FAILED",
+            format!("Failed to draw: {}", e),
+        )),
+        None => Err(game_not_ready(
+            &session_id,
+            anyhow::anyhow!("Game not ready or not found: {}", session_id),
+        )),
+    }
+}
+
+/// Request body for passing the turn
+#[derive(Debug, Deserialize)]
+pub struct PassTurnRequest {
+    pub player_id: PlayerId,
+}
+
+/// Response for a passed turn
+#[derive(Debug, Serialize)]
+pub struct PassTurnResponse {
+    pub session_id: String,
+    pub passed_by: PlayerId,
+    pub current_player: PlayerId,
+}
+
+/// POST /api/game/:session_id/pass - End the current player's turn without playing
+pub async fn pass(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path(session_id): Path<String>,
+    ApiJson(req): ApiJson<PassTurnRequest>,
+) -> Result<Json<PassTurnResponse>, ApiError> {
+    tracing::debug!(
+        session_id = %session_id,
+        player_id = req.player_id,
+        "API: Pass turn"
+    );
+
+    let result = orchestrator
+        .update_game_state(&session_id, |game_state| {
+            pass_turn(game_state, req.player_id)?;
+            Ok(game_state.current_player)
+        })
+        .await;
+
+    match result {
+        Some(Ok(current_player)) => Ok(Json(PassTurnResponse {
+            session_id,
+            passed_by: req.player_id,
+            current_player,
+        })),
+        Some(Err(e)) => Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "PASS_FAILED",
+            format!("Failed to pass: {}", e),
+        )),
+        None => Err(game_not_ready(
+            &session_id,
+            anyhow::anyhow!("Game not ready or not found: {}", session_id),
+        )),
+    }
+}
+
+/// Request body for declaring the active color
+#[derive(Debug, Deserialize)]
+pub struct SetColorRequest {
+    /// One of "R", "G", "B", "Y"
+    pub color: char,
+}
+
+/// Response for a color declaration
+#[derive(Debug, Serialize)]
+pub struct SetColorResponse {
+    pub session_id: String,
+    pub active_color: Color,
+}
+
+/// POST /api/game/:session_id/set-color - Declare the color for a wild on top (house rule)
+///
+/// Bypasses playing a card, so it requires the admin token.
+pub async fn set_color(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path(session_id): Path<String>,
+    headers: HeaderMap,
+    ApiJson(req): ApiJson<SetColorRequest>,
+) -> Result<Json<SetColorResponse>, ApiError> {
+    require_admin(&orchestrator, &headers)?;
+
+    tracing::debug!(session_id = %session_id, color = %req.color, "API: Set color");
+
+    let color = Color::from_suffix(req.color.to_ascii_uppercase())
+        .filter(|color| Color::DECLARABLE.contains(color))
+        .ok_or_else(|| {
+            api_error(
+                StatusCode::BAD_REQUEST,
+                "INVALID_COLOR",
+                format!("Invalid color '{}': expected one of R, G, B, Y", req.color),
+            )
+        })?;
+
+    let result = orchestrator
+        .update_game_state(&session_id, |game_state| {
+            set_active_color(game_state, color)
+        })
+        .await;
+
+    match result {
+        Some(Ok(())) => Ok(Json(SetColorResponse {
+            session_id,
+            active_color: color,
+        })),
+        Some(Err(e)) => Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "SET_COLOR_FAILED",
+            format!("Failed to set color: {}", e),
+        )),
+        None => Err(game_not_ready(
+            &session_id,
+            anyhow::anyhow!("Game not ready or not found: {}", session_id),
+        )),
+    }
+}
+
+/// GET /api/game/:session_id/player/:player_id/opponents - Opponent hand counts in turn order
+pub async fn get_opponents(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path((session_id, player_id)): Path<(String, PlayerId)>,
+    Query(query): Query<OpponentsQuery>,
+    headers: HeaderMap,
+) -> Result<Json<OpponentsResponse>, ApiError> {
+    tracing::debug!(
+        session_id = %session_id,
+        player_id = player_id,
+        window = ?query.window,
+        "API: Get opponents"
+    );
+
+    let game_state = orchestrator
+        .get_game_state(&session_id)
+        .await
+        .map_err(|e| game_not_ready(&session_id, e))?;
+
+    if !game_state.is_valid_player(player_id) {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_PLAYER",
+            format!("Invalid player: Player {} not found", player_id),
+        ));
+    }
+    require_player(&game_state, player_id, &headers)?;
+
+    Ok(Json(OpponentsResponse {
+        session_id,
+        player_id,
+        direction: game_state.direction,
+        opponents: match query.window {
+            Some(window) => game_state.opponents_window(player_id, window),
+            None => game_state.opponents_of(player_id),
+        },
+    }))
+}
+
+/// Composition of the starting deal, across every hand and the draw pile
+#[derive(Debug, Serialize)]
+pub struct DealStatsResponse {
+    pub session_id: String,
+    pub hands: CardHistogram,
+    pub draw_pile: CardHistogram,
+    pub combined: CardHistogram,
+}
+
+/// GET /api/game/:session_id/stats - Color/kind histogram of the starting deal
+///
+/// Reveals the full deal, so while the game is on it requires the admin token. Once it has
+/// finished, any seated player's token will do.
+pub async fn get_deal_stats(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path(session_id): Path<String>,
+    headers: HeaderMap,
+    format: JsonFormat,
+) -> Result<FormattedJson<DealStatsResponse>, ApiError> {
+    tracing::debug!(session_id = %session_id, "API: Get deal stats");
+
+    let game_state = orchestrator
+        .get_game_state(&session_id)
+        .await
+        .map_err(|e| game_not_ready(&session_id, e));
+    if let Err(admin_error) = require_admin(&orchestrator, &headers) {
+        match &game_state {
+            Ok(game_state) if game_state.is_finished() => require_any_player(game_state, &headers)?,
+            _ => return Err(admin_error),
+        }
+    }
+    let game_state = game_state?;
+
+    let deal = game_state.initial_deal().map_err(|e| {
+        api_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "DEAL_UNAVAILABLE",
+            format!("Failed to reconstruct the starting deal: {}", e),
+        )
+    })?;
+
+    Ok(format.json(DealStatsResponse {
+        session_id,
+        hands: CardHistogram::from_cards(deal.player_hands.iter().flatten()),
+        draw_pile: CardHistogram::from_cards(&deal.draw_pile),
+        combined: CardHistogram::from_cards(deal.full_deck().iter()),
+    }))
+}
+
+/// GET /api/game/:session_id/discard - Get the last N discarded cards
+pub async fn get_discard_pile(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path(session_id): Path<String>,
+    Query(query): Query<DiscardQuery>,
+    format: JsonFormat,
+) -> Result<FormattedJson<DiscardPileResponse>, ApiError> {
+    tracing::debug!(
+        session_id = %session_id,
+        count = query.count,
+        "API: Get discard pile"
+    );
+
+    let game_state = orchestrator
+        .get_game_state(&session_id)
+        .await
+        .map_err(|e| game_not_ready(&session_id, e))?;
+
+    Ok(format.json(DiscardPileResponse {
+        session_id,
+        cards: convert_indexes_to_js_cards(game_state.visible_discards(query.count)),
+        pile_size: game_state.discard_pile.len(),
+    }))
+}
+
+/// GET /api/game/:session_id/replay - Export seed, params and moves for deterministic replay
+pub async fn get_game_replay(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path(session_id): Path<String>,
+    format: JsonFormat,
+) -> Result<FormattedJson<GameReplay>, ApiError> {
+    tracing::debug!(session_id = %session_id, "API: Get game replay");
+
+    let game_state = orchestrator
+        .get_game_state(&session_id)
+        .await
+        .map_err(|e| game_not_ready(&session_id, e))?;
+
+    Ok(format.json(GameReplay::from(&game_state)))
+}
+
+/// POST /api/game/:session_id/debug/reshuffle - Force a discard-into-draw reshuffle (dev mode only)
+pub async fn debug_reshuffle(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path(session_id): Path<String>,
+) -> Result<Json<ReshuffleResponse>, ApiError> {
+    require_dev_mode(&orchestrator)?;
+
+    tracing::debug!(session_id = %session_id, "API: Debug reshuffle");
+
+    let result = orchestrator
+        .update_game_state(&session_id, |game_state| {
+            force_reshuffle(game_state)?;
+            Ok(ReshuffleResponse {
+                session_id: session_id.clone(),
+                reshuffle_count: game_state.reshuffle_count,
+                draw_pile_count: game_state.draw_pile.len(),
+                discard_pile_count: game_state.discard_pile.len(),
+            })
+        })
+        .await;
+
+    match result {
+        Some(Ok(response)) => Ok(Json(response)),
+        Some(Err(e)) => Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "NOTHING_TO_RESHUFFLE",
+            e.to_string(),
+        )),
+        None => Err(game_not_ready(
+            &session_id,
+            anyhow::anyhow!("Game not ready or not found: {}", session_id),
+        )),
+    }
+}
+
+/// Reject debug endpoints unless the server runs in dev mode
+pub(super) fn require_dev_mode(orchestrator: &GameOrchestrator) -> Result<(), ApiError> {
+    if orchestrator.config().dev_mode {
+        Ok(())
+    } else {
+        Err(api_error(
+            StatusCode::NOT_FOUND,
+            "ROUTE_NOT_FOUND",
+            "No such endpoint",
+        ))
+    }
+}
+
+/// Reject the request unless it carries `player_id`'s seat token
+pub(super) fn require_player(
+    game_state: &GameState,
+    player_id: PlayerId,
+    headers: &HeaderMap,
+) -> Result<(), ApiError> {
+    let provided = headers
+        .get(PLAYER_TOKEN_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    if game_state.player_token_matches(player_id, provided) {
+        Ok(())
+    } else {
+        Err(api_error(
+            StatusCode::UNAUTHORIZED,
+            "UNAUTHORIZED",
+            format!("Missing or invalid token for player {}", player_id),
+        ))
+    }
+}
+
+/// Check `x-player-token` against every seat of `game_state`
+fn require_any_player(game_state: &GameState, headers: &HeaderMap) -> Result<(), ApiError> {
+    let provided = headers
+        .get(PLAYER_TOKEN_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    if (0..game_state.player_count() as PlayerId)
+        .any(|player_id| game_state.player_token_matches(player_id, provided))
+    {
+        Ok(())
+    } else {
+        Err(api_error(
+            StatusCode::UNAUTHORIZED,
+            "UNAUTHORIZED",
+            "Missing or invalid player token",
+        ))
+    }
+}
+
+/// Deal order and timing hint for a dealt game
+fn deal_animation(
+    game_state: &GameState,
+    format: CardFormat,
+    deal_timing_ms: u64,
+) -> Result<DealAnimation, ApiError> {
+    let sequence = game_state.deal_sequence().map_err(|e| {
+        api_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "DEAL_UNAVAILABLE",
+            format!("Failed to rebuild the deal: {}", e),
+        )
+    })?;
+
+    Ok(DealAnimation {
+        deal_timing_ms,
+        events: sequence
+            .into_iter()
+            .map(|(player_id, card)| DealEvent {
+                player_id,
+                card: CardValue::new(card, format),
+            })
+            .collect(),
+    })
+}
+
+/// HTTP status used when reporting a game's status to clients
+///
+/// `Failed` is a known, final state rather than a server error, so it is a 200 with the
+/// reason in the body; in-progress states are 202 so pollers can tell them apart.
+pub(super) fn game_status_code(status: &GameStatus) -> StatusCode {
+    if status.is_in_progress() {
+        StatusCode::ACCEPTED
+    } else {
+        StatusCode::OK
+    }
+}
+
+/// Log and build the error for a game that isn't finalized or doesn't exist
+pub(super) fn game_not_ready(session_id: &str, e: anyhow::Error) -> ApiError {
+    tracing::warn!(
+        session_id = %session_id,
+        error = %e,
+        "API: Game not ready or not found"
+    );
+    api_error(
+        StatusCode::NOT_FOUND,
+        "GAME_NOT_READY",
+        format!("Game not ready: {}", e),
+    )
+}
+
+/// Error for a verifiable game whose proof has no CID yet (e.g. its upload is dead-lettered)
+pub(super) fn proof_not_available(session_id: &str) -> ApiError {
+    api_error(
+        StatusCode::CONFLICT,
+        "PROOF_NOT_AVAILABLE",
+        format!("Proof for game {} has not been stored yet", session_id),
+    )
+}
+
+/// GET /api/estimate - Estimate proving cycles and time for game parameters
+pub async fn estimate_proof_cost(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Query(query): Query<EstimateQuery>,
+) -> Result<Json<ProofEstimate>, ApiError> {
+    tracing::debug!(
+        num_players = query.num_players,
+        cards_per_player = query.cards_per_player,
+        "API: Estimate proof cost"
+    );
+
+    if let Err(e) = validate_game_params(query.num_players, query.cards_per_player) {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_GAME_PARAMS",
+            e.to_string(),
+        ));
+    }
+
+    match orchestrator
+        .estimate_proof_cost(query.num_players, query.cards_per_player)
+        .await
+    {
+        Ok(estimate) => Ok(Json(estimate)),
+        Err(e) => {
+            tracing::error!(error = %e, "API: Failed to estimate proof cost");
+            Err(api_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "ESTIMATE_FAILED",
+                format!("Failed to estimate proof cost: {}", e),
+            ))
         }
     }
 }
@@ -147,3 +1299,46 @@ pub async fn health_check() -> Json<serde_json::Value> {
         "timestamp": chrono::Utc::now().timestamp()
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestrator::FailureReason;
+
+    #[test]
+    fn in_progress_statuses_are_accepted_and_final_ones_ok() {
+        for status in [
+            GameStatus::Requesting,
+            GameStatus::Committed,
+            GameStatus::WaitingForVRF,
+            GameStatus::GeneratingProof,
+        ] {
+            assert_eq!(
+                game_status_code(&status),
+                StatusCode::ACCEPTED,
+                "{:?}",
+                status
+            );
+        }
+
+        for status in [GameStatus::Ready, GameStatus::Finished] {
+            assert_eq!(game_status_code(&status), StatusCode::OK, "{:?}", status);
+        }
+    }
+
+    #[test]
+    fn a_failed_game_is_a_known_state_not_a_server_error() {
+        let failed = GameStatus::Failed {
+            reason: FailureReason::ProofUpload,
+            detail: "every provider failed".to_string(),
+        };
+
+        assert_eq!(game_status_code(&failed), StatusCode::OK);
+        assert_eq!(
+            serde_json::to_value(&failed).unwrap(),
+            serde_json::json!({
+                "Failed": { "reason": "proof_upload", "detail": "every provider failed" }
+            })
+        );
+    }
+}
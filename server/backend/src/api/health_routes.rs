@@ -0,0 +1,51 @@
+// backend/src/api/health_routes.rs
+
+use axum::{extract::State, http::StatusCode, Json};
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::orchestrator::GameOrchestrator;
+
+/// Overall result of the deep health check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Ok,
+    Degraded,
+}
+
+/// Response for the deep health check
+#[derive(Debug, Serialize)]
+pub struct DeepHealthResponse {
+    pub status: HealthStatus,
+    /// The prover executed the program on a minimal deal
+    pub prover: bool,
+    /// The WebSocket RPC answered a block number query
+    pub rpc: bool,
+}
+
+/// GET /health/deep - Probe the prover and RPC instead of only answering
+///
+/// Degraded instances respond 503, so load balancers can take them out of rotation before
+/// games start failing in finalization.
+pub async fn deep_health(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+) -> (StatusCode, Json<DeepHealthResponse>) {
+    let (prover, rpc) = tokio::join!(orchestrator.prover_healthy(), orchestrator.rpc_healthy());
+
+    let (code, status) = if prover && rpc {
+        (StatusCode::OK, HealthStatus::Ok)
+    } else {
+        tracing::warn!(prover, rpc, "Deep health check degraded");
+        (StatusCode::SERVICE_UNAVAILABLE, HealthStatus::Degraded)
+    };
+
+    (
+        code,
+        Json(DeepHealthResponse {
+            status,
+            prover,
+            rpc,
+        }),
+    )
+}
@@ -1,5 +1,34 @@
 // backend/src/api/mod.rs
 
+pub mod admin_routes;
+pub mod debug_routes;
+pub mod errors;
+pub mod format;
 pub mod game_routes;
+pub mod health_routes;
+pub mod pagination;
+pub mod proof_routes;
+pub mod router;
 
-pub use game_routes::{get_game_proof, get_game_state, get_game_status, start_game};
+pub use admin_routes::{
+    compare_deals, get_config, list_games, lookup_vrf_request, rerequest_vrf, swap_rpc_providers,
+};
+pub use debug_routes::get_debug_logs;
+pub use errors::{
+    api_error, api_error_with_details, route_not_found, ApiError, ApiJson, ErrorResponse,
+};
+pub use format::{FormattedJson, JsonFormat};
+pub use health_routes::{deep_health, DeepHealthResponse, HealthStatus};
+pub use pagination::{Page, PageQuery, DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT};
+pub use proof_routes::{
+    download_proof, get_image_id, get_proof_manifest, get_verifying_key, submit_proof,
+    verify_draw_pile,
+};
+pub use router::build_router;
+
+pub use game_routes::{
+    debug_reshuffle, draw_cards, estimate_proof_cost, fork_game, get_deal_stats, get_discard_pile,
+    get_game_proof, get_game_replay, get_game_state, get_game_status, get_opponents,
+    get_playable_cards, get_player_hand, get_player_info, pass, rematch_game, set_color,
+    start_game,
+};
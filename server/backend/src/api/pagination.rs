@@ -0,0 +1,118 @@
+// backend/src/api/pagination.rs
+
+use serde::{Deserialize, Serialize};
+
+/// Page size used when a list request doesn't specify one
+pub const DEFAULT_PAGE_LIMIT: usize = 20;
+/// Largest page size a client may request
+pub const MAX_PAGE_LIMIT: usize = 100;
+
+/// Query parameters shared by list endpoints (`?limit=&offset=`)
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct PageQuery {
+    #[serde(default = "default_page_limit")]
+    pub limit: usize,
+    #[serde(default)]
+    pub offset: usize,
+}
+
+fn default_page_limit() -> usize {
+    DEFAULT_PAGE_LIMIT
+}
+
+impl Default for PageQuery {
+    fn default() -> Self {
+        PageQuery {
+            limit: DEFAULT_PAGE_LIMIT,
+            offset: 0,
+        }
+    }
+}
+
+impl PageQuery {
+    /// Limit clamped to `1..=MAX_PAGE_LIMIT`
+    pub fn effective_limit(&self) -> usize {
+        self.limit.clamp(1, MAX_PAGE_LIMIT)
+    }
+}
+
+/// One page of a list response with the metadata clients need to build pagers
+#[derive(Debug, Serialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Number of items across all pages
+    pub total: usize,
+    pub limit: usize,
+    pub offset: usize,
+    /// Whether items exist past this page
+    pub has_more: bool,
+}
+
+impl<T> Page<T> {
+    /// Wrap already-sliced items, deriving `has_more` from the total
+    pub fn new(items: Vec<T>, total: usize, limit: usize, offset: usize) -> Self {
+        let has_more = offset.saturating_add(items.len()) < total;
+        Page {
+            items,
+            total,
+            limit,
+            offset,
+            has_more,
+        }
+    }
+
+    /// Cut the requested page out of the full, already-ordered list
+    pub fn from_vec(all: Vec<T>, query: PageQuery) -> Self {
+        let total = all.len();
+        let limit = query.effective_limit();
+        let items = all.into_iter().skip(query.offset).take(limit).collect();
+        Page::new(items, total, limit, query.offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(total: usize, limit: usize, offset: usize) -> Page<usize> {
+        Page::from_vec((0..total).collect(), PageQuery { limit, offset })
+    }
+
+    #[test]
+    fn earlier_pages_have_more() {
+        let first = page(25, 10, 0);
+
+        assert_eq!(first.items, (0..10).collect::<Vec<_>>());
+        assert_eq!(first.total, 25);
+        assert!(first.has_more);
+        assert!(page(25, 10, 10).has_more);
+    }
+
+    #[test]
+    fn the_last_page_has_no_more() {
+        let last = page(25, 10, 20);
+        assert_eq!(last.items, (20..25).collect::<Vec<_>>());
+        assert!(!last.has_more);
+
+        // A page ending exactly on the total is also the last one
+        let exact = page(20, 10, 10);
+        assert_eq!(exact.items.len(), 10);
+        assert!(!exact.has_more);
+    }
+
+    #[test]
+    fn offsets_past_the_end_are_empty_and_final() {
+        let beyond = page(25, 10, 40);
+
+        assert!(beyond.items.is_empty());
+        assert_eq!(beyond.total, 25);
+        assert_eq!(beyond.offset, 40);
+        assert!(!beyond.has_more);
+    }
+
+    #[test]
+    fn limits_are_clamped() {
+        assert_eq!(page(5, 0, 0).limit, 1);
+        assert_eq!(page(500, 1000, 0).items.len(), MAX_PAGE_LIMIT);
+    }
+}
@@ -0,0 +1,346 @@
+// backend/src/api/proof_routes.rs
+
+use alloy::primitives::hex;
+use alloy_sol_types::SolType;
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+use std::sync::Arc;
+
+use super::admin_routes::require_admin;
+use super::errors::{api_error, api_error_with_details, ApiError, ApiJson};
+use super::format::{FormattedJson, JsonFormat};
+use super::game_routes::{game_not_ready, proof_not_available};
+use crate::orchestrator::{FailureReason, GameFailure, GameOrchestrator};
+use zunnogame_lib::PublicValuesStruct;
+use zunnogame_script::{ProofConversion, ProofManifest, ProofOutput};
+
+/// Response for the image id endpoint
+#[derive(Debug, Serialize)]
+pub struct ImageIdResponse {
+    /// Hex-encoded image ID (verification key hash)
+    pub image_id: String,
+}
+
+/// Response for the verifying key endpoint
+#[derive(Debug, Serialize)]
+pub struct VerifyingKeyResponse {
+    /// Hash of `vk`, as reported by `/api/proof/image-id`
+    pub image_id: String,
+    /// How `vk` is serialized
+    pub encoding: String,
+    /// 0x-prefixed hex of the serialized `SP1VerifyingKey`
+    pub vk: String,
+}
+
+/// Result of recomputing a committed hash on the host
+#[derive(Debug, Serialize)]
+pub struct CommitmentCheckResponse {
+    pub session_id: String,
+    /// Hex value committed in the proof's public values
+    pub committed: String,
+    /// Hex value recomputed from the stored deal
+    pub computed: String,
+    pub matches: bool,
+}
+
+/// GET /api/proof/manifest - Current image id and program/commitment versions
+pub async fn get_proof_manifest(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    format: JsonFormat,
+) -> FormattedJson<ProofManifest> {
+    tracing::debug!("API: Get proof manifest");
+
+    format.json(orchestrator.proof_manifest().clone())
+}
+
+/// GET /api/game/:session_id/proof/download - The proof itself, as a JSON file attachment
+pub async fn download_proof(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path(session_id): Path<String>,
+) -> Result<Response, ApiError> {
+    tracing::debug!(session_id = %session_id, "API: Download proof");
+
+    let game_state = orchestrator
+        .get_game_state(&session_id)
+        .await
+        .map_err(|e| game_not_ready(&session_id, e))?;
+
+    if !game_state.verifiable {
+        return Err(api_error(
+            StatusCode::CONFLICT,
+            "GAME_UNVERIFIABLE",
+            format!("Game {} has no proof", session_id),
+        ));
+    }
+    let Some(proof_cid) = game_state.proof_cid.as_deref() else {
+        return Err(proof_not_available(&session_id));
+    };
+
+    let proof = orchestrator.fetch_proof(proof_cid).await.map_err(|e| {
+        tracing::warn!(
+            session_id = %session_id,
+            proof_cid = %proof_cid,
+            error = %e,
+            "API: Failed to fetch proof from IPFS"
+        );
+        api_error(
+            StatusCode::BAD_GATEWAY,
+            "PROOF_FETCH_FAILED",
+            format!("Failed to fetch proof {}: {}", proof_cid, e),
+        )
+    })?;
+
+    let body = serde_json::to_vec_pretty(&proof).map_err(|e| {
+        api_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "PROOF_SERIALIZATION_FAILED",
+            format!("Failed to serialize proof: {}", e),
+        )
+    })?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/json".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"proof_{}.json\"", session_id),
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+/// GET /api/proof/image-id - Image id of the embedded program, cached at startup
+pub async fn get_image_id(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+) -> Json<ImageIdResponse> {
+    tracing::debug!("API: Get image id");
+
+    Json(ImageIdResponse {
+        image_id: orchestrator.proof_manifest().image_id.clone(),
+    })
+}
+
+/// GET /api/proof/vk - Full verifying key, for clients that verify proofs themselves
+///
+/// Every game is proven against the same program, so this one key covers all of them.
+pub async fn get_verifying_key(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    format: JsonFormat,
+) -> Result<FormattedJson<VerifyingKeyResponse>, ApiError> {
+    tracing::debug!("API: Get verifying key");
+
+    let vk = orchestrator.export_vk().map_err(|e| {
+        api_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "VK_EXPORT_FAILED",
+            e.to_string(),
+        )
+    })?;
+
+    Ok(format.json(VerifyingKeyResponse {
+        image_id: orchestrator.proof_manifest().image_id.clone(),
+        encoding: "bincode-legacy".to_string(),
+        vk: hex::encode_prefixed(vk),
+    }))
+}
+
+/// Response after a submitted proof is stored
+#[derive(Debug, Serialize)]
+pub struct SubmitProofResponse {
+    pub session_id: String,
+    pub proof_cid: String,
+    /// IPFS providers holding a pin of the proof
+    pub pinned_by: Vec<String>,
+    pub verification_tx: String,
+}
+
+/// POST /api/game/:session_id/proof - Store a proof generated elsewhere (admin)
+///
+/// For deployments that prove on a separate fleet. The proof must be for the embedded program
+/// and commit exactly the public values recomputed from the game's seed and parameters; it is
+/// then verified on zkVerify and pinned like a proof generated here.
+pub async fn submit_proof(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path(session_id): Path<String>,
+    headers: HeaderMap,
+    ApiJson(proof): ApiJson<ProofOutput>,
+) -> Result<(StatusCode, Json<SubmitProofResponse>), ApiError> {
+    require_admin(&orchestrator, &headers)?;
+    tracing::info!(session_id = %session_id, "API: Proof submitted");
+
+    let game_state = orchestrator
+        .get_game_state(&session_id)
+        .await
+        .map_err(|e| game_not_ready(&session_id, e))?;
+
+    if !game_state.verifiable {
+        return Err(api_error(
+            StatusCode::CONFLICT,
+            "GAME_UNVERIFIABLE",
+            format!(
+                "Game {} was dealt from OS randomness and has no proof",
+                session_id
+            ),
+        ));
+    }
+    if game_state.proof_cid.is_some() {
+        return Err(api_error(
+            StatusCode::CONFLICT,
+            "PROOF_ALREADY_STORED",
+            format!("Game {} already has a stored proof", session_id),
+        ));
+    }
+
+    let proof_len = hex::decode(&proof.proof)
+        .map(|bytes| bytes.len())
+        .map_err(|e| e.to_string())
+        .and_then(|len| {
+            orchestrator
+                .config()
+                .proof_size_limits()
+                .check(len)
+                .map(|_| len)
+                .map_err(|e| e.to_string())
+        })
+        .map_err(|e| {
+            api_error(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "INVALID_PROOF",
+                format!("Invalid proof: {}", e),
+            )
+        })?;
+
+    let image_id = &orchestrator.proof_manifest().image_id;
+    if !proof.image_id.eq_ignore_ascii_case(image_id) {
+        return Err(api_error_with_details(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "PROOF_MISMATCH",
+            "Proof was generated for a different program",
+            serde_json::json!({ "expected_image_id": image_id, "image_id": proof.image_id }),
+        ));
+    }
+
+    // The conversion picks the `proofType` sent to zkVerify, so it must be the server's own
+    let conversion = ProofConversion::default();
+    if proof.conversion != conversion {
+        return Err(api_error_with_details(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "PROOF_MISMATCH",
+            "Proof was converted differently than this server's proofs",
+            serde_json::json!({ "expected_conversion": conversion, "conversion": proof.conversion }),
+        ));
+    }
+
+    let expected = game_state
+        .to_public_values(&game_state.seed_metadata)
+        .map_err(|e| {
+            api_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "DEAL_RECOMPUTE_FAILED",
+                format!("Failed to re-deal game: {}", e),
+            )
+        })?;
+    let expected = hex::encode_prefixed(PublicValuesStruct::abi_encode(&expected));
+    if !proof.pub_inputs.eq_ignore_ascii_case(&expected) {
+        return Err(api_error(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "PROOF_MISMATCH",
+            "Proof public values don't match the game's seed and parameters",
+        ));
+    }
+
+    tracing::debug!(
+        session_id = %session_id,
+        proof_bytes = proof_len,
+        "API: Submitted proof matches the deal"
+    );
+
+    match orchestrator.attach_external_proof(&session_id, proof).await {
+        Ok(game_state) => Ok((
+            StatusCode::CREATED,
+            Json(SubmitProofResponse {
+                session_id,
+                proof_cid: game_state.proof_cid.unwrap_or_default(),
+                pinned_by: game_state.proof_pinned_by,
+                verification_tx: game_state.verification_tx,
+            }),
+        )),
+        Err(e) => {
+            tracing::warn!(session_id = %session_id, error = %e, "API: Submitted proof not stored");
+            let (status, code) = match e.downcast_ref::<GameFailure>().map(|f| f.reason) {
+                Some(FailureReason::ProofVerification) => (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    "PROOF_VERIFICATION_FAILED",
+                ),
+                Some(FailureReason::ProofUpload) => {
+                    (StatusCode::BAD_GATEWAY, "PROOF_UPLOAD_FAILED")
+                }
+                _ => (StatusCode::CONFLICT, "PROOF_NOT_STORED"),
+            };
+            Err(api_error(status, code, format!("Proof not stored: {}", e)))
+        }
+    }
+}
+
+/// GET /api/game/:session_id/verify/draw-pile - Check the committed draw pile hash
+pub async fn verify_draw_pile(
+    State(orchestrator): State<Arc<GameOrchestrator>>,
+    Path(session_id): Path<String>,
+    format: JsonFormat,
+) -> Result<FormattedJson<CommitmentCheckResponse>, ApiError> {
+    tracing::debug!(session_id = %session_id, "API: Verify draw pile commitment");
+
+    let game_state = orchestrator
+        .get_game_state(&session_id)
+        .await
+        .map_err(|e| game_not_ready(&session_id, e))?;
+
+    let public_values = decode_public_values(&game_state.public_values)?;
+
+    let computed = game_state
+        .to_public_values(&game_state.seed_metadata)
+        .map_err(|e| {
+            api_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "DEAL_RECOMPUTE_FAILED",
+                format!("Failed to re-deal game: {}", e),
+            )
+        })?
+        .draw_pile_hash;
+
+    Ok(format.json(CommitmentCheckResponse {
+        session_id,
+        committed: hex::encode_prefixed(public_values.draw_pile_hash),
+        computed: hex::encode_prefixed(computed),
+        matches: public_values.draw_pile_hash == computed,
+    }))
+}
+
+/// Decode the hex ABI-encoded public values stored with a game
+fn decode_public_values(encoded: &str) -> Result<PublicValuesStruct, ApiError> {
+    if encoded.is_empty() {
+        return Err(api_error(
+            StatusCode::NOT_FOUND,
+            "PUBLIC_VALUES_UNAVAILABLE",
+            "No committed public values stored for this game",
+        ));
+    }
+
+    hex::decode(encoded)
+        .map_err(|e| e.to_string())
+        .and_then(|bytes| PublicValuesStruct::abi_decode(&bytes).map_err(|e| e.to_string()))
+        .map_err(|e| {
+            api_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "INVALID_PUBLIC_VALUES",
+                format!("Failed to decode public values: {}", e),
+            )
+        })
+}
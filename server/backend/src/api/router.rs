@@ -0,0 +1,347 @@
+// backend/src/api/router.rs
+
+use axum::{
+    body::Body,
+    extract::{DefaultBodyLimit, MatchedPath},
+    http::{header, HeaderMap, Request},
+    middleware::{self, Next},
+    response::Response,
+    routing::{get, post},
+    Router,
+};
+use std::sync::Arc;
+use std::time::Instant;
+use tower_http::compression::{
+    predicate::{DefaultPredicate, Predicate, SizeAbove},
+    CompressionLayer,
+};
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
+use tower_http::trace::TraceLayer;
+
+use super::admin_routes::{
+    compare_deals, get_config, list_games, lookup_vrf_request, rerequest_vrf, swap_rpc_providers,
+};
+use super::debug_routes::get_debug_logs;
+use super::errors::route_not_found;
+use super::game_routes::{
+    debug_reshuffle, draw_cards, estimate_proof_cost, fork_game, get_deal_stats, get_discard_pile,
+    get_game_proof, get_game_replay, get_game_state, get_game_status, get_opponents,
+    get_playable_cards, get_player_hand, get_player_info, pass, rematch_game, set_color,
+    start_game,
+};
+use super::health_routes::deep_health;
+use super::proof_routes::{
+    download_proof, get_image_id, get_proof_manifest, get_verifying_key, submit_proof,
+    verify_draw_pile,
+};
+use crate::orchestrator::GameOrchestrator;
+
+/// Responses smaller than this are sent uncompressed
+const COMPRESSION_MIN_BYTES: u16 = 1024;
+
+/// Header carrying the per-request id, generated when the client doesn't send one
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Tracing span for one HTTP request, tagged with its request id
+fn request_span(request: &Request<Body>) -> tracing::Span {
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("-");
+
+    tracing::debug_span!(
+        "request",
+        method = %request.method(),
+        uri = %request.uri(),
+        request_id = %request_id,
+    )
+}
+
+/// Debug log per API call: matched route, body sizes, status and latency
+///
+/// Bodies themselves are never logged since they can carry hands and tokens.
+async fn log_request(request: Request<Body>, next: Next) -> Response {
+    let method = request.method().clone();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| "<unmatched>".to_string());
+    let request_bytes = content_length(request.headers());
+    let started = Instant::now();
+
+    let response = next.run(request).await;
+
+    tracing::debug!(
+        method = %method,
+        route = %route,
+        request_bytes = ?request_bytes,
+        status = response.status().as_u16(),
+        response_bytes = ?content_length(response.headers()),
+        latency_ms = started.elapsed().as_millis() as u64,
+        "API call"
+    );
+
+    response
+}
+
+fn content_length(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+}
+
+/// Build the full API router around an orchestrator
+///
+/// Shared by `main` and anything that needs to serve the API in-process.
+pub fn build_router(orchestrator: Arc<GameOrchestrator>) -> Router {
+    let body_limit = orchestrator.config().max_request_body_bytes;
+    // Submitted proofs are hex in JSON: twice the proof size plus room for the other fields
+    let proof_body_limit = orchestrator
+        .config()
+        .proof_max_bytes
+        .saturating_mul(2)
+        .saturating_add(body_limit);
+
+    Router::new()
+        .route("/api/game/start", post(start_game))
+        .route("/api/game/:session_id/status", get(get_game_status))
+        .route("/api/game/:session_id", get(get_game_state))
+        .route(
+            "/api/game/:session_id/proof",
+            get(get_game_proof)
+                .post(submit_proof)
+                .layer(DefaultBodyLimit::max(proof_body_limit)),
+        )
+        .route("/api/game/:session_id/proof/download", get(download_proof))
+        .route("/api/game/:session_id/discard", get(get_discard_pile))
+        .route("/api/game/:session_id/replay", get(get_game_replay))
+        .route("/api/game/:session_id/stats", get(get_deal_stats))
+        .route("/api/game/:session_id/pass", post(pass))
+        .route("/api/game/:session_id/set-color", post(set_color))
+        .route("/api/game/:session_id/fork", post(fork_game))
+        .route("/api/game/:session_id/rematch", post(rematch_game))
+        .route(
+            "/api/game/:session_id/verify/draw-pile",
+            get(verify_draw_pile),
+        )
+        .route(
+            "/api/game/:session_id/player/:player_id/hand",
+            get(get_player_hand),
+        )
+        .route(
+            "/api/game/:session_id/player/:player_id/playable",
+            get(get_playable_cards),
+        )
+        .route(
+            "/api/game/:session_id/player/:player_id/opponents",
+            get(get_opponents),
+        )
+        .route(
+            "/api/game/:session_id/player/:player_id/info",
+            get(get_player_info),
+        )
+        .route(
+            "/api/game/:session_id/player/:player_id/draw",
+            post(draw_cards),
+        )
+        .route(
+            "/api/game/:session_id/debug/reshuffle",
+            post(debug_reshuffle),
+        )
+        .route("/api/debug/logs", get(get_debug_logs))
+        .route("/api/estimate", get(estimate_proof_cost))
+        .route("/api/proof/manifest", get(get_proof_manifest))
+        .route("/api/proof/image-id", get(get_image_id))
+        .route("/api/proof/vk", get(get_verifying_key))
+        .route("/api/admin/games", get(list_games))
+        .route("/api/admin/config", get(get_config))
+        .route("/api/admin/compare", get(compare_deals))
+        .route("/api/admin/vrf/:request_id", get(lookup_vrf_request))
+        .route(
+            "/api/admin/game/:session_id/rerequest-vrf",
+            post(rerequest_vrf),
+        )
+        .route("/api/admin/rpc/swap", post(swap_rpc_providers))
+        .route("/health", get(|| async { "OK" }))
+        .route("/health/deep", get(deep_health))
+        .fallback(route_not_found)
+        .layer(DefaultBodyLimit::max(body_limit))
+        .layer(middleware::from_fn(log_request))
+        // gzip/br negotiated from Accept-Encoding; shrinks card-string state and proof JSON
+        .layer(
+            CompressionLayer::new()
+                .compress_when(DefaultPredicate::new().and(SizeAbove::new(COMPRESSION_MIN_BYTES))),
+        )
+        // Layers run outermost-last: the id is set first, logged in the span, then
+        // copied onto the response so clients can quote it in bug reports
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(TraceLayer::new_for_http().make_span_with(request_span))
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
+        .with_state(orchestrator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestrator::{mock::Harness, OrchestratorConfig};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use tower::ServiceExt;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id};
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+    /// Records the `request_id` of every `request` span opened
+    #[derive(Clone, Default)]
+    struct SpanIds(Arc<Mutex<Vec<String>>>);
+
+    impl<S: tracing::Subscriber> Layer<S> for SpanIds {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+            if attrs.metadata().name() == "request" {
+                attrs.record(&mut RequestIdVisitor(&self.0));
+            }
+        }
+    }
+
+    struct RequestIdVisitor<'a>(&'a Mutex<Vec<String>>);
+
+    impl Visit for RequestIdVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "request_id" {
+                self.0.lock().unwrap().push(format!("{:?}", value));
+            }
+        }
+    }
+
+    /// Fields of every "API call" event, formatted with `Debug`
+    #[derive(Clone, Default)]
+    struct ApiCalls(Arc<Mutex<Vec<HashMap<String, String>>>>);
+
+    impl<S: tracing::Subscriber> Layer<S> for ApiCalls {
+        fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+            let mut fields = FieldMap::default();
+            event.record(&mut fields);
+            if fields.0.get("message").map(String::as_str) == Some("API call") {
+                self.0.lock().unwrap().push(fields.0);
+            }
+        }
+    }
+
+    impl ApiCalls {
+        fn take(&self) -> Vec<HashMap<String, String>> {
+            std::mem::take(&mut *self.0.lock().unwrap())
+        }
+    }
+
+    #[derive(Default)]
+    struct FieldMap(HashMap<String, String>);
+
+    impl Visit for FieldMap {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    /// Send `request` to a fresh router, returning the "API call" events it logged
+    async fn api_calls(request: Request<Body>) -> Vec<HashMap<String, String>> {
+        let calls = ApiCalls::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(calls.clone()));
+        let router = build_router(Harness::new(OrchestratorConfig::default()).orchestrator);
+
+        router.oneshot(request).await.unwrap();
+
+        calls.take()
+    }
+
+    #[tokio::test]
+    async fn api_calls_log_the_route_status_and_latency() {
+        let calls = api_calls(
+            Request::get("/api/game/some-session/status")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+
+        assert_eq!(calls.len(), 1);
+        let call = &calls[0];
+        assert_eq!(call["method"], "GET");
+        assert_eq!(call["route"], "/api/game/:session_id/status");
+        assert_eq!(call["status"], "404");
+        assert!(call["latency_ms"].parse::<u64>().is_ok(), "{:?}", call);
+    }
+
+    #[tokio::test]
+    async fn api_calls_log_body_sizes_but_not_bodies() {
+        let body = r#"{"num_players":2,"cards_per_player":7,"name":"secret-table"}"#;
+        let request = Request::post("/api/game/start")
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::CONTENT_LENGTH, body.len())
+            .body(Body::from(body))
+            .unwrap();
+
+        let calls = api_calls(request).await;
+
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0]["route"], "/api/game/start");
+        assert_eq!(calls[0]["request_bytes"], format!("Some({})", body.len()));
+        assert!(
+            calls[0]
+                .values()
+                .all(|value| !value.contains("secret-table")),
+            "{:?}",
+            calls[0]
+        );
+    }
+
+    /// Send `request` to a fresh router, returning the response id and the ids logged in spans
+    async fn traced(request: Request<Body>) -> (Option<String>, Vec<String>) {
+        let spans = SpanIds::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(spans.clone()));
+        let router = build_router(Harness::new(OrchestratorConfig::default()).orchestrator);
+
+        let response = router.oneshot(request).await.unwrap();
+
+        let header = response
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .map(|value| value.to_str().unwrap().to_string());
+        let logged = spans.0.lock().unwrap().clone();
+        (header, logged)
+    }
+
+    #[tokio::test]
+    async fn every_response_carries_the_id_logged_for_its_request() {
+        let (header, logged) = traced(Request::get("/health").body(Body::empty()).unwrap()).await;
+
+        let header = header.expect("response has a request id");
+        assert!(uuid::Uuid::parse_str(&header).is_ok(), "{}", header);
+        assert_eq!(logged, [header]);
+    }
+
+    #[tokio::test]
+    async fn a_client_supplied_id_is_kept() {
+        let request = Request::get("/api/game/unknown/status")
+            .header(REQUEST_ID_HEADER, "client-42")
+            .body(Body::empty())
+            .unwrap();
+
+        let (header, logged) = traced(request).await;
+
+        assert_eq!(header.as_deref(), Some("client-42"));
+        assert_eq!(logged, ["client-42"]);
+    }
+
+    #[tokio::test]
+    async fn each_request_gets_its_own_id() {
+        let (first, _) = traced(Request::get("/health").body(Body::empty()).unwrap()).await;
+        let (second, _) = traced(Request::get("/health").body(Body::empty()).unwrap()).await;
+
+        assert_ne!(first.unwrap(), second.unwrap());
+    }
+}
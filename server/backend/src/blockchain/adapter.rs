@@ -8,9 +8,17 @@ use alloy::{
     },
 };
 use anyhow::{anyhow, Result};
+use serde::Serialize;
 use std::env;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::Notify;
 use url::Url;
 
+use super::listener::VrfEventRouter;
+
 // ============================================================================
 // TYPE DEFINITIONS
 // ============================================================================
@@ -33,20 +41,67 @@ pub type HttpProvider = FillProvider<
     RootProvider,
 >;
 
+/// How VRF fulfillments are waited for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VrfMode {
+    /// WebSocket subscriptions first, HTTP polling as fallback
+    #[default]
+    Hybrid,
+    /// HTTP polling only, for providers with unreliable or unsupported subscriptions
+    Polling,
+}
+
+impl FromStr for VrfMode {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "hybrid" | "websocket" | "ws" => Ok(Self::Hybrid),
+            "polling" | "http" => Ok(Self::Polling),
+            other => Err(anyhow!(
+                "Invalid VRF_MODE '{}': expected 'hybrid' or 'polling'",
+                other
+            )),
+        }
+    }
+}
+
+/// Longest wait for a new endpoint to answer before a provider swap is abandoned
+const SWAP_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Both providers and the endpoints they were built from, always replaced together
+#[derive(Clone)]
+struct RpcProviders {
+    ws: WsProvider,
+    http: HttpProvider,
+    ws_url: String,
+    http_url: Url,
+}
+
 // ============================================================================
 // BLOCKCHAIN ADAPTER
 // ============================================================================
 
 pub struct BlockchainAdapter {
-    pub ws_provider: WsProvider,
-    pub http_provider: HttpProvider,
+    /// Current providers; callers clone them out, so a swap only affects later calls
+    providers: RwLock<RpcProviders>,
+    /// Woken by `swap_providers` so long-lived subscriptions move to the new WebSocket
+    pub(crate) providers_swapped: Notify,
     pub contract_address: Address,
+    /// Log subscriptions currently open on the WebSocket provider
+    pub(crate) active_subscriptions: AtomicUsize,
+    /// Fan-out for the persistent `RequestFulfilled` listener
+    pub(crate) vrf_events: VrfEventRouter,
+    /// Whether fulfillments are awaited over WebSocket or only polled over HTTP
+    pub vrf_mode: VrfMode,
+    /// Send VRF requests as one JSON-RPC batch; cleared once the HTTP endpoint rejects one
+    pub(crate) rpc_batching: AtomicBool,
 }
 
 impl BlockchainAdapter {
     /// Initialize adapter with both WebSocket (primary) and HTTP (fallback)
     pub async fn new() -> Result<Self> {
-        
         // Load configuration
         let ws_rpc_url = env::var("WS_RPC_URL")
             .map_err(|_| anyhow!("WS_RPC_URL not configured (e.g., wss://...)"))?;
@@ -58,46 +113,171 @@ impl BlockchainAdapter {
         let contract_address =
             env::var("CONTRACT_ADDRESS").map_err(|_| anyhow!("CONTRACT_ADDRESS not configured"))?;
 
-        // Initialize WebSocket provider (primary)
-        tracing::info!(ws_url = %ws_rpc_url, "Connecting to WebSocket provider");
-        let ws_connect = WsConnect::new(&ws_rpc_url);
-        let ws_provider = ProviderBuilder::new()
-            .connect_ws(ws_connect)
-            .await
-            .map_err(|e| anyhow!("Failed to connect WebSocket provider: {}", e))?;
+        let vrf_mode = match env::var("VRF_MODE") {
+            Ok(mode) if !mode.trim().is_empty() => mode.parse()?,
+            _ => VrfMode::default(),
+        };
+
+        let rpc_batching = match env::var("RPC_BATCHING") {
+            Ok(value) if !value.trim().is_empty() => value
+                .trim()
+                .parse::<bool>()
+                .map_err(|_| anyhow!("Invalid RPC_BATCHING '{}': expected true or false", value))?,
+            _ => true,
+        };
 
-        // Initialize HTTP provider (fallback)
-        tracing::info!(http_url = %http_rpc_url, "Connecting to HTTP provider");
-        let http_provider = ProviderBuilder::new().connect_http(http_rpc_url);
+        let (ws_provider, http_provider) = connect_providers(&ws_rpc_url, &http_rpc_url).await?;
 
         let contract_address = Address::parse_checksummed(&contract_address, None)?;
 
+        Ok(Self::with_providers(
+            ws_provider,
+            http_provider,
+            ws_rpc_url,
+            http_rpc_url,
+            contract_address,
+            vrf_mode,
+            rpc_batching,
+        ))
+    }
+
+    /// Build an adapter around already connected providers, e.g. ones on a mock transport
+    ///
+    /// The URLs are only reported (`rpc_urls`, the config endpoint); nothing connects to them.
+    pub fn with_providers(
+        ws_provider: WsProvider,
+        http_provider: HttpProvider,
+        ws_rpc_url: String,
+        http_rpc_url: Url,
+        contract_address: Address,
+        vrf_mode: VrfMode,
+        rpc_batching: bool,
+    ) -> Self {
         tracing::info!(
             contract = %contract_address,
+            vrf_mode = ?vrf_mode,
+            rpc_batching = rpc_batching,
             "BlockchainAdapter initialized successfully"
         );
 
-        Ok(Self {
-            ws_provider,
-            http_provider,
+        Self {
+            providers: RwLock::new(RpcProviders {
+                ws: ws_provider,
+                http: http_provider,
+                ws_url: ws_rpc_url,
+                http_url: http_rpc_url,
+            }),
+            providers_swapped: Notify::new(),
             contract_address,
-        })
+            active_subscriptions: AtomicUsize::new(0),
+            vrf_events: VrfEventRouter::default(),
+            vrf_mode,
+            rpc_batching: AtomicBool::new(rpc_batching),
+        }
+    }
+
+    fn providers(&self) -> RpcProviders {
+        self.providers
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
     }
 
     /// Get WebSocket provider (for subscriptions and real-time events)
-    pub fn get_ws_provider(&self) -> &WsProvider {
-        &self.ws_provider
+    pub fn get_ws_provider(&self) -> WsProvider {
+        self.providers().ws
     }
 
     /// Get HTTP provider (for one-off queries and fallback)
-    pub fn get_http_provider(&self) -> &HttpProvider {
-        &self.http_provider
+    pub fn get_http_provider(&self) -> HttpProvider {
+        self.providers().http
+    }
+
+    /// WebSocket and HTTP endpoints currently in use
+    pub fn rpc_urls(&self) -> (String, Url) {
+        let providers = self.providers();
+        (providers.ws_url, providers.http_url)
+    }
+
+    /// Replace both providers at once, without a restart
+    ///
+    /// The new endpoints must answer and agree on the chain id (and match the current chain
+    /// when the old HTTP endpoint still answers) before anything is swapped. Calls already
+    /// in flight finish on the old providers; the VRF listener resubscribes on the new one.
+    pub async fn swap_providers(&self, ws_rpc_url: &str, http_rpc_url: &str) -> Result<u64> {
+        let http_rpc_url: Url = http_rpc_url
+            .trim()
+            .parse()
+            .map_err(|e| anyhow!("Invalid HTTP RPC URL: {}", e))?;
+        let ws_rpc_url = ws_rpc_url.trim().to_string();
+
+        let (ws_provider, http_provider) = tokio::time::timeout(
+            SWAP_CHECK_TIMEOUT,
+            connect_providers(&ws_rpc_url, &http_rpc_url),
+        )
+        .await
+        .map_err(|_| anyhow!("Timed out connecting to the new WebSocket provider"))??;
+
+        self.install_providers(ws_provider, http_provider, ws_rpc_url, http_rpc_url)
+            .await
+    }
+
+    /// Swap in already connected providers once they pass the chain id checks of
+    /// `swap_providers`
+    pub(crate) async fn install_providers(
+        &self,
+        ws_provider: WsProvider,
+        http_provider: HttpProvider,
+        ws_rpc_url: String,
+        http_rpc_url: Url,
+    ) -> Result<u64> {
+        let ws_chain = chain_id_within(&ws_provider, "WebSocket").await?;
+        let http_chain = chain_id_within(&http_provider, "HTTP").await?;
+        if ws_chain != http_chain {
+            return Err(anyhow!(
+                "New providers disagree on chain id (WebSocket {}, HTTP {})",
+                ws_chain,
+                http_chain
+            ));
+        }
+
+        if let Ok(current_chain) = chain_id_within(&self.get_http_provider(), "current HTTP").await
+        {
+            if current_chain != http_chain {
+                return Err(anyhow!(
+                    "New providers serve chain {} but the contract is on chain {}",
+                    http_chain,
+                    current_chain
+                ));
+            }
+        }
+
+        *self.providers.write().unwrap_or_else(|e| e.into_inner()) = RpcProviders {
+            ws: ws_provider,
+            http: http_provider,
+            ws_url: ws_rpc_url,
+            http_url: http_rpc_url,
+        };
+        self.providers_swapped.notify_waiters();
+
+        tracing::warn!(chain_id = http_chain, "RPC providers swapped");
+        Ok(http_chain)
     }
 
     /// Get contract address
     pub fn contract_address(&self) -> Address {
         self.contract_address
     }
+
+    /// Number of VRF log subscriptions currently open
+    pub fn active_subscriptions(&self) -> usize {
+        self.active_subscriptions.load(Ordering::Relaxed)
+    }
+
+    /// Whether VRF requests are still sent as a JSON-RPC batch
+    pub fn rpc_batching(&self) -> bool {
+        self.rpc_batching.load(Ordering::Relaxed)
+    }
 }
 
 // ============================================================================
@@ -108,22 +288,158 @@ impl BlockchainAdapter {
     /// Check if WebSocket connection is healthy
     pub async fn check_ws_health(&self) -> bool {
         // Try to get block number as a health check
-        self.ws_provider.get_block_number().await.is_ok()
+        self.get_ws_provider().get_block_number().await.is_ok()
     }
 
     /// Reconnect WebSocket if connection is lost
-    pub async fn reconnect_ws(&mut self) -> Result<()> {
-        let ws_rpc_url = env::var("WS_RPC_URL")?;
+    pub async fn reconnect_ws(&self) -> Result<()> {
+        let (ws_rpc_url, _) = self.rpc_urls();
 
         tracing::warn!("Reconnecting WebSocket provider");
 
         let ws_connect = WsConnect::new(&ws_rpc_url);
-        self.ws_provider = ProviderBuilder::new()
+        let ws_provider = ProviderBuilder::new()
             .connect_ws(ws_connect)
             .await
             .map_err(|e| anyhow!("Failed to reconnect WebSocket: {}", e))?;
 
+        let mut providers = self.providers.write().unwrap_or_else(|e| e.into_inner());
+        // A concurrent swap wins; its provider already points somewhere else
+        if providers.ws_url == ws_rpc_url {
+            providers.ws = ws_provider;
+        }
+
         tracing::info!("WebSocket reconnected successfully");
         Ok(())
     }
 }
+
+/// Connect the WebSocket (primary) and HTTP (fallback) providers
+async fn connect_providers(
+    ws_rpc_url: &str,
+    http_rpc_url: &Url,
+) -> Result<(WsProvider, HttpProvider)> {
+    tracing::info!(ws_url = %ws_rpc_url, "Connecting to WebSocket provider");
+    let ws_connect = WsConnect::new(ws_rpc_url);
+    let ws_provider = ProviderBuilder::new()
+        .connect_ws(ws_connect)
+        .await
+        .map_err(|e| anyhow!("Failed to connect WebSocket provider: {}", e))?;
+
+    tracing::info!(http_url = %http_rpc_url, "Connecting to HTTP provider");
+    let http_provider = ProviderBuilder::new().connect_http(http_rpc_url.clone());
+
+    Ok((ws_provider, http_provider))
+}
+
+/// Chain id reported by `provider`, bounded by `SWAP_CHECK_TIMEOUT`
+async fn chain_id_within<P: Provider>(provider: &P, label: &str) -> Result<u64> {
+    tokio::time::timeout(SWAP_CHECK_TIMEOUT, provider.get_chain_id())
+        .await
+        .map_err(|_| {
+            anyhow!(
+                "{} provider did not answer within {:?}",
+                label,
+                SWAP_CHECK_TIMEOUT
+            )
+        })?
+        .map_err(|e| anyhow!("{} provider is unreachable: {}", label, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::mock::{uint_result, MockTransport};
+    use alloy::primitives::U256;
+    use serde_json::json;
+
+    /// Node on chain 1 that hands out `request_id` for every VRF request
+    fn vrf_node(request_id: u64) -> MockTransport {
+        let node = MockTransport::new();
+        node.set("eth_call", uint_result(request_id));
+        node
+    }
+
+    async fn swap_to(
+        adapter: &BlockchainAdapter,
+        ws: &MockTransport,
+        http: &MockTransport,
+    ) -> Result<u64> {
+        adapter
+            .install_providers(
+                ws.provider(),
+                http.provider(),
+                "ws://replacement".to_string(),
+                Url::parse("http://replacement").unwrap(),
+            )
+            .await
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn vrf_requests_after_a_swap_hit_the_new_provider() {
+        let (old, new) = (vrf_node(7), vrf_node(9));
+        let adapter = old.adapter(VrfMode::Polling);
+
+        assert_eq!(swap_to(&adapter, &new, &new).await.unwrap(), 1);
+        let request = adapter.request_vrf().await.unwrap();
+
+        assert_eq!(request.request_id, U256::from(9));
+        assert_eq!(old.calls("eth_call"), 0);
+        assert_eq!(new.calls("eth_call"), 1);
+        let (ws_url, http_url) = adapter.rpc_urls();
+        assert_eq!(ws_url, "ws://replacement");
+        assert_eq!(http_url.as_str(), "http://replacement/");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn providers_on_another_chain_are_not_swapped_in() {
+        let old = vrf_node(7);
+        let adapter = old.adapter(VrfMode::Polling);
+        let (agreeing, other_chain) = (vrf_node(9), vrf_node(9));
+        other_chain.set("eth_chainId", json!("0x5"));
+
+        // The two new endpoints disagree with each other
+        let split = swap_to(&adapter, &agreeing, &other_chain)
+            .await
+            .unwrap_err();
+        assert!(
+            split.to_string().contains("disagree on chain id"),
+            "{}",
+            split
+        );
+        // Both new endpoints agree, but not with the contract's chain
+        let moved = swap_to(&adapter, &other_chain, &other_chain)
+            .await
+            .unwrap_err();
+        assert!(
+            moved.to_string().contains("contract is on chain 1"),
+            "{}",
+            moved
+        );
+
+        let request = adapter.request_vrf().await.unwrap();
+        assert_eq!(request.request_id, U256::from(7));
+        assert_eq!(adapter.rpc_urls().0, "ws://mock");
+        assert_eq!(
+            agreeing.calls("eth_call") + other_chain.calls("eth_call"),
+            0
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn an_invalid_http_url_is_rejected_before_connecting() {
+        let adapter = vrf_node(7).adapter(VrfMode::Polling);
+
+        let error = adapter
+            .swap_providers("ws://replacement", "not a url")
+            .await
+            .unwrap_err();
+
+        assert!(
+            error.to_string().contains("Invalid HTTP RPC URL"),
+            "{}",
+            error
+        );
+        assert_eq!(adapter.rpc_urls().0, "ws://mock");
+    }
+}
@@ -0,0 +1,172 @@
+// backend/src/blockchain/listener.rs
+
+use alloy::{primitives::U256, providers::Provider, rpc::types::Filter};
+use alloy_sol_types::SolEvent;
+use anyhow::{anyhow, Result};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tokio_stream::StreamExt;
+
+use super::adapter::{BlockchainAdapter, VrfMode};
+use super::vrf::{LogSubscriptionGuard, RequestFulfilled};
+
+/// Fulfillments kept for request ids nobody is waiting on yet
+const MAX_UNCLAIMED_EVENTS: usize = 1024;
+/// Longest pause between resubscription attempts
+const MAX_RESUBSCRIBE_BACKOFF: Duration = Duration::from_secs(30);
+
+#[derive(Default)]
+struct RouterState {
+    waiters: HashMap<U256, Vec<oneshot::Sender<U256>>>,
+    /// Events that arrived before anyone registered, oldest first
+    unclaimed: HashMap<U256, U256>,
+    unclaimed_order: VecDeque<U256>,
+}
+
+/// Routes `RequestFulfilled` events from one shared subscription to waiting games
+#[derive(Default)]
+pub struct VrfEventRouter {
+    state: Mutex<RouterState>,
+    running: AtomicBool,
+}
+
+impl VrfEventRouter {
+    /// Whether the persistent listener task is currently subscribed
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::Relaxed)
+    }
+
+    /// Wait for the fulfillment of `request_id`
+    ///
+    /// Resolves immediately if the event was already seen.
+    pub fn register(&self, request_id: U256) -> oneshot::Receiver<U256> {
+        let (sender, receiver) = oneshot::channel();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(random_word) = state.unclaimed.remove(&request_id) {
+            state.unclaimed_order.retain(|id| *id != request_id);
+            let _ = sender.send(random_word);
+        } else {
+            state.waiters.entry(request_id).or_default().push(sender);
+        }
+
+        receiver
+    }
+
+    /// Drop waiters whose receivers have gone away (timed out or cancelled)
+    pub fn prune(&self, request_id: U256) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(senders) = state.waiters.get_mut(&request_id) {
+            senders.retain(|sender| !sender.is_closed());
+            if senders.is_empty() {
+                state.waiters.remove(&request_id);
+            }
+        }
+    }
+
+    /// Hand a fulfillment to everyone waiting on it, or park it for a later `register`
+    pub fn dispatch(&self, request_id: U256, random_word: U256) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        match state.waiters.remove(&request_id) {
+            Some(senders) => {
+                for sender in senders {
+                    let _ = sender.send(random_word);
+                }
+            }
+            None => {
+                if state.unclaimed.insert(request_id, random_word).is_none() {
+                    state.unclaimed_order.push_back(request_id);
+                }
+                while state.unclaimed_order.len() > MAX_UNCLAIMED_EVENTS {
+                    if let Some(oldest) = state.unclaimed_order.pop_front() {
+                        state.unclaimed.remove(&oldest);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl BlockchainAdapter {
+    /// Persistent listener for every `RequestFulfilled` event on the contract
+    ///
+    /// Runs for the life of the process, resubscribing with backoff whenever the
+    /// subscription fails or the stream ends.
+    pub async fn run_vrf_event_listener(self: Arc<Self>) {
+        if self.vrf_mode == VrfMode::Polling {
+            tracing::info!("VRF_MODE=polling, not starting the VRF event listener");
+            return;
+        }
+
+        tracing::info!("Starting persistent VRF event listener");
+
+        let mut backoff = Duration::from_secs(1);
+        loop {
+            match self.listen_for_vrf_events().await {
+                Ok(()) => {
+                    tracing::warn!("VRF event stream ended, resubscribing");
+                    backoff = Duration::from_secs(1);
+                }
+                Err(e) => tracing::error!(error = %e, "VRF event listener failed, resubscribing"),
+            }
+
+            self.vrf_events.running.store(false, Ordering::Relaxed);
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_RESUBSCRIBE_BACKOFF);
+        }
+    }
+
+    async fn listen_for_vrf_events(&self) -> Result<()> {
+        let filter = Filter::new()
+            .address(self.contract_address)
+            .event_signature(RequestFulfilled::SIGNATURE_HASH);
+
+        // Registered before subscribing, so a swap during setup is not missed
+        let swapped = self.providers_swapped.notified();
+        tokio::pin!(swapped);
+
+        let ws_provider = self.get_ws_provider();
+        let sub = ws_provider
+            .subscribe_logs(&filter)
+            .await
+            .map_err(|e| anyhow!("Failed to subscribe to logs: {}", e))?;
+
+        let _guard =
+            LogSubscriptionGuard::new(ws_provider, *sub.local_id(), &self.active_subscriptions);
+        let mut stream = sub.into_stream();
+        self.vrf_events.running.store(true, Ordering::Relaxed);
+
+        loop {
+            let log = tokio::select! {
+                log = stream.next() => match log {
+                    Some(log) => log,
+                    None => break,
+                },
+                _ = &mut swapped => {
+                    tracing::info!("RPC providers swapped, moving VRF listener to the new WebSocket");
+                    break;
+                }
+            };
+
+            match RequestFulfilled::decode_log(&log.inner) {
+                Ok(event) => {
+                    tracing::debug!(
+                        request_id = %event.data.requestId,
+                        "VRF fulfillment received by listener"
+                    );
+                    self.vrf_events
+                        .dispatch(event.data.requestId, event.data.randomWord);
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to decode VRF log, continuing...");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,338 @@
+// backend/src/blockchain/mock.rs
+
+//! In-memory JSON-RPC transport for tests, answering each method from a queue of results,
+//! plus a pubsub backend that counts subscriptions
+
+use alloy::{
+    primitives::{Address, B256, U256},
+    providers::ProviderBuilder,
+    pubsub::{ConnectionHandle, PubSubConnect},
+    rpc::{
+        client::{ClientBuilder, RpcClient},
+        json_rpc::{
+            ErrorPayload, PubSubItem, RequestPacket, Response, ResponsePacket, ResponsePayload,
+            SerializedRequest,
+        },
+    },
+    transports::{TransportError, TransportErrorKind, TransportFut, TransportResult},
+};
+use alloy_sol_types::SolEvent;
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tokio::sync::mpsc;
+use url::Url;
+
+use super::adapter::{BlockchainAdapter, VrfMode, WsProvider};
+use super::vrf::RequestFulfilled;
+
+pub(crate) const MOCK_CONTRACT: Address = Address::repeat_byte(0x42);
+
+#[derive(Default)]
+struct MockState {
+    /// Results per method, in answer order; the last one repeats
+    results: HashMap<String, VecDeque<Value>>,
+    /// Every method called, batched or not
+    calls: Vec<String>,
+    /// Size of every batch received
+    batches: Vec<usize>,
+    reject_batches: bool,
+    /// Batches still to fail with a dropped connection
+    batch_failures: usize,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockTransport {
+    /// A node on chain 1 at block 16 that knows no other method
+    pub(crate) fn new() -> Self {
+        let mock = Self::default();
+        mock.set("eth_chainId", json!("0x1"));
+        mock.set("eth_blockNumber", json!("0x10"));
+        mock
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Answer every later `method` call with `result`
+    pub(crate) fn set(&self, method: &str, result: Value) {
+        self.state()
+            .results
+            .insert(method.to_string(), VecDeque::from([result]));
+    }
+
+    /// Queue `result` behind the ones already queued for `method`
+    pub(crate) fn push(&self, method: &str, result: Value) {
+        self.state()
+            .results
+            .entry(method.to_string())
+            .or_default()
+            .push_back(result);
+    }
+
+    /// Fail every batch request the way endpoints without batch support do
+    pub(crate) fn reject_batches(&self) {
+        self.state().reject_batches = true;
+    }
+
+    /// Drop the connection on the next `failures` batches, as a flaky network would
+    pub(crate) fn fail_batches(&self, failures: usize) {
+        self.state().batch_failures = failures;
+    }
+
+    /// Number of `method` calls received so far
+    pub(crate) fn calls(&self, method: &str) -> usize {
+        self.state().calls.iter().filter(|m| *m == method).count()
+    }
+
+    /// Sizes of the batches received so far
+    pub(crate) fn batches(&self) -> Vec<usize> {
+        self.state().batches.clone()
+    }
+
+    pub(crate) fn provider(&self) -> WsProvider {
+        ProviderBuilder::new().connect_client(RpcClient::new(self.clone(), true))
+    }
+
+    /// Adapter using this transport for both providers
+    pub(crate) fn adapter(&self, vrf_mode: VrfMode) -> BlockchainAdapter {
+        BlockchainAdapter::with_providers(
+            self.provider(),
+            self.provider(),
+            "ws://mock".to_string(),
+            Url::parse("http://mock").unwrap(),
+            MOCK_CONTRACT,
+            vrf_mode,
+            true,
+        )
+    }
+
+    fn answer(&self, request: &SerializedRequest) -> Response {
+        let mut state = self.state();
+        let method = request.method().to_string();
+        state.calls.push(method.clone());
+
+        let result = state.results.get_mut(&method).and_then(|queue| {
+            if queue.len() > 1 {
+                queue.pop_front()
+            } else {
+                queue.front().cloned()
+            }
+        });
+        let payload = match result {
+            Some(result) => ResponsePayload::Success(
+                serde_json::value::to_raw_value(&result).expect("mock result serializes"),
+            ),
+            None => ResponsePayload::Failure(ErrorPayload {
+                code: -32601,
+                message: format!("method {} not mocked", method).into(),
+                data: None,
+            }),
+        };
+
+        Response {
+            id: request.id().clone(),
+            payload,
+        }
+    }
+}
+
+impl tower::Service<RequestPacket> for MockTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let response = match request {
+            RequestPacket::Single(request) => Ok(ResponsePacket::Single(self.answer(&request))),
+            RequestPacket::Batch(requests) => {
+                let (rejected, dropped) = {
+                    let mut state = self.state();
+                    state.batches.push(requests.len());
+                    let dropped = state.batch_failures > 0;
+                    state.batch_failures = state.batch_failures.saturating_sub(1);
+                    (state.reject_batches, dropped)
+                };
+                if rejected {
+                    Err(TransportErrorKind::http_error(
+                        405,
+                        "batch requests are not supported".to_string(),
+                    ))
+                } else if dropped {
+                    Err(TransportErrorKind::custom_str("connection reset by peer"))
+                } else {
+                    Ok(ResponsePacket::Batch(
+                        requests
+                            .iter()
+                            .map(|request| self.answer(request))
+                            .collect(),
+                    ))
+                }
+            }
+        };
+
+        Box::pin(async move { response })
+    }
+}
+
+/// Pubsub node that accepts every `eth_subscribe` and counts subscribes against
+/// unsubscribes; notifications are only sent through `emit_log`
+#[derive(Clone, Default)]
+pub(crate) struct MockPubSub {
+    subscribes: Arc<AtomicUsize>,
+    unsubscribes: Arc<AtomicUsize>,
+    /// Server ids of subscriptions not yet unsubscribed
+    live: Arc<Mutex<Vec<U256>>>,
+    /// Notification queue of every connection opened
+    connections: Arc<Mutex<Vec<mpsc::UnboundedSender<PubSubItem>>>>,
+}
+
+impl MockPubSub {
+    pub(crate) fn subscribes(&self) -> usize {
+        self.subscribes.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn unsubscribes(&self) -> usize {
+        self.unsubscribes.load(Ordering::SeqCst)
+    }
+
+    /// Subscriptions currently open on the node
+    pub(crate) fn live_subscriptions(&self) -> usize {
+        self.live.lock().unwrap().len()
+    }
+
+    /// Adapter subscribing through this node and answering HTTP calls from `http`
+    pub(crate) async fn adapter(
+        &self,
+        http: &MockTransport,
+        vrf_mode: VrfMode,
+    ) -> BlockchainAdapter {
+        let ws = ClientBuilder::default()
+            .pubsub(self.clone())
+            .await
+            .expect("mock pubsub connects");
+        BlockchainAdapter::with_providers(
+            ProviderBuilder::new().connect_client(ws),
+            http.provider(),
+            "ws://mock".to_string(),
+            Url::parse("http://mock").unwrap(),
+            MOCK_CONTRACT,
+            vrf_mode,
+            true,
+        )
+    }
+
+    /// Deliver `log` (as from `fulfilled_log`) to every open subscription
+    pub(crate) fn emit_log(&self, log: Value) {
+        let live = self.live.lock().unwrap().clone();
+        let connections = self.connections.lock().unwrap();
+        for server_id in live {
+            let notification = json!({
+                "jsonrpc": "2.0",
+                "method": "eth_subscription",
+                "params": { "subscription": subscription_id(server_id), "result": log },
+            });
+            for connection in connections.iter() {
+                let item: PubSubItem = serde_json::from_value(notification.clone())
+                    .expect("notification is a pubsub item");
+                let _ = connection.send(item);
+            }
+        }
+    }
+
+    fn result_for(&self, request: &Value) -> Value {
+        match request["method"].as_str().unwrap_or_default() {
+            "eth_subscribe" => {
+                let server_id = U256::from(self.subscribes.fetch_add(1, Ordering::SeqCst) + 1);
+                self.live.lock().unwrap().push(server_id);
+                json!(subscription_id(server_id))
+            }
+            "eth_unsubscribe" => {
+                self.unsubscribes.fetch_add(1, Ordering::SeqCst);
+                let closed = request["params"][0]
+                    .as_str()
+                    .and_then(|id| U256::from_str(id).ok());
+                self.live.lock().unwrap().retain(|id| Some(*id) != closed);
+                json!(true)
+            }
+            "eth_chainId" => json!("0x1"),
+            _ => Value::Null,
+        }
+    }
+}
+
+/// Subscription ids as full 32-byte hex, which parses both as `U256` and `B256`
+fn subscription_id(server_id: U256) -> String {
+    B256::from(server_id).to_string()
+}
+
+impl PubSubConnect for MockPubSub {
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    async fn connect(&self) -> TransportResult<ConnectionHandle> {
+        let (handle, mut interface) = ConnectionHandle::new();
+        let (notify, mut notifications) = mpsc::unbounded_channel();
+        self.connections.lock().unwrap().push(notify);
+        let node = self.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let item = tokio::select! {
+                    raw = interface.recv_from_frontend() => {
+                        let Some(raw) = raw else { break };
+                        let request: Value =
+                            serde_json::from_str(raw.get()).expect("request is JSON");
+                        let response = json!({
+                            "jsonrpc": "2.0",
+                            "id": request["id"],
+                            "result": node.result_for(&request),
+                        });
+                        serde_json::from_value(response).expect("response is a pubsub item")
+                    }
+                    Some(item) = notifications.recv() => item,
+                };
+                if interface.send_to_frontend(item).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(handle)
+    }
+}
+
+/// `eth_call` result encoding a single `uint256`
+pub(crate) fn uint_result(value: u64) -> Value {
+    json!(format!("0x{:064x}", value))
+}
+
+/// `eth_getLogs` entry for the fulfillment of `request_id`
+pub(crate) fn fulfilled_log(request_id: U256, random_word: U256) -> Value {
+    let log = alloy::rpc::types::Log {
+        inner: alloy::primitives::Log {
+            address: MOCK_CONTRACT,
+            data: RequestFulfilled {
+                requestId: request_id,
+                randomWord: random_word,
+            }
+            .encode_log_data(),
+        },
+        block_number: Some(0x10),
+        ..Default::default()
+    };
+    serde_json::to_value(log).expect("log serializes")
+}
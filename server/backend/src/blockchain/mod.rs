@@ -1,11 +1,15 @@
 // backend/src/blockchain/mod.rs
 
 pub mod adapter;
+pub mod listener;
+#[cfg(test)]
+pub(crate) mod mock;
 pub mod types;
 pub mod vrf;
 
-pub use adapter::BlockchainAdapter;
-pub use types::BlockchainSeed;
+pub use adapter::{BlockchainAdapter, VrfMode};
+pub use listener::VrfEventRouter;
+pub use types::{seed_hex, seed_weakness, BlockchainSeed, MasterSeed};
 pub use vrf::VrfRequest;
 
 // Re-export for convenience
@@ -1,12 +1,19 @@
 // backend/src/blockchain/types.rs
 
-use alloy::primitives::U256;
+use alloy::primitives::{hex, B256, U256};
 use serde::{Deserialize, Serialize};
+use zunnogame_lib::{derive_shuffle_seed, u256_to_bytes32, SeedDerivation};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockchainSeed {
     pub value: U256,
     pub request_id: U256,
+    /// Per-instance salt mixed into the VRF value before shuffling
+    #[serde(default)]
+    pub instance_salt: B256,
+    /// Set when `value` was derived from another game's VRF value instead of its own request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub master: Option<MasterSeed>,
 }
 
 impl Default for BlockchainSeed {
@@ -14,12 +21,144 @@ impl Default for BlockchainSeed {
         Self {
             value: U256::ZERO,
             request_id: U256::ZERO,
+            instance_salt: B256::ZERO,
+            master: None,
         }
     }
 }
 
-pub fn u256_to_bytes32(value: U256) -> [u8; 32] {
-    let mut bytes = [0u8; 32];
-    bytes.copy_from_slice(&value.to_be_bytes::<32>());
-    bytes
+/// A shared master VRF draw a game seed is derived from, see `derive_game_seed`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MasterSeed {
+    /// Game whose VRF fulfillment supplied the master value
+    pub master_session: String,
+    /// VRF request the master value was fulfilled for
+    pub request_id: U256,
+    pub master_value: U256,
+    /// Mixed into the master value: the session id of the derived game
+    pub context: String,
+}
+
+impl MasterSeed {
+    pub fn derivation(&self) -> SeedDerivation {
+        SeedDerivation {
+            master_value: u256_to_bytes32(self.master_value),
+            context: self.context.as_bytes().to_vec(),
+        }
+    }
+
+    /// Seed value of the derived game
+    pub fn value(&self) -> U256 {
+        U256::from_be_bytes(self.derivation().seed())
+    }
+}
+
+/// A seed as 0x-prefixed, zero-padded 32-byte hex: the `bytes32` form committed in proofs
+///
+/// Round-trips through `U256::from_str`.
+pub fn seed_hex(value: U256) -> String {
+    hex::encode_prefixed(u256_to_bytes32(value))
+}
+
+/// Cheap heuristic for a VRF value that looks too structured to be random
+///
+/// Returns why the value looks weak: zero, fewer than `min_bits` significant bits,
+/// or an obvious repeating byte pattern. Not a statistical test, just a tripwire for
+/// a misconfigured VRF.
+pub fn seed_weakness(value: U256, min_bits: usize) -> Option<String> {
+    if value.is_zero() {
+        return Some("value is zero".to_string());
+    }
+
+    if value.bit_len() < min_bits {
+        return Some(format!(
+            "only {} significant bits (expected at least {})",
+            value.bit_len(),
+            min_bits
+        ));
+    }
+
+    let bytes = u256_to_bytes32(value);
+    if bytes.iter().all(|&byte| byte == bytes[0]) {
+        return Some(format!("every byte is 0x{:02x}", bytes[0]));
+    }
+    if bytes[..16] == bytes[16..] {
+        return Some("both 128-bit halves are identical".to_string());
+    }
+
+    None
+}
+
+impl BlockchainSeed {
+    /// Seed actually used for the deal
+    pub fn shuffle_seed(&self) -> [u8; 32] {
+        derive_shuffle_seed(u256_to_bytes32(self.value), self.instance_salt.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_is_always_weak() {
+        assert_eq!(
+            seed_weakness(U256::ZERO, 0).as_deref(),
+            Some("value is zero")
+        );
+        assert!(seed_weakness(U256::ZERO, 128).is_some());
+    }
+
+    #[test]
+    fn small_and_patterned_values_are_weak() {
+        let small = seed_weakness(U256::from(12345u64), 128).unwrap();
+        assert!(small.contains("only 14 significant bits"), "{}", small);
+
+        let repeated = seed_weakness(U256::from_be_bytes([0xab; 32]), 128).unwrap();
+        assert!(repeated.contains("every byte is 0xab"), "{}", repeated);
+
+        let mut halves = [0u8; 32];
+        for half in [0, 16] {
+            halves[half] = 0x9c;
+            halves[half + 15] = 0x01;
+        }
+        let mirrored = seed_weakness(U256::from_be_bytes(halves), 128).unwrap();
+        assert!(mirrored.contains("halves are identical"), "{}", mirrored);
+    }
+
+    #[test]
+    fn a_full_256_bit_value_passes() {
+        let value = U256::from_be_bytes(alloy::primitives::keccak256(b"vrf").0);
+
+        assert_eq!(seed_weakness(value, 128), None);
+        assert_eq!(seed_weakness(value, 200), None);
+    }
+
+    #[test]
+    fn seed_hex_round_trips_to_the_same_value() {
+        let vrf = U256::from_be_bytes(alloy::primitives::keccak256(b"vrf").0);
+
+        for value in [
+            U256::ZERO,
+            U256::from(1u8),
+            U256::from(0xabcdu64),
+            vrf,
+            U256::MAX,
+        ] {
+            let hex = seed_hex(value);
+            assert_eq!(hex.len(), 66, "{}", hex);
+            assert!(hex.starts_with("0x"), "{}", hex);
+            assert_eq!(hex.parse::<U256>().unwrap(), value);
+            assert_eq!(hex.parse::<B256>().unwrap().0, u256_to_bytes32(value));
+        }
+    }
+
+    #[test]
+    fn seed_hex_is_zero_padded_lowercase() {
+        assert_eq!(
+            seed_hex(U256::from(0xabcdu64)),
+            format!("0x{:0>64}", "abcd")
+        );
+        assert_eq!(seed_hex(U256::MAX), format!("0x{}", "f".repeat(64)));
+    }
 }
@@ -1,14 +1,17 @@
 // backend/src/blockchain/vrf.rs
 
-use super::adapter::BlockchainAdapter;
+use super::adapter::{BlockchainAdapter, VrfMode, WsProvider};
 use alloy::{
-    primitives::U256,
+    eips::BlockId,
+    primitives::{Bytes, B256, U256, U64},
     providers::Provider,
     rpc::types::{BlockNumberOrTag, Filter},
     sol,
+    transports::{RpcError, TransportError, TransportErrorKind},
 };
-use alloy_sol_types::SolEvent;
+use alloy_sol_types::{SolCall, SolEvent};
 use anyhow::{anyhow, Result};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use tokio_stream::StreamExt;
 
@@ -35,6 +38,43 @@ sol! {
 // TYPE DEFINITIONS
 // ============================================================================
 
+/// Unsubscribes a log subscription when dropped
+///
+/// `wait_for_vrf_event_ws` runs under a timeout; when it fires the future is dropped
+/// mid-stream, and without this the node keeps the subscription alive.
+pub(super) struct LogSubscriptionGuard<'a> {
+    provider: WsProvider,
+    id: B256,
+    active: &'a AtomicUsize,
+}
+
+impl<'a> LogSubscriptionGuard<'a> {
+    pub(super) fn new(provider: WsProvider, id: B256, active: &'a AtomicUsize) -> Self {
+        let open = active.fetch_add(1, Ordering::Relaxed) + 1;
+        tracing::debug!(subscription_id = %id, active = open, "Log subscription opened");
+        Self {
+            provider,
+            id,
+            active,
+        }
+    }
+}
+
+impl Drop for LogSubscriptionGuard<'_> {
+    fn drop(&mut self) {
+        if let Err(e) = self.provider.unsubscribe(self.id) {
+            tracing::warn!(
+                subscription_id = %self.id,
+                error = %e,
+                "Failed to unsubscribe from logs"
+            );
+        }
+
+        let open = self.active.fetch_sub(1, Ordering::Relaxed) - 1;
+        tracing::debug!(subscription_id = %self.id, active = open, "Log subscription closed");
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct VrfRequest {
     pub request_id: U256,
@@ -45,24 +85,103 @@ pub struct VrfRequest {
 // VRF REQUEST OPERATIONS
 // ============================================================================
 
+/// Whether a failed batch shows the endpoint can't take batches, not a passing fault
+///
+/// An error reply, a reply that isn't a batch, or a client error status all come back the
+/// same way every time; dropped connections, timeouts and 5xx/429 statuses don't.
+fn batch_unsupported(error: &TransportError) -> bool {
+    match error {
+        RpcError::ErrorResp(_) | RpcError::DeserError { .. } | RpcError::UnsupportedFeature(_) => {
+            true
+        }
+        RpcError::Transport(TransportErrorKind::MissingBatchResponse(_)) => true,
+        RpcError::Transport(TransportErrorKind::HttpError(e)) => {
+            (400..500).contains(&e.status) && e.status != 429
+        }
+        _ => false,
+    }
+}
+
 impl BlockchainAdapter {
     /// Request VRF randomness from the contract
+    ///
+    /// The call and the block-number query go out as a single JSON-RPC batch. If the
+    /// endpoint rejects batches, batching is switched off and both are sent one by one; any
+    /// other batch failure falls back for this request only.
     pub async fn request_vrf(&self) -> Result<VrfRequest> {
-        // Use HTTP provider for transaction sending
-        let contract = UnoGame::new(self.contract_address, self.get_http_provider());
-
         tracing::info!("Requesting VRF randomness from contract");
 
-        let tx_builder = contract.requestRandomWords();
-        let request_id = tx_builder.call().await?;
-        let block_number = self.http_provider.get_block_number().await?;
+        let request = if self.rpc_batching() {
+            match self.request_vrf_batched().await {
+                Ok(request) => request?,
+                Err(e) if batch_unsupported(&e) => {
+                    tracing::warn!(
+                        error = %e,
+                        "RPC endpoint rejected a batch request; using sequential calls"
+                    );
+                    self.rpc_batching.store(false, Ordering::Relaxed);
+                    self.request_vrf_sequential().await?
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        error = %e,
+                        "Batch request failed; retrying this one sequentially"
+                    );
+                    self.request_vrf_sequential().await?
+                }
+            }
+        } else {
+            self.request_vrf_sequential().await?
+        };
 
         tracing::info!(
-            request_id = %request_id,
-            block_number = block_number,
+            request_id = %request.request_id,
+            block_number = request.block_number,
             "VRF request initiated"
         );
 
+        Ok(request)
+    }
+
+    /// `requestRandomWords` and `eth_blockNumber` as one JSON-RPC batch
+    ///
+    /// The outer error means the batch itself failed (e.g. batching is unsupported); the
+    /// inner one is an ordinary failure of either call.
+    async fn request_vrf_batched(&self) -> std::result::Result<Result<VrfRequest>, TransportError> {
+        let tx = UnoGame::new(self.contract_address, self.get_http_provider())
+            .requestRandomWords()
+            .into_transaction_request();
+
+        let http_provider = self.get_http_provider();
+        let mut batch = http_provider.client().new_batch();
+        let call_output = batch.add_call::<_, Bytes>("eth_call", &(tx, BlockId::latest()))?;
+        let block_number = batch.add_call::<_, U64>("eth_blockNumber", &())?;
+        batch.send().await?;
+
+        let request = async {
+            let output = call_output.await?;
+            let request_id = UnoGame::requestRandomWordsCall::abi_decode_returns(&output)?;
+            let block_number = block_number.await?.to::<u64>();
+
+            Ok::<_, anyhow::Error>(VrfRequest {
+                request_id,
+                block_number,
+            })
+        }
+        .await;
+
+        Ok(request)
+    }
+
+    /// `requestRandomWords` then `eth_blockNumber`, one round-trip each
+    async fn request_vrf_sequential(&self) -> Result<VrfRequest> {
+        // Use HTTP provider for transaction sending
+        let contract = UnoGame::new(self.contract_address, self.get_http_provider());
+
+        let tx_builder = contract.requestRandomWords();
+        let request_id = tx_builder.call().await?;
+        let block_number = self.get_http_provider().get_block_number().await?;
+
         Ok(VrfRequest {
             request_id,
             block_number,
@@ -79,7 +198,7 @@ impl BlockchainAdapter {
     }
 
     /// Check if VRF event already occurred (missed event detection)
-    async fn check_for_missed_event(
+    pub(crate) async fn check_for_missed_event(
         &self,
         request_id: U256,
         from_block: u64,
@@ -93,7 +212,7 @@ impl BlockchainAdapter {
         let filter = self.create_vrf_filter(request_id, from_block);
 
         // Use HTTP provider for historical query
-        let logs = self.http_provider.get_logs(&filter).await?;
+        let logs = self.get_http_provider().get_logs(&filter).await?;
 
         if let Some(log) = logs.first() {
             if let Ok(event) = RequestFulfilled::decode_log(&log.inner) {
@@ -120,12 +239,14 @@ impl BlockchainAdapter {
         let filter = self.create_vrf_filter(request_id, from_block);
 
         // Use WebSocket provider for real-time subscription
-        let sub = self
-            .ws_provider
+        let ws_provider = self.get_ws_provider();
+        let sub = ws_provider
             .subscribe_logs(&filter)
             .await
             .map_err(|e| anyhow!("Failed to subscribe to logs: {}", e))?;
 
+        let _guard =
+            LogSubscriptionGuard::new(ws_provider, *sub.local_id(), &self.active_subscriptions);
         let mut stream = sub.into_stream();
 
         // Wait for the specific event
@@ -220,6 +341,8 @@ impl BlockchainAdapter {
     /// 1. Check for missed events (HTTP historical query)
     /// 2. Subscribe via WebSocket for real-time delivery (primary)
     /// 3. Fallback to HTTP polling if WebSocket fails
+    ///
+    /// With `VRF_MODE=polling`, step 2 is skipped and it goes straight to HTTP polling.
     pub async fn get_randomness(
         &self,
         request_id: U256,
@@ -238,7 +361,44 @@ impl BlockchainAdapter {
             return Ok(random_word);
         }
 
-        // Step 2: Try WebSocket subscription (primary method)
+        // Polling mode: skip subscriptions that are known not to work on this provider
+        if self.vrf_mode == VrfMode::Polling {
+            let poll_attempts = (timeout_secs / 3).max(5) as u32;
+            return self
+                .poll_random_words_http(request_id, poll_attempts, 3)
+                .await;
+        }
+
+        // Step 2: Wait on the shared listener when it's up, instead of a per-game subscription
+        if self.vrf_events.is_running() {
+            let receiver = self.vrf_events.register(request_id);
+            let result = tokio::time::timeout(Duration::from_secs(timeout_secs), receiver).await;
+            self.vrf_events.prune(request_id);
+
+            match result {
+                Ok(Ok(random_word)) => {
+                    tracing::info!("VRF received via persistent listener (optimal path)");
+                    return Ok(random_word);
+                }
+                Ok(Err(_)) => tracing::warn!("VRF listener dropped waiter, falling back"),
+                Err(_) => {
+                    // The event may have landed while the listener was resubscribing
+                    match self.check_for_missed_event(request_id, from_block).await {
+                        Ok(Some(random_word)) => return Ok(random_word),
+                        Ok(None) => {}
+                        Err(e) => tracing::warn!(
+                            request_id = %request_id,
+                            error = %e,
+                            "Missed-event recheck failed"
+                        ),
+                    }
+                    tracing::debug!(request_id = %request_id, "VRF not fulfilled within timeout");
+                    return Err(anyhow!("VRF not fulfilled within {}s", timeout_secs));
+                }
+            }
+        }
+
+        // Step 3: Try a dedicated WebSocket subscription
         let ws_result = tokio::time::timeout(
             Duration::from_secs(timeout_secs),
             self.wait_for_vrf_event_ws(request_id, from_block),
@@ -261,7 +421,7 @@ impl BlockchainAdapter {
             }
         }
 
-        // Step 3: Fallback to HTTP polling
+        // Step 4: Fallback to HTTP polling
         let poll_attempts = (timeout_secs / 3).max(5) as u32; // At least 5 attempts
         self.poll_random_words_http(request_id, poll_attempts, 3)
             .await
@@ -292,3 +452,278 @@ impl BlockchainAdapter {
         Ok(random_word)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::mock::{fulfilled_log, uint_result, MockPubSub, MockTransport};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    /// Hybrid adapter with its persistent listener subscribed to `node`
+    async fn listening_adapter(node: &MockPubSub, http: &MockTransport) -> Arc<BlockchainAdapter> {
+        let adapter = Arc::new(node.adapter(http, VrfMode::Hybrid).await);
+        tokio::spawn(adapter.clone().run_vrf_event_listener());
+        for _ in 0..100 {
+            if adapter.vrf_events.is_running() {
+                return adapter;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("VRF listener never subscribed");
+    }
+
+    /// Let the pubsub service deliver queued unsubscribes to the node
+    async fn settle(node: &MockPubSub, unsubscribes: usize) {
+        for _ in 0..100 {
+            if node.unsubscribes() == unsubscribes {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!(
+            "node saw {} unsubscribes, expected {}",
+            node.unsubscribes(),
+            unsubscribes
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_timed_out_subscription_is_unsubscribed() {
+        let node = MockPubSub::default();
+        let adapter = node.adapter(&MockTransport::new(), VrfMode::Hybrid).await;
+
+        let waited = tokio::time::timeout(
+            Duration::from_secs(30),
+            adapter.wait_for_vrf_event_ws(U256::from(7), 16),
+        )
+        .await;
+
+        assert!(waited.is_err(), "no fulfillment was ever sent");
+        assert_eq!(adapter.active_subscriptions(), 0);
+        settle(&node, 1).await;
+        assert_eq!(node.subscribes(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn subscriptions_stay_balanced_across_timed_out_games() {
+        let node = MockPubSub::default();
+        let http = MockTransport::new();
+        http.set("eth_getLogs", json!([]));
+        http.set("eth_call", uint_result(0));
+        let adapter = node.adapter(&http, VrfMode::Hybrid).await;
+
+        for game in 1..=3u64 {
+            // Subscription times out, then polling gives up on the unfulfilled request
+            let result = adapter.get_randomness(U256::from(game), 16, 6).await;
+            assert!(result.is_err());
+        }
+
+        assert_eq!(adapter.active_subscriptions(), 0);
+        settle(&node, 3).await;
+        assert_eq!(node.subscribes(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn concurrent_waiters_each_get_their_own_fulfillment() {
+        let node = MockPubSub::default();
+        let http = MockTransport::new();
+        http.set("eth_getLogs", json!([]));
+        let adapter = listening_adapter(&node, &http).await;
+
+        let waiter = |request_id: u64| {
+            let adapter = adapter.clone();
+            tokio::spawn(
+                async move { adapter.get_randomness(U256::from(request_id), 16, 60).await },
+            )
+        };
+        let (first, second) = (waiter(1), waiter(2));
+        // Both are registered with the listener once their missed-event checks are done
+        while http.calls("eth_getLogs") < 2 {
+            tokio::task::yield_now().await;
+        }
+
+        // Fulfilled in the opposite order to the requests
+        node.emit_log(fulfilled_log(U256::from(2), U256::from(222)));
+        node.emit_log(fulfilled_log(U256::from(1), U256::from(111)));
+
+        assert_eq!(first.await.unwrap().unwrap(), U256::from(111));
+        assert_eq!(second.await.unwrap().unwrap(), U256::from(222));
+        // One shared subscription served both games
+        assert_eq!(node.subscribes(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_listener_timeout_rechecks_for_a_missed_event() {
+        let node = MockPubSub::default();
+        let http = MockTransport::new();
+        // Not fulfilled at the first check; the event then lands while the listener is away
+        http.set("eth_getLogs", json!([]));
+        http.push(
+            "eth_getLogs",
+            json!([fulfilled_log(U256::from(7), U256::from(777))]),
+        );
+        let adapter = listening_adapter(&node, &http).await;
+
+        let random_word = adapter.get_randomness(U256::from(7), 16, 10).await.unwrap();
+
+        assert_eq!(random_word, U256::from(777));
+        assert_eq!(http.calls("eth_getLogs"), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_listener_timeout_without_any_event_is_an_error() {
+        let node = MockPubSub::default();
+        let http = MockTransport::new();
+        http.set("eth_getLogs", json!([]));
+        let adapter = listening_adapter(&node, &http).await;
+
+        let result = adapter.get_randomness(U256::from(7), 16, 10).await;
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("not fulfilled within 10s"));
+        // Checked before waiting and once more after the timeout, without polling
+        assert_eq!(http.calls("eth_getLogs"), 2);
+        assert_eq!(http.calls("eth_call"), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn polling_mode_never_subscribes() {
+        let node = MockPubSub::default();
+        let http = MockTransport::new();
+        http.set("eth_getLogs", json!([]));
+        http.set("eth_call", uint_result(0));
+        http.push("eth_call", uint_result(0));
+        http.push("eth_call", uint_result(99));
+        let adapter = node.adapter(&http, VrfMode::Polling).await;
+
+        let random_word = adapter.get_randomness(U256::from(7), 16, 30).await.unwrap();
+
+        assert_eq!(random_word, U256::from(99));
+        assert_eq!(http.calls("eth_call"), 3);
+        assert_eq!(node.subscribes(), 0);
+        assert_eq!(adapter.active_subscriptions(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn polling_mode_still_checks_for_a_missed_event_first() {
+        let node = MockPubSub::default();
+        let http = MockTransport::new();
+        http.set(
+            "eth_getLogs",
+            json!([fulfilled_log(U256::from(7), U256::from(777))]),
+        );
+        let adapter = node.adapter(&http, VrfMode::Polling).await;
+
+        let random_word = adapter.get_randomness(U256::from(7), 16, 30).await.unwrap();
+
+        assert_eq!(random_word, U256::from(777));
+        assert_eq!(http.calls("eth_call"), 0);
+        assert_eq!(node.subscribes(), 0);
+    }
+
+    fn vrf_node() -> MockTransport {
+        let http = MockTransport::new();
+        http.set("eth_call", uint_result(7));
+        http
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_vrf_request_goes_out_as_one_batch_when_supported() {
+        let http = vrf_node();
+        let adapter = http.adapter(VrfMode::Polling);
+
+        let request = adapter.request_vrf().await.unwrap();
+
+        assert_eq!(request.request_id, U256::from(7));
+        assert_eq!(request.block_number, 0x10);
+        assert_eq!(http.batches(), [2]);
+        assert_eq!(http.calls("eth_call"), 1);
+        assert_eq!(http.calls("eth_blockNumber"), 1);
+        assert!(adapter.rpc_batching());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_rejected_batch_falls_back_to_sequential_calls_for_good() {
+        let http = vrf_node();
+        http.reject_batches();
+        let adapter = http.adapter(VrfMode::Polling);
+
+        for _ in 0..2 {
+            let request = adapter.request_vrf().await.unwrap();
+            assert_eq!(request.request_id, U256::from(7));
+            assert_eq!(request.block_number, 0x10);
+        }
+
+        // Only the first request tried a batch
+        assert_eq!(http.batches(), [2]);
+        assert_eq!(http.calls("eth_call"), 2);
+        assert_eq!(http.calls("eth_blockNumber"), 2);
+        assert!(!adapter.rpc_batching());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_dropped_batch_keeps_batching_on() {
+        let http = vrf_node();
+        http.fail_batches(1);
+        let adapter = http.adapter(VrfMode::Polling);
+
+        for _ in 0..2 {
+            let request = adapter.request_vrf().await.unwrap();
+            assert_eq!(request.request_id, U256::from(7));
+        }
+
+        // The first request fell back after its batch failed; the second batched again
+        assert_eq!(http.batches(), [2, 2]);
+        assert_eq!(http.calls("eth_call"), 2);
+        assert!(adapter.rpc_batching());
+    }
+
+    #[test]
+    fn only_a_refusal_counts_as_unsupported_batching() {
+        let unsupported = [
+            TransportErrorKind::http_error(405, "method not allowed".to_string()),
+            TransportErrorKind::http_error(400, "batch requests are not supported".to_string()),
+            RpcError::ErrorResp(alloy::rpc::json_rpc::ErrorPayload {
+                code: -32600,
+                message: "batch not supported".into(),
+                data: None,
+            }),
+        ];
+        let transient = [
+            TransportErrorKind::custom_str("connection reset by peer"),
+            TransportErrorKind::backend_gone(),
+            TransportErrorKind::http_error(503, "unavailable".to_string()),
+            TransportErrorKind::http_error(429, "slow down".to_string()),
+        ];
+
+        for error in &unsupported {
+            assert!(batch_unsupported(error), "{}", error);
+        }
+        for error in &transient {
+            assert!(!batch_unsupported(error), "{}", error);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn batching_can_be_switched_off() {
+        let http = vrf_node();
+        let adapter = BlockchainAdapter::with_providers(
+            http.provider(),
+            http.provider(),
+            "ws://mock".to_string(),
+            url::Url::parse("http://mock").unwrap(),
+            crate::blockchain::mock::MOCK_CONTRACT,
+            VrfMode::Polling,
+            false,
+        );
+
+        let request = adapter.request_vrf().await.unwrap();
+
+        assert_eq!(request.request_id, U256::from(7));
+        assert!(http.batches().is_empty());
+        assert_eq!(http.calls("eth_call"), 1);
+    }
+}
@@ -0,0 +1,203 @@
+// backend/src/game/card_names.rs
+
+use anyhow::{anyhow, Context, Result};
+use std::path::Path;
+use std::sync::OnceLock;
+
+use super::{DECK_SIZE, PACK_OF_CARDS};
+
+/// Client-facing card names, overriding `PACK_OF_CARDS` when configured
+static CARD_NAMES: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Name sent to clients for a card index
+///
+/// Game rules always use `PACK_OF_CARDS`; this mapping only affects conversions.
+pub fn card_name(index: u8) -> &'static str {
+    match active_card_names() {
+        Some(names) => names[index as usize].as_str(),
+        None => PACK_OF_CARDS[index as usize],
+    }
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Per-thread mapping so tests can exercise custom names without touching `CARD_NAMES`
+    static TEST_CARD_NAMES: std::cell::Cell<Option<&'static [String]>> =
+        const { std::cell::Cell::new(None) };
+}
+
+fn active_card_names() -> Option<&'static [String]> {
+    #[cfg(test)]
+    if let Some(names) = TEST_CARD_NAMES.with(std::cell::Cell::get) {
+        return Some(names);
+    }
+
+    CARD_NAMES.get().map(Vec::as_slice)
+}
+
+/// Check that a mapping names every card in the deck
+pub fn validate_card_names(names: &[String]) -> Result<()> {
+    if names.len() != DECK_SIZE {
+        return Err(anyhow!(
+            "Card name mapping must have exactly {} entries, got {}",
+            DECK_SIZE,
+            names.len()
+        ));
+    }
+
+    if let Some(position) = names.iter().position(|name| name.trim().is_empty()) {
+        return Err(anyhow!("Card name at index {} is empty", position));
+    }
+
+    Ok(())
+}
+
+/// Read a JSON array of card names from disk and validate it
+pub fn load_card_names_file(path: impl AsRef<Path>) -> Result<Vec<String>> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read card names file {}", path.display()))?;
+    let names: Vec<String> = serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid card names file {}", path.display()))?;
+
+    validate_card_names(&names)?;
+    Ok(names)
+}
+
+/// Install a custom mapping; can only be done once, before serving requests
+pub fn set_card_names(names: Vec<String>) -> Result<()> {
+    validate_card_names(&names)?;
+    CARD_NAMES
+        .set(names)
+        .map_err(|_| anyhow!("Card names already configured"))
+}
+
+/// Load `CARD_NAMES_FILE` if set; otherwise keep the default `PACK_OF_CARDS` names
+pub fn init_card_names_from_env() -> Result<()> {
+    match std::env::var("CARD_NAMES_FILE") {
+        Ok(path) if !path.trim().is_empty() => {
+            let names = load_card_names_file(path.trim())?;
+            set_card_names(names)?;
+            tracing::info!(path = %path.trim(), "Loaded custom card names");
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{convert_distributed_cards_to_js, index_to_card, GameState, GameStateJS};
+
+    /// `red_5`-style names, the vocabulary of a frontend other than the default one
+    fn snake_case_names() -> Vec<String> {
+        PACK_OF_CARDS
+            .iter()
+            .enumerate()
+            .map(|(index, name)| format!("{}_{}", name.to_lowercase(), index))
+            .collect()
+    }
+
+    /// Use `names` for conversions on this test's thread
+    fn install_for_this_thread(names: Vec<String>) {
+        validate_card_names(&names).unwrap();
+        let names: &'static [String] = Vec::leak(names);
+        TEST_CARD_NAMES.with(|cell| cell.set(Some(names)));
+    }
+
+    fn temp_file(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "zunno-card-names-{}-{}.json",
+            std::process::id(),
+            name
+        ));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn conversions_use_the_default_names_without_a_mapping() {
+        assert_eq!(index_to_card(9), PACK_OF_CARDS[9]);
+        assert_eq!(card_name(107), PACK_OF_CARDS[107]);
+    }
+
+    #[test]
+    fn a_custom_mapping_is_applied_in_conversions() {
+        install_for_this_thread(snake_case_names());
+
+        assert_eq!(
+            index_to_card(0),
+            format!("{}_0", PACK_OF_CARDS[0].to_lowercase())
+        );
+        assert_eq!(
+            convert_distributed_cards_to_js(&[vec![9], vec![107]]),
+            [
+                vec![format!("{}_9", PACK_OF_CARDS[9].to_lowercase())],
+                vec![format!("{}_107", PACK_OF_CARDS[107].to_lowercase())],
+            ]
+        );
+
+        let game = GameState {
+            player_hands: vec![vec![1], vec![2]],
+            draw_pile: vec![3],
+            discard_pile: vec![4],
+            ..GameState::new()
+        };
+        let js = GameStateJS::from(&game);
+        assert_eq!(
+            js.draw_pile,
+            [format!("{}_3", PACK_OF_CARDS[3].to_lowercase())]
+        );
+        assert_eq!(
+            js.discard_pile,
+            [format!("{}_4", PACK_OF_CARDS[4].to_lowercase())]
+        );
+    }
+
+    #[test]
+    fn a_mapping_file_is_loaded() {
+        let names = snake_case_names();
+        let path = temp_file("valid", &serde_json::to_string(&names).unwrap());
+
+        let loaded = load_card_names_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, names);
+    }
+
+    #[test]
+    fn a_wrong_length_file_is_rejected() {
+        let mut names = snake_case_names();
+        names.pop();
+        let path = temp_file("short", &serde_json::to_string(&names).unwrap());
+
+        let error = load_card_names_file(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(
+            error.to_string().contains("exactly 108 entries, got 107"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn blank_names_and_malformed_files_are_rejected() {
+        let mut names = snake_case_names();
+        names[12] = "  ".to_string();
+        assert!(validate_card_names(&names)
+            .unwrap_err()
+            .to_string()
+            .contains("index 12"));
+
+        let path = temp_file("malformed", "{\"not\": \"an array\"}");
+        let error = load_card_names_file(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(
+            error.to_string().contains("Invalid card names file"),
+            "{}",
+            error
+        );
+    }
+}
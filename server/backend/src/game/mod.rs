@@ -1,26 +1,42 @@
 // backend/src/game/mod.rs
 
+mod card_names;
 mod operations;
+mod replay;
+mod rules;
 mod state;
 
+pub use card_names::{
+    card_name, init_card_names_from_env, load_card_names_file, set_card_names, validate_card_names,
+};
 pub use operations::{
-    draw_card, draw_multiple_cards, get_initial_hands, get_initial_hands_ref, play_card,
+    draw_card, draw_multiple_cards, draw_multiple_cards_once, force_reshuffle, get_initial_hands,
+    get_initial_hands_ref, pass_turn, play_card, playable_cards, reshuffle_discard_into_draw,
+    set_active_color,
+};
+pub use replay::{replay, GameAction, GameReplay};
+pub use rules::{
+    card_color, card_kind, card_value, is_playable, is_wild, CardHistogram, CardKind, Color,
+};
+pub use state::{
+    seed_starting_player, AppliedDraw, CardOrigin, DeckHealth, Direction, DiscardVisibility,
+    GameOutcome, GameState, OpponentInfo, PlayerId, MAX_RECENT_ACTIONS,
 };
-pub use state::{GameState, PlayerId, PACK_OF_CARDS};
 
 // Re-export from lib for convenience
 pub use zunnogame_lib::{
-    perform_shuffle, validate_game_params, ShuffleOutcome, DECK_SIZE, MAX_CARDS_PER_PLAYER,
-    MAX_PLAYERS,
+    is_valid_permutation, perform_shuffle, validate_game_params, validate_game_params_all,
+    ShuffleOutcome, ValidationIssue, DECK_SIZE, MAX_CARDS_PER_PLAYER, MAX_PLAYERS, PACK_OF_CARDS,
 };
 
+use crate::blockchain::seed_hex;
 use alloy::primitives::U256;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
 /// Convert index to card string (matches JavaScript side)
 pub fn index_to_card(index: u8) -> &'static str {
-    PACK_OF_CARDS[index as usize]
+    card_name(index)
 }
 
 // pub async fn shuffle_and_deal(num_players: u8, cards_per_player: u8) -> Result<GameState> {
@@ -65,7 +81,7 @@ pub fn index_to_card(index: u8) -> &'static str {
 pub fn convert_indexes_to_js_cards(card_indexes: &[u8]) -> Vec<String> {
     card_indexes
         .iter()
-        .map(|&index| PACK_OF_CARDS[index as usize].to_string())
+        .map(|&index| card_name(index).to_string())
         .collect()
 }
 
@@ -86,6 +102,9 @@ pub struct GameStateJS {
     pub discard_pile: Vec<String>,
     pub is_shuffled: bool,
     pub seed_used: U256,
+    /// `seed_used` as padded 32-byte hex, as committed in the proof's public values
+    pub seed_hex: String,
+    pub theme: String,
 }
 
 impl From<&GameState> for GameStateJS {
@@ -96,13 +115,42 @@ impl From<&GameState> for GameStateJS {
             discard_pile: convert_indexes_to_js_cards(&game_state.discard_pile),
             is_shuffled: game_state.is_shuffled,
             seed_used: game_state.seed_metadata.value,
+            seed_hex: seed_hex(game_state.seed_metadata.value),
+            theme: game_state.theme.clone(),
+        }
+    }
+}
+
+/// Entire game state as raw `u8` deck indices, the counterpart of `GameStateJS`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameStateRaw {
+    pub player_hands: Vec<Vec<u8>>,
+    pub draw_pile: Vec<u8>,
+    pub discard_pile: Vec<u8>,
+    pub is_shuffled: bool,
+    pub seed_used: U256,
+    /// `seed_used` as padded 32-byte hex, as committed in the proof's public values
+    pub seed_hex: String,
+    pub theme: String,
+}
+
+impl From<&GameState> for GameStateRaw {
+    fn from(game_state: &GameState) -> Self {
+        Self {
+            player_hands: game_state.player_hands.clone(),
+            draw_pile: game_state.draw_pile.clone(),
+            discard_pile: game_state.discard_pile.clone(),
+            is_shuffled: game_state.is_shuffled,
+            seed_used: game_state.seed_metadata.value,
+            seed_hex: seed_hex(game_state.seed_metadata.value),
+            theme: game_state.theme.clone(),
         }
     }
 }
 
 /// Convert single card index to JavaScript format
 pub fn convert_card_to_js(card_index: u8) -> String {
-    PACK_OF_CARDS[card_index as usize].to_string()
+    card_name(card_index).to_string()
 }
 
 /// Optimized conversion for API responses (avoids intermediate allocations)
@@ -113,3 +161,80 @@ pub fn serialize_player_hand_js(
     let hand = get_initial_hands_ref(game_state, player_id)?;
     Ok(convert_indexes_to_js_cards(hand))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dealt_game() -> GameState {
+        let outcome = perform_shuffle(3, 7, [0x21; 32]).unwrap();
+        let mut game_state = GameState {
+            player_hands: outcome.player_hands,
+            draw_pile: outcome.draw_pile,
+            is_shuffled: true,
+            ..GameState::new()
+        };
+        game_state.seed_metadata.value = U256::from(0xabcdu64);
+        let top = game_state.draw_pile.pop().unwrap();
+        game_state.discard_pile.push(top);
+        game_state
+    }
+
+    #[test]
+    fn raw_state_keeps_every_index() {
+        let game_state = dealt_game();
+
+        let raw = GameStateRaw::from(&game_state);
+
+        assert_eq!(raw.player_hands, game_state.player_hands);
+        assert_eq!(raw.draw_pile, game_state.draw_pile);
+        assert_eq!(raw.discard_pile, game_state.discard_pile);
+        assert!(raw.is_shuffled);
+        assert_eq!(raw.seed_used, U256::from(0xabcdu64));
+        assert_eq!(raw.seed_hex, format!("0x{:0>64}", "abcd"));
+        assert_eq!(raw.theme, game_state.theme);
+        let mut cards: Vec<u8> = raw.player_hands.concat();
+        cards.extend(&raw.draw_pile);
+        cards.extend(&raw.discard_pile);
+        assert!(is_valid_permutation(&cards, DECK_SIZE));
+    }
+
+    #[test]
+    fn raw_state_serializes_cards_as_numbers() {
+        let game_state = dealt_game();
+
+        let json = serde_json::to_value(GameStateRaw::from(&game_state)).unwrap();
+
+        for (pile, expected) in [
+            ("draw_pile", &game_state.draw_pile),
+            ("discard_pile", &game_state.discard_pile),
+        ] {
+            let cards: Vec<u8> = json[pile]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|card| card.as_u64().unwrap() as u8)
+                .collect();
+            assert_eq!(&cards, expected);
+        }
+        let hands = json["player_hands"].as_array().unwrap();
+        assert_eq!(hands.len(), 3);
+        assert!(hands
+            .iter()
+            .flat_map(|hand| hand.as_array().unwrap())
+            .all(serde_json::Value::is_u64));
+        let round_trip: GameStateRaw = serde_json::from_value(json).unwrap();
+        assert_eq!(round_trip, GameStateRaw::from(&game_state));
+    }
+
+    #[test]
+    fn js_state_carries_the_same_seed_hex() {
+        let game_state = dealt_game();
+
+        let json = serde_json::to_value(GameStateJS::from(&game_state)).unwrap();
+
+        let hex = json["seed_hex"].as_str().unwrap();
+        assert_eq!(hex, GameStateRaw::from(&game_state).seed_hex);
+        assert_eq!(hex.parse::<U256>().unwrap(), game_state.seed_metadata.value);
+    }
+}
@@ -1,35 +1,66 @@
 // backend/src/game/operations.rs
 
-use super::{GameState, PlayerId};
+use super::replay::GameAction;
+use super::rules::{card_kind, is_playable, is_wild, CardKind, Color};
+use super::{card_name, CardOrigin, GameOutcome, GameState, PlayerId};
 use crate::orchestrator::u256_to_bytes32;
 use alloy::primitives::U256;
 use anyhow::{anyhow, Result};
+use uuid::Uuid;
 use zunnogame_lib::shuffle_deck;
 
+/// Reject moves on a game that already has an outcome
+fn ensure_not_finished(game_state: &GameState) -> Result<()> {
+    match &game_state.outcome {
+        Some(outcome) => Err(anyhow!("Game is over: {:?}", outcome)),
+        None => Ok(()),
+    }
+}
+
+/// End the game as a draw when it can no longer progress (if enabled for this game)
+fn end_if_stuck(game_state: &mut GameState) {
+    if game_state.auto_end_when_stuck && !game_state.is_finished() && game_state.is_stuck() {
+        game_state.outcome = Some(GameOutcome::Draw {
+            reason: "Deck exhausted and no player can move".to_string(),
+        });
+    }
+}
+
 /// Draw a single card for a player
 pub fn draw_card(game_state: &mut GameState, player_id: PlayerId) -> Result<u8> {
+    draw_one(game_state, player_id, false)
+}
+
+/// Draw one card; `penalty` draws (Draw 2, Draw 4) ignore and don't count toward the turn limit
+fn draw_one(game_state: &mut GameState, player_id: PlayerId, penalty: bool) -> Result<u8> {
     if !game_state.is_initialized() {
         return Err(anyhow!("Game not initialized"));
     }
 
+    ensure_not_finished(game_state)?;
+
     if !game_state.is_valid_player(player_id) {
         return Err(anyhow!("Invalid player ID"));
     }
 
+    game_state.check_turn(player_id)?;
+    if let Some(limit) = game_state.max_draws_per_turn.filter(|_| !penalty) {
+        if game_state.enforce_turns && game_state.draws_this_turn >= limit {
+            return Err(anyhow!(
+                "Draw limit reached: at most {} draw(s) per turn",
+                limit
+            ));
+        }
+    }
+
     // Handle empty draw pile (reshuffle discard)
     if game_state.draw_pile.is_empty() {
         if game_state.discard_pile.len() <= 1 {
+            end_if_stuck(game_state);
             return Err(anyhow!("No cards available"));
         }
 
-        let top_card = game_state.discard_pile.pop().unwrap();
-        game_state.draw_pile.append(&mut game_state.discard_pile);
-        game_state.discard_pile.push(top_card);
-
-        // Reshuffle with derived seed
-        let new_seed = game_state.seed_metadata.value.wrapping_add(U256::from(1));
-        let new_seed_bytes = u256_to_bytes32(new_seed);
-        shuffle_deck(&mut game_state.draw_pile, new_seed_bytes);
+        reshuffle_discard_into_draw(game_state)?;
     }
 
     let card = game_state
@@ -38,14 +69,70 @@ pub fn draw_card(game_state: &mut GameState, player_id: PlayerId) -> Result<u8>
         .ok_or_else(|| anyhow!("Draw pile empty"))?;
 
     game_state.player_hands[player_id as usize].push(card);
+    if let Some(origins) = game_state.card_origin.get_mut(player_id as usize) {
+        origins.push(CardOrigin::Drawn);
+    }
+    if !penalty {
+        game_state.draws_this_turn = game_state.draws_this_turn.saturating_add(1);
+    }
+    game_state.history.push(GameAction::Draw { player_id });
+    end_if_stuck(game_state);
     Ok(card)
 }
 
-// Draw multiple cards (for Draw 2, Draw 4 penalties)
+/// Move every discard except the top card into the draw pile and reshuffle it
+///
+/// The shuffle seed is derived from the game seed and the reshuffle count, so the
+/// n-th reshuffle of a game is always the same permutation.
+pub fn reshuffle_discard_into_draw(game_state: &mut GameState) -> Result<()> {
+    if game_state.discard_pile.len() <= 1 {
+        return Err(anyhow!(
+            "Nothing to reshuffle: discard pile has {} card(s)",
+            game_state.discard_pile.len()
+        ));
+    }
+
+    let top_card = game_state.discard_pile.pop().unwrap();
+    game_state.draw_pile.append(&mut game_state.discard_pile);
+    game_state.discard_pile.push(top_card);
+
+    // Reshuffle with derived seed
+    game_state.reshuffle_count += 1;
+    let new_seed = game_state
+        .seed_metadata
+        .value
+        .wrapping_add(U256::from(game_state.reshuffle_count));
+    let new_seed_bytes = u256_to_bytes32(new_seed);
+    shuffle_deck(&mut game_state.draw_pile, new_seed_bytes);
+
+    Ok(())
+}
+
+/// Debug operation: reshuffle the discard pile into the draw pile right now
+pub fn force_reshuffle(game_state: &mut GameState) -> Result<()> {
+    if !game_state.is_initialized() {
+        return Err(anyhow!("Game has not been initialized yet"));
+    }
+
+    reshuffle_discard_into_draw(game_state)?;
+    game_state.history.push(GameAction::Reshuffle);
+    Ok(())
+}
+
+// Draw multiple cards (for Draw 2, Draw 4 penalties), exempt from `max_draws_per_turn`
 pub fn draw_multiple_cards(
     game_state: &mut GameState,
     player_id: PlayerId,
     count: u8,
+) -> Result<Vec<u8>> {
+    draw_many(game_state, player_id, count, true)
+}
+
+fn draw_many(
+    game_state: &mut GameState,
+    player_id: PlayerId,
+    count: u8,
+    penalty: bool,
 ) -> Result<Vec<u8>> {
     if count == 0 {
         return Ok(Vec::new());
@@ -53,13 +140,91 @@ pub fn draw_multiple_cards(
 
     let mut drawn_cards = Vec::with_capacity(count as usize);
     for _ in 0..count {
-        let card = draw_card(game_state, player_id)?;
+        let card = draw_one(game_state, player_id, penalty)?;
         drawn_cards.push(card);
     }
     Ok(drawn_cards)
 }
 
+/// Reject a voluntary draw of `count` cards up front if any of them would fail
+///
+/// Checked before the first card moves, so a draw either applies whole or not at all.
+/// That includes `max_draws_per_turn`, which penalty draws don't go through.
+fn check_can_draw(game_state: &mut GameState, player_id: PlayerId, count: u8) -> Result<()> {
+    if !game_state.is_initialized() {
+        return Err(anyhow!("Game not initialized"));
+    }
+
+    ensure_not_finished(game_state)?;
+
+    if !game_state.is_valid_player(player_id) {
+        return Err(anyhow!("Invalid player ID"));
+    }
+
+    game_state.check_turn(player_id)?;
+    if let Some(limit) = game_state.max_draws_per_turn {
+        let left = limit.saturating_sub(game_state.draws_this_turn);
+        if game_state.enforce_turns && count > left {
+            return Err(anyhow!(
+                "Draw limit reached: at most {} draw(s) per turn ({} left)",
+                limit,
+                left
+            ));
+        }
+    }
+
+    // Everything but the top discard can be reshuffled back in
+    let available = game_state.draw_pile.len() + game_state.discard_pile.len().saturating_sub(1);
+    if available == 0 {
+        end_if_stuck(game_state);
+        return Err(anyhow!("No cards available"));
+    }
+    if count as usize > available {
+        return Err(anyhow!(
+            "Cannot draw {} card(s): only {} left to draw",
+            count,
+            available
+        ));
+    }
+
+    Ok(())
+}
+
+/// Draw `count` cards, applying each client action id at most once
+///
+/// A repeated `action_id` returns the cards drawn the first time instead of drawing again.
+/// A new draw that can't be completed in full draws nothing.
+pub fn draw_multiple_cards_once(
+    game_state: &mut GameState,
+    player_id: PlayerId,
+    count: u8,
+    action_id: Option<Uuid>,
+) -> Result<Vec<u8>> {
+    let Some(action_id) = action_id else {
+        check_can_draw(game_state, player_id, count)?;
+        return draw_many(game_state, player_id, count, false);
+    };
+
+    if let Some(applied) = game_state.applied_draw(action_id) {
+        if applied.player_id != player_id {
+            return Err(anyhow!(
+                "Action id {} was already used by player {}",
+                action_id,
+                applied.player_id
+            ));
+        }
+        return Ok(applied.cards.clone());
+    }
+
+    check_can_draw(game_state, player_id, count)?;
+    let drawn = draw_many(game_state, player_id, count, false)?;
+    game_state.record_draw(action_id, player_id, drawn.clone());
+    Ok(drawn)
+}
+
 /// Play a card to the discard pile
+///
+/// The card must be legal on the top discard by the same rule `playable_cards` lists.
 pub fn play_card(game_state: &mut GameState, player_id: PlayerId, card_index: usize) -> Result<u8> {
     if !game_state.is_initialized() {
         return Err(anyhow!("Game has not been initialized yet"));
@@ -69,6 +234,9 @@ pub fn play_card(game_state: &mut GameState, player_id: PlayerId, card_index: us
         return Err(anyhow!("Player {} not found", player_id));
     }
 
+    ensure_not_finished(game_state)?;
+    game_state.check_turn(player_id)?;
+
     let player_index = player_id as usize;
     let hand = &mut game_state.player_hands[player_index];
 
@@ -80,12 +248,86 @@ pub fn play_card(game_state: &mut GameState, player_id: PlayerId, card_index: us
         ));
     }
 
+    let card = hand[card_index];
+    let top = game_state.top_discard();
+    if !is_playable(card, top, game_state.active_color()) {
+        return Err(anyhow!(
+            "Card {} can't be played on {}",
+            card_name(card),
+            top.map_or("an empty pile", card_name)
+        ));
+    }
+
+    let hand = &mut game_state.player_hands[player_index];
     let played_card = hand.remove(card_index);
+    if let Some(origins) = game_state.card_origin.get_mut(player_index) {
+        if card_index < origins.len() {
+            origins.remove(card_index);
+        }
+    }
     game_state.discard_pile.push(played_card);
+    // A chosen wild color only applies while that wild is on top
+    game_state.current_color = None;
+    if card_kind(played_card) == CardKind::Reverse {
+        game_state.direction = game_state.direction.reversed();
+    }
+    game_state.advance_turn();
+    game_state.history.push(GameAction::Play {
+        player_id,
+        card_index,
+    });
+    end_if_stuck(game_state);
 
     Ok(played_card)
 }
 
+/// End the current player's turn without playing a card
+pub fn pass_turn(game_state: &mut GameState, player_id: PlayerId) -> Result<()> {
+    if !game_state.is_initialized() {
+        return Err(anyhow!("Game has not been initialized yet"));
+    }
+
+    if !game_state.is_valid_player(player_id) {
+        return Err(anyhow!("Player {} not found", player_id));
+    }
+
+    ensure_not_finished(game_state)?;
+    game_state.check_turn(player_id)?;
+
+    if game_state.require_draw_before_pass && game_state.draws_this_turn == 0 {
+        return Err(anyhow!("Player {} must draw before passing", player_id));
+    }
+
+    game_state.advance_turn();
+    game_state.history.push(GameAction::Pass { player_id });
+    Ok(())
+}
+
+/// Declare the color for the wild on top of the discard pile (house rule)
+///
+/// Only allowed while a wild is on top, so it can't be used to override a colored card.
+pub fn set_active_color(game_state: &mut GameState, color: Color) -> Result<()> {
+    if !game_state.is_initialized() {
+        return Err(anyhow!("Game has not been initialized yet"));
+    }
+
+    if !Color::DECLARABLE.contains(&color) {
+        return Err(anyhow!(
+            "Invalid color '{}': expected one of R, G, B, Y",
+            color
+        ));
+    }
+
+    match game_state.top_discard() {
+        Some(top) if is_wild(top) => {}
+        _ => return Err(anyhow!("Top of the discard pile is not a wild")),
+    }
+
+    game_state.current_color = Some(color);
+    game_state.history.push(GameAction::SetColor { color });
+    Ok(())
+}
+
 // Return reference for efficiency, add owned version when needed
 pub fn get_initial_hands_ref(game_state: &GameState, player_id: PlayerId) -> Result<&[u8]> {
     if !game_state.is_initialized() {
@@ -110,3 +352,476 @@ pub fn get_initial_hands_ref(game_state: &GameState, player_id: PlayerId) -> Res
 pub fn get_initial_hands(game_state: &GameState, player_id: PlayerId) -> Result<Vec<u8>> {
     get_initial_hands_ref(game_state, player_id).map(|hand| hand.to_vec())
 }
+
+/// Positions of the cards in a player's hand that can legally be played
+pub fn playable_cards(game_state: &GameState, player_id: PlayerId) -> Result<Vec<usize>> {
+    let hand = get_initial_hands_ref(game_state, player_id)?;
+    let top = game_state.top_discard();
+    let active_color = game_state.active_color();
+
+    Ok(hand
+        .iter()
+        .enumerate()
+        .filter(|&(_, &card)| is_playable(card, top, active_color))
+        .map(|(position, _)| position)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Direction;
+
+    /// Two-player game where seat 0 holds `hand` and `top` is on the discard pile
+    fn table(hand: Vec<u8>, top: u8) -> GameState {
+        let mut used = hand.clone();
+        used.push(top);
+        let mut game = GameState {
+            player_hands: vec![hand, vec![]],
+            draw_pile: (0..108).filter(|card| !used.contains(card)).collect(),
+            discard_pile: vec![top],
+            is_shuffled: true,
+            ..GameState::new()
+        };
+        game.card_origin = CardOrigin::dealt(&game.player_hands);
+        game
+    }
+
+    // 5R, 5G, 4B, 3Y, W, skipR
+    const MIXED_HAND: [u8; 6] = [9, 34, 57, 81, 100, 19];
+
+    /// Four-player turn-enforced game where every seat holds a 5R and a reverse R
+    fn four_player_game() -> GameState {
+        let player_hands = vec![vec![9, 21], vec![10, 22], vec![1, 2], vec![3, 4]];
+        let dealt: Vec<u8> = player_hands.iter().flatten().copied().collect();
+        GameState {
+            card_origin: CardOrigin::dealt(&player_hands),
+            draw_pile: (0..108)
+                .filter(|card| !dealt.contains(card) && *card != 0)
+                .collect(),
+            discard_pile: vec![0],
+            player_hands,
+            is_shuffled: true,
+            enforce_turns: true,
+            ..GameState::new()
+        }
+    }
+
+    #[test]
+    fn a_reverse_card_flips_the_direction_of_play() {
+        let mut game = four_player_game();
+
+        // Seat 0 plays a number: play moves on clockwise
+        play_card(&mut game, 0, 0).unwrap();
+        assert_eq!(game.direction, Direction::Clockwise);
+        assert_eq!(game.current_player, 1);
+
+        // Seat 1 reverses: seat 0 is next, and opponents are listed the other way round
+        play_card(&mut game, 1, 1).unwrap();
+        assert_eq!(game.direction, Direction::CounterClockwise);
+        assert_eq!(game.current_player, 0);
+        let order: Vec<PlayerId> = game.opponents_of(1).iter().map(|o| o.player_id).collect();
+        assert_eq!(order, [0, 3, 2]);
+
+        // Seat 0 reverses back: clockwise again, so seat 1 plays next
+        play_card(&mut game, 0, 0).unwrap();
+        assert_eq!(game.direction, Direction::Clockwise);
+        assert_eq!(game.current_player, 1);
+    }
+
+    #[test]
+    fn a_reverse_from_the_first_seat_hands_the_turn_to_the_last() {
+        let mut game = four_player_game();
+        play_card(&mut game, 0, 1).unwrap();
+
+        assert_eq!(game.direction, Direction::CounterClockwise);
+        assert_eq!(game.current_player, 3);
+    }
+
+    #[test]
+    fn playable_cards_match_color_or_value_plus_wilds() {
+        // 3G on top: 5G by color, 3Y by value, and the wild
+        let game = table(MIXED_HAND.to_vec(), 30);
+
+        assert_eq!(playable_cards(&game, 0).unwrap(), [1, 3, 4]);
+    }
+
+    #[test]
+    fn playable_cards_follow_the_declared_color_on_a_wild() {
+        let mut game = table(MIXED_HAND.to_vec(), 101);
+        game.current_color = Some(Color::Blue);
+
+        assert_eq!(playable_cards(&game, 0).unwrap(), [2, 4]);
+    }
+
+    #[test]
+    fn everything_is_playable_on_an_undeclared_wild() {
+        let game = table(MIXED_HAND.to_vec(), 101);
+
+        assert_eq!(playable_cards(&game, 0).unwrap(), [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn play_card_refuses_what_the_playable_list_leaves_out() {
+        let mut game = table(MIXED_HAND.to_vec(), 30);
+        let playable = playable_cards(&game, 0).unwrap();
+
+        for index in (0..MIXED_HAND.len()).filter(|index| !playable.contains(index)) {
+            let error = play_card(&mut game, 0, index).unwrap_err();
+            assert!(error.to_string().contains("can't be played"), "{}", error);
+        }
+        assert_eq!(game.player_hands[0], MIXED_HAND);
+        assert_eq!(game.discard_pile, [30]);
+        assert!(game.history.is_empty());
+
+        // The declared color rules a wild on top the same way for both
+        let mut game = table(MIXED_HAND.to_vec(), 101);
+        game.current_color = Some(Color::Blue);
+        assert!(play_card(&mut game, 0, 0).is_err());
+        assert_eq!(play_card(&mut game, 0, 2).unwrap(), 57);
+    }
+
+    #[test]
+    fn playable_cards_reject_unknown_players() {
+        let game = table(MIXED_HAND.to_vec(), 30);
+
+        assert!(playable_cards(&game, 2).is_err());
+    }
+
+    #[test]
+    fn a_repeated_action_id_returns_the_first_draw() {
+        let mut game = table(MIXED_HAND.to_vec(), 30);
+        let action_id = Uuid::new_v4();
+
+        let first = draw_multiple_cards_once(&mut game, 0, 2, Some(action_id)).unwrap();
+        let retried = draw_multiple_cards_once(&mut game, 0, 2, Some(action_id)).unwrap();
+
+        assert_eq!(retried, first);
+        assert_eq!(game.player_hands[0].len(), MIXED_HAND.len() + 2);
+        assert!(draw_multiple_cards_once(&mut game, 1, 2, Some(action_id)).is_err());
+    }
+
+    #[test]
+    fn distinct_action_ids_draw_distinct_cards() {
+        let mut game = table(MIXED_HAND.to_vec(), 30);
+
+        let first = draw_multiple_cards_once(&mut game, 0, 1, Some(Uuid::new_v4())).unwrap();
+        let second = draw_multiple_cards_once(&mut game, 0, 1, Some(Uuid::new_v4())).unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(game.player_hands[0].len(), MIXED_HAND.len() + 2);
+    }
+
+    #[test]
+    fn draws_beyond_the_cards_left_draw_nothing() {
+        let mut game = table(MIXED_HAND.to_vec(), 30);
+        game.draw_pile.truncate(2);
+        game.discard_pile = vec![50, 30];
+
+        assert!(draw_multiple_cards_once(&mut game, 0, 4, None).is_err());
+        assert!(draw_multiple_cards_once(&mut game, 0, 4, Some(Uuid::new_v4())).is_err());
+        assert_eq!(game.player_hands[0], MIXED_HAND);
+        assert!(game.recent_draws.is_empty());
+
+        // The card under the top discard is reshuffled in for the third draw
+        assert_eq!(
+            draw_multiple_cards_once(&mut game, 0, 3, None)
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn an_exhausted_deck_ends_a_stuck_game() {
+        let mut game = table(vec![9], 30);
+        game.draw_pile.clear();
+        game.auto_end_when_stuck = true;
+
+        assert!(draw_multiple_cards_once(&mut game, 0, 1, None).is_err());
+        assert!(game.is_finished());
+    }
+
+    #[test]
+    fn draws_past_the_turn_limit_draw_nothing() {
+        let mut game = table(MIXED_HAND.to_vec(), 30);
+        game.enforce_turns = true;
+        game.max_draws_per_turn = Some(2);
+        draw_card(&mut game, 0).unwrap();
+
+        assert!(draw_multiple_cards_once(&mut game, 0, 2, None).is_err());
+        assert_eq!(game.player_hands[0].len(), MIXED_HAND.len() + 1);
+        assert!(draw_multiple_cards_once(&mut game, 1, 1, None).is_err());
+        assert_eq!(
+            draw_multiple_cards_once(&mut game, 0, 1, None)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn finished_games_accept_no_draws() {
+        let mut game = table(MIXED_HAND.to_vec(), 30);
+        game.outcome = Some(GameOutcome::Draw {
+            reason: "test".to_string(),
+        });
+
+        assert!(draw_multiple_cards_once(&mut game, 0, 1, Some(Uuid::new_v4())).is_err());
+        assert!(game.recent_draws.is_empty());
+    }
+
+    /// `table` with `discards` under `top` and the game seeded with `seed`
+    fn reshuffle_table(seed: u64, discards: &[u8]) -> GameState {
+        let mut game = table(MIXED_HAND.to_vec(), 30);
+        game.seed_metadata.value = U256::from(seed);
+        game.draw_pile.retain(|card| !discards.contains(card));
+        game.discard_pile = discards.iter().copied().chain([30]).collect();
+        game
+    }
+
+    #[test]
+    fn forced_reshuffles_are_deterministic() {
+        let mut first = reshuffle_table(7, &[0, 1, 2, 3]);
+        let mut second = reshuffle_table(7, &[0, 1, 2, 3]);
+
+        force_reshuffle(&mut first).unwrap();
+        force_reshuffle(&mut second).unwrap();
+
+        assert_eq!(first.draw_pile, second.draw_pile);
+        assert_eq!(first.discard_pile, [30]);
+        assert_eq!(first.reshuffle_count, 1);
+        assert_eq!(first.history, [GameAction::Reshuffle]);
+
+        let mut expected: Vec<u8> = reshuffle_table(7, &[0, 1, 2, 3]).draw_pile;
+        expected.extend([0, 1, 2, 3]);
+        shuffle_deck(&mut expected, u256_to_bytes32(U256::from(7 + 1)));
+        assert_eq!(first.draw_pile, expected);
+    }
+
+    #[test]
+    fn the_reshuffle_count_picks_the_permutation() {
+        let mut first = reshuffle_table(7, &[0, 1, 2, 3]);
+        let mut later = reshuffle_table(7, &[0, 1, 2, 3]);
+        later.reshuffle_count = 4;
+
+        force_reshuffle(&mut first).unwrap();
+        force_reshuffle(&mut later).unwrap();
+
+        assert_eq!(later.reshuffle_count, 5);
+        assert_ne!(first.draw_pile, later.draw_pile);
+    }
+
+    #[test]
+    fn forced_reshuffle_needs_cards_under_the_top() {
+        let mut game = reshuffle_table(7, &[]);
+        let draw_pile = game.draw_pile.clone();
+
+        assert!(force_reshuffle(&mut game).is_err());
+        assert_eq!(game.draw_pile, draw_pile);
+        assert_eq!(game.reshuffle_count, 0);
+        assert!(game.history.is_empty());
+    }
+
+    #[test]
+    fn a_color_can_be_declared_for_a_wild_on_top() {
+        let mut game = table(vec![9], 100);
+
+        set_active_color(&mut game, Color::Green).unwrap();
+
+        assert_eq!(game.current_color, Some(Color::Green));
+        assert_eq!(game.active_color(), Some(Color::Green));
+        assert!(matches!(
+            game.history.last(),
+            Some(GameAction::SetColor {
+                color: Color::Green
+            })
+        ));
+        // 5R no longer matches, as if the wild had been played as green
+        assert!(playable_cards(&game, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn a_color_cannot_be_declared_over_a_number_card() {
+        let mut game = table(vec![9], 30);
+
+        let error = set_active_color(&mut game, Color::Red).unwrap_err();
+
+        assert!(error.to_string().contains("not a wild"), "{}", error);
+        assert_eq!(game.current_color, None);
+        assert_eq!(game.active_color(), Some(Color::Green));
+        assert!(game.history.is_empty());
+    }
+
+    #[test]
+    fn a_wild_cannot_be_declared_as_wild() {
+        let mut game = table(vec![9], 104);
+
+        let error = set_active_color(&mut game, Color::Wild).unwrap_err();
+
+        assert!(error.to_string().contains("Invalid color 'W'"), "{}", error);
+        assert_eq!(game.current_color, None);
+    }
+
+    #[test]
+    fn penalty_draws_are_exempt_from_the_turn_limit() {
+        let mut game = table(MIXED_HAND.to_vec(), 30);
+        game.enforce_turns = true;
+        game.max_draws_per_turn = Some(1);
+        draw_card(&mut game, 0).unwrap();
+
+        // A Draw 4 still lands in full, and doesn't use up the turn's draws
+        assert_eq!(draw_multiple_cards(&mut game, 0, 4).unwrap().len(), 4);
+        assert_eq!(game.draws_this_turn, 1);
+        assert_eq!(game.player_hands[0].len(), MIXED_HAND.len() + 5);
+    }
+
+    #[test]
+    fn voluntary_draws_stay_limited_after_a_penalty() {
+        let mut game = table(MIXED_HAND.to_vec(), 30);
+        game.enforce_turns = true;
+        game.max_draws_per_turn = Some(1);
+        draw_multiple_cards(&mut game, 0, 2).unwrap();
+
+        assert_eq!(game.draws_this_turn, 0);
+        assert_eq!(
+            draw_multiple_cards_once(&mut game, 0, 1, None)
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(game.draws_this_turn, 1);
+        assert!(draw_card(&mut game, 0).is_err());
+        assert!(draw_multiple_cards_once(&mut game, 0, 1, None).is_err());
+    }
+
+    #[test]
+    fn the_draw_limit_resets_when_the_turn_advances() {
+        let mut game = table(MIXED_HAND.to_vec(), 30);
+        game.enforce_turns = true;
+        game.max_draws_per_turn = Some(1);
+        draw_card(&mut game, 0).unwrap();
+        assert!(draw_card(&mut game, 0).is_err());
+
+        game.advance_turn();
+        assert_eq!(game.draws_this_turn, 0);
+        draw_card(&mut game, 1).unwrap();
+        assert!(draw_card(&mut game, 1).is_err());
+
+        game.advance_turn();
+        draw_card(&mut game, 0).unwrap();
+        assert_eq!(game.player_hands[0].len(), MIXED_HAND.len() + 2);
+    }
+
+    #[test]
+    fn passing_hands_the_turn_on() {
+        let mut game = four_player_game();
+
+        pass_turn(&mut game, 0).unwrap();
+        assert_eq!(game.current_player, 1);
+        pass_turn(&mut game, 1).unwrap();
+        assert_eq!(game.current_player, 2);
+        assert_eq!(
+            game.history,
+            [
+                GameAction::Pass { player_id: 0 },
+                GameAction::Pass { player_id: 1 }
+            ]
+        );
+    }
+
+    #[test]
+    fn passing_out_of_turn_is_rejected() {
+        let mut game = four_player_game();
+
+        assert!(pass_turn(&mut game, 2).is_err());
+        assert_eq!(game.current_player, 0);
+        assert!(game.history.is_empty());
+    }
+
+    #[test]
+    fn passing_without_drawing_is_rejected_when_required() {
+        let mut game = four_player_game();
+        game.require_draw_before_pass = true;
+
+        let error = pass_turn(&mut game, 0).unwrap_err();
+        assert!(error.to_string().contains("must draw"), "{}", error);
+        assert_eq!(game.current_player, 0);
+
+        draw_card(&mut game, 0).unwrap();
+        pass_turn(&mut game, 0).unwrap();
+        assert_eq!(game.current_player, 1);
+        // The next player starts their turn without a draw to their name
+        assert!(pass_turn(&mut game, 1).is_err());
+    }
+
+    #[test]
+    fn finished_games_cannot_be_passed() {
+        let mut game = four_player_game();
+        game.outcome = Some(GameOutcome::Draw {
+            reason: "test".to_string(),
+        });
+
+        assert!(pass_turn(&mut game, 0).is_err());
+        assert_eq!(game.current_player, 0);
+    }
+
+    #[test]
+    fn a_stuck_game_only_ends_when_auto_end_is_on() {
+        let mut game = table(vec![9], 30);
+        game.draw_pile.clear();
+
+        assert!(draw_card(&mut game, 0).is_err());
+        assert!(game.is_stuck());
+        assert!(!game.is_finished());
+
+        game.auto_end_when_stuck = true;
+        assert!(draw_card(&mut game, 0).is_err());
+        assert!(matches!(game.outcome, Some(GameOutcome::Draw { .. })));
+    }
+
+    #[test]
+    fn dealt_cards_are_initial_and_drawn_ones_drawn() {
+        let mut game = table(MIXED_HAND.to_vec(), 30);
+        assert_eq!(
+            game.hand_origins(0).unwrap(),
+            [CardOrigin::Initial; MIXED_HAND.len()]
+        );
+
+        let single = draw_card(&mut game, 0).unwrap();
+        let penalty = draw_multiple_cards(&mut game, 0, 2).unwrap();
+
+        let origins = game.hand_origins(0).unwrap();
+        assert_eq!(origins.len(), MIXED_HAND.len() + 3);
+        assert!(origins[..MIXED_HAND.len()]
+            .iter()
+            .all(|origin| *origin == CardOrigin::Initial));
+        assert!(origins[MIXED_HAND.len()..]
+            .iter()
+            .all(|origin| *origin == CardOrigin::Drawn));
+        assert_eq!(game.player_hands[0][MIXED_HAND.len()], single);
+        assert_eq!(game.player_hands[0][MIXED_HAND.len() + 1..], penalty[..]);
+        assert!(game.hand_origins(1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn playing_a_card_drops_its_origin_with_it() {
+        let mut game = table(MIXED_HAND.to_vec(), 30);
+        draw_card(&mut game, 0).unwrap();
+
+        // 5G on a 3G: the first dealt card stays, the second leaves
+        play_card(&mut game, 0, 1).unwrap();
+
+        let origins = game.hand_origins(0).unwrap();
+        assert_eq!(origins.len(), game.player_hands[0].len());
+        assert_eq!(origins[0], CardOrigin::Initial);
+        assert_eq!(origins.last(), Some(&CardOrigin::Drawn));
+        assert_eq!(
+            origins
+                .iter()
+                .filter(|origin| **origin == CardOrigin::Initial)
+                .count(),
+            MIXED_HAND.len() - 1
+        );
+    }
+}
@@ -0,0 +1,184 @@
+// backend/src/game/replay.rs
+
+use super::{
+    draw_card, force_reshuffle, pass_turn, play_card, set_active_color, CardOrigin, Color,
+    GameState, PlayerId,
+};
+use crate::blockchain::BlockchainSeed;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use zunnogame_lib::perform_shuffle;
+
+/// A single state-changing move applied to a game
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GameAction {
+    Draw {
+        player_id: PlayerId,
+    },
+    Play {
+        player_id: PlayerId,
+        card_index: usize,
+    },
+    /// Forced discard-into-draw reshuffle (debug operation)
+    Reshuffle,
+    /// Turn ended without playing a card
+    Pass {
+        player_id: PlayerId,
+    },
+    /// Color declared for the wild on top without playing a card (house rule)
+    SetColor {
+        color: Color,
+    },
+}
+
+/// Everything needed to deterministically rebuild a game: seed, params and ordered moves
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameReplay {
+    pub seed: BlockchainSeed,
+    pub num_players: u8,
+    pub cards_per_player: u8,
+    /// Seat that took the first turn; replays exported before this was recorded start at 0
+    #[serde(default)]
+    pub starting_player: PlayerId,
+    pub actions: Vec<GameAction>,
+}
+
+impl From<&GameState> for GameReplay {
+    fn from(game_state: &GameState) -> Self {
+        Self {
+            seed: game_state.seed_metadata.clone(),
+            num_players: game_state.player_count() as u8,
+            cards_per_player: game_state.cards_per_player,
+            starting_player: game_state.starting_player,
+            actions: game_state.history.clone(),
+        }
+    }
+}
+
+/// Rebuild the exact game state by re-dealing from the seed and re-applying every action
+pub fn replay(replay: &GameReplay) -> Result<GameState> {
+    let shuffle_outcome = perform_shuffle(
+        replay.num_players,
+        replay.cards_per_player,
+        replay.seed.shuffle_seed(),
+    )?;
+
+    let card_origin = CardOrigin::dealt(&shuffle_outcome.player_hands);
+    let mut game_state = GameState {
+        player_hands: shuffle_outcome.player_hands,
+        card_origin,
+        draw_pile: shuffle_outcome.draw_pile,
+        is_shuffled: true,
+        seed_metadata: replay.seed.clone(),
+        cards_per_player: replay.cards_per_player,
+        current_player: replay.starting_player,
+        starting_player: replay.starting_player,
+        ..GameState::new()
+    };
+
+    for (step, action) in replay.actions.iter().enumerate() {
+        let applied = match *action {
+            GameAction::Draw { player_id } => draw_card(&mut game_state, player_id).map(|_| ()),
+            GameAction::Play {
+                player_id,
+                card_index,
+            } => play_card(&mut game_state, player_id, card_index).map(|_| ()),
+            GameAction::Reshuffle => force_reshuffle(&mut game_state),
+            GameAction::Pass { player_id } => pass_turn(&mut game_state, player_id),
+            GameAction::SetColor { color } => set_active_color(&mut game_state, color),
+        };
+
+        applied.map_err(|e| anyhow!("Replay failed at action {} ({:?}): {}", step, action, e))?;
+    }
+
+    Ok(game_state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::playable_cards;
+    use alloy::primitives::U256;
+
+    fn dealt(seed: u64) -> GameState {
+        replay(&GameReplay {
+            seed: BlockchainSeed {
+                value: U256::from(seed),
+                request_id: U256::from(1),
+                ..BlockchainSeed::default()
+            },
+            num_players: 2,
+            cards_per_player: 7,
+            starting_player: 0,
+            actions: Vec::new(),
+        })
+        .unwrap()
+    }
+
+    /// Play the first legal card in the player's hand, drawing until one turns up
+    fn play_a_legal_card(game: &mut GameState, player_id: PlayerId) {
+        loop {
+            if let Some(&index) = playable_cards(game, player_id).unwrap().first() {
+                play_card(game, player_id, index).unwrap();
+                return;
+            }
+            draw_card(game, player_id).unwrap();
+        }
+    }
+
+    /// Plays, draws, a reshuffle and a pass, applied live
+    fn played(seed: u64) -> GameState {
+        let mut game = dealt(seed);
+        play_a_legal_card(&mut game, 0);
+        draw_card(&mut game, 1).unwrap();
+        play_a_legal_card(&mut game, 1);
+        play_a_legal_card(&mut game, 0);
+        force_reshuffle(&mut game).unwrap();
+        pass_turn(&mut game, 1).unwrap();
+        draw_card(&mut game, 0).unwrap();
+        game
+    }
+
+    #[test]
+    fn replaying_a_recorded_game_rebuilds_the_same_state() {
+        let original = played(0x5eed);
+
+        let replayed = replay(&GameReplay::from(&original)).unwrap();
+
+        assert_eq!(replayed.history, original.history);
+        assert!(replayed.history.len() >= 7);
+        assert_eq!(
+            serde_json::to_value(&replayed).unwrap(),
+            serde_json::to_value(&original).unwrap()
+        );
+    }
+
+    #[test]
+    fn replays_survive_a_json_round_trip() {
+        let original = played(42);
+        let json = serde_json::to_string(&GameReplay::from(&original)).unwrap();
+
+        let replayed = replay(&serde_json::from_str(&json).unwrap()).unwrap();
+
+        assert_eq!(replayed.player_hands, original.player_hands);
+        assert_eq!(replayed.draw_pile, original.draw_pile);
+        assert_eq!(replayed.discard_pile, original.discard_pile);
+        assert_eq!(replayed.current_player, original.current_player);
+    }
+
+    #[test]
+    fn replay_reports_the_action_that_failed() {
+        let mut recorded = GameReplay::from(&played(42));
+        let failing_step = recorded.actions.len();
+        recorded.actions.push(GameAction::Play {
+            player_id: 7,
+            card_index: 0,
+        });
+
+        let error = replay(&recorded).unwrap_err().to_string();
+
+        let expected = format!("Replay failed at action {}", failing_step);
+        assert!(error.starts_with(&expected), "{}", error);
+    }
+}
@@ -0,0 +1,253 @@
+// backend/src/game/rules.rs
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::PACK_OF_CARDS;
+
+/// Card color, serialized as its `PACK_OF_CARDS` suffix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Color {
+    #[serde(rename = "R")]
+    Red,
+    #[serde(rename = "G")]
+    Green,
+    #[serde(rename = "B")]
+    Blue,
+    #[serde(rename = "Y")]
+    Yellow,
+    /// Wilds have no color of their own until one is declared
+    #[serde(rename = "W")]
+    Wild,
+}
+
+impl Color {
+    /// Colors a wild can be declared as
+    pub const DECLARABLE: [Color; 4] = [Color::Red, Color::Green, Color::Blue, Color::Yellow];
+
+    /// Parse a card-name suffix ('R', 'G', 'B', 'Y', 'W')
+    pub fn from_suffix(suffix: char) -> Option<Self> {
+        match suffix {
+            'R' => Some(Self::Red),
+            'G' => Some(Self::Green),
+            'B' => Some(Self::Blue),
+            'Y' => Some(Self::Yellow),
+            'W' => Some(Self::Wild),
+            _ => None,
+        }
+    }
+
+    /// Card-name suffix for this color
+    pub fn to_suffix(self) -> char {
+        match self {
+            Self::Red => 'R',
+            Self::Green => 'G',
+            Self::Blue => 'B',
+            Self::Yellow => 'Y',
+            Self::Wild => 'W',
+        }
+    }
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_suffix())
+    }
+}
+
+/// What a card does, independent of its color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardKind {
+    Number,
+    Skip,
+    Reverse,
+    DrawTwo,
+    Wild,
+    WildDrawFour,
+}
+
+/// Color of a card, taken from its name suffix
+pub fn card_color(card: u8) -> Color {
+    let name = PACK_OF_CARDS[card as usize];
+    name.chars()
+        .last()
+        .and_then(Color::from_suffix)
+        .unwrap_or(Color::Wild)
+}
+
+/// Face value of a card without its color suffix ("5", "skip", "_", "D2", "D4", "")
+pub fn card_value(card: u8) -> &'static str {
+    let name = PACK_OF_CARDS[card as usize];
+    &name[..name.len() - 1]
+}
+
+/// Kind of a card, derived from its face value
+pub fn card_kind(card: u8) -> CardKind {
+    match card_value(card) {
+        "skip" => CardKind::Skip,
+        "_" => CardKind::Reverse,
+        "D2" => CardKind::DrawTwo,
+        "D4" => CardKind::WildDrawFour,
+        "" => CardKind::Wild,
+        _ => CardKind::Number,
+    }
+}
+
+/// Color and kind counts over a set of cards
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CardHistogram {
+    pub total: usize,
+    /// Keyed by color suffix ("R", "G", "B", "Y", "W")
+    pub colors: BTreeMap<Color, usize>,
+    pub kinds: BTreeMap<CardKind, usize>,
+}
+
+impl CardHistogram {
+    pub fn from_cards<'a>(cards: impl IntoIterator<Item = &'a u8>) -> Self {
+        let mut histogram = Self::default();
+        for &card in cards {
+            histogram.total += 1;
+            *histogram.colors.entry(card_color(card)).or_default() += 1;
+            *histogram.kinds.entry(card_kind(card)).or_default() += 1;
+        }
+        histogram
+    }
+}
+
+/// Whether a card is a wild (W or D4W)
+pub fn is_wild(card: u8) -> bool {
+    card_color(card) == Color::Wild
+}
+
+/// Check if a card can legally be played on top of the discard pile
+///
+/// `active_color` comes from `GameState::active_color`; `None` with a wild on top
+/// means no color was chosen, so anything goes.
+pub fn is_playable(card: u8, top: Option<u8>, active_color: Option<Color>) -> bool {
+    let Some(top) = top else {
+        return true;
+    };
+
+    if is_wild(card) {
+        return true;
+    }
+
+    match active_color {
+        Some(color) if card_color(card) == color => true,
+        Some(_) => !is_wild(top) && card_value(card) == card_value(top),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deck_histogram() -> CardHistogram {
+        CardHistogram::from_cards(&zunnogame_lib::new_deck())
+    }
+
+    #[test]
+    fn the_backend_uses_the_lib_deck() {
+        assert_eq!(crate::game::DECK_SIZE, zunnogame_lib::DECK_SIZE);
+        assert_eq!(PACK_OF_CARDS, zunnogame_lib::PACK_OF_CARDS);
+
+        // Every card the lib deals has a name and a color here
+        let outcome = zunnogame_lib::perform_shuffle(4, 7, [0x42; 32]).unwrap();
+        let mut dealt = outcome.full_deck();
+        dealt.sort_unstable();
+        assert_eq!(dealt, zunnogame_lib::new_deck());
+        for card in dealt {
+            let name = PACK_OF_CARDS[card as usize];
+            assert_eq!(card_color(card).to_suffix(), name.chars().last().unwrap());
+        }
+    }
+
+    #[test]
+    fn a_full_deck_has_the_standard_colors() {
+        let histogram = deck_histogram();
+
+        assert_eq!(histogram.total, 108);
+        assert_eq!(
+            histogram.colors,
+            BTreeMap::from([
+                (Color::Red, 25),
+                (Color::Green, 25),
+                (Color::Blue, 25),
+                (Color::Yellow, 25),
+                (Color::Wild, 8),
+            ])
+        );
+    }
+
+    #[test]
+    fn a_full_deck_has_the_standard_kinds() {
+        assert_eq!(
+            deck_histogram().kinds,
+            BTreeMap::from([
+                (CardKind::Number, 76),
+                (CardKind::Skip, 8),
+                (CardKind::Reverse, 8),
+                (CardKind::DrawTwo, 8),
+                (CardKind::Wild, 4),
+                (CardKind::WildDrawFour, 4),
+            ])
+        );
+    }
+
+    #[test]
+    fn histograms_serialize_with_card_suffixes_and_kind_names() {
+        // 0R, skipR, W, D4W
+        let histogram = CardHistogram::from_cards(&[0, 19, 100, 104]);
+
+        assert_eq!(
+            serde_json::to_value(&histogram).unwrap(),
+            serde_json::json!({
+                "total": 4,
+                "colors": { "R": 2, "W": 2 },
+                "kinds": { "number": 1, "skip": 1, "wild": 1, "wild_draw_four": 1 },
+            })
+        );
+    }
+
+    #[test]
+    fn every_suffix_maps_to_its_color_and_back() {
+        let pairs = [
+            ('R', Color::Red),
+            ('G', Color::Green),
+            ('B', Color::Blue),
+            ('Y', Color::Yellow),
+            ('W', Color::Wild),
+        ];
+
+        for (suffix, color) in pairs {
+            assert_eq!(Color::from_suffix(suffix), Some(color));
+            assert_eq!(color.to_suffix(), suffix);
+            assert_eq!(color.to_string(), suffix.to_string());
+        }
+        for unknown in ['r', 'X', '_', ' '] {
+            assert_eq!(Color::from_suffix(unknown), None);
+        }
+    }
+
+    #[test]
+    fn wilds_are_the_only_undeclarable_color() {
+        assert!(!Color::DECLARABLE.contains(&Color::Wild));
+        assert_eq!(card_color(100), Color::Wild);
+        assert_eq!(card_color(104), Color::Wild);
+        assert_eq!(card_color(0), Color::Red);
+        assert_eq!(card_color(25), Color::Green);
+        assert_eq!(card_color(50), Color::Blue);
+        assert_eq!(card_color(75), Color::Yellow);
+    }
+
+    #[test]
+    fn colors_serialize_as_their_suffix() {
+        assert_eq!(serde_json::to_string(&Color::Wild).unwrap(), "\"W\"");
+        assert_eq!(
+            serde_json::from_str::<Color>("\"Y\"").unwrap(),
+            Color::Yellow
+        );
+    }
+}
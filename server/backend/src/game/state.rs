@@ -1,21 +1,138 @@
 // backend/src/game/state.rs
 
-use crate::blockchain::BlockchainSeed;
+use super::replay::GameAction;
+use super::rules::{card_color, is_playable, is_wild, Color};
+use crate::blockchain::{BlockchainSeed, U256};
+use crate::orchestrator::DEFAULT_THEME;
+use crate::proof_management::proof_verification::VerificationResult;
+use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use subtle::ConstantTimeEq;
+use uuid::Uuid;
+use zunnogame_lib::{
+    build_public_values, commit_seed_derivation, deal_seat, perform_shuffle, u256_to_bytes32,
+    PublicValuesStruct, ShuffleOutcome,
+};
 
 pub type PlayerId = u8;
 
-// UNO card deck mapping - matches JavaScript PACK_OF_CARDS exactly
-pub const PACK_OF_CARDS: [&str; 108] = [
-    "0R", "1R", "1R", "2R", "2R", "3R", "3R", "4R", "4R", "5R", "5R", "6R", "6R", "7R", "7R", "8R",
-    "8R", "9R", "9R", "skipR", "skipR", "_R", "_R", "D2R", "D2R", "0G", "1G", "1G", "2G", "2G",
-    "3G", "3G", "4G", "4G", "5G", "5G", "6G", "6G", "7G", "7G", "8G", "8G", "9G", "9G", "skipG",
-    "skipG", "_G", "_G", "D2G", "D2G", "0B", "1B", "1B", "2B", "2B", "3B", "3B", "4B", "4B", "5B",
-    "5B", "6B", "6B", "7B", "7B", "8B", "8B", "9B", "9B", "skipB", "skipB", "_B", "_B", "D2B",
-    "D2B", "0Y", "1Y", "1Y", "2Y", "2Y", "3Y", "3Y", "4Y", "4Y", "5Y", "5Y", "6Y", "6Y", "7Y",
-    "7Y", "8Y", "8Y", "9Y", "9Y", "skipY", "skipY", "_Y", "_Y", "D2Y", "D2Y", "W", "W", "W", "W",
-    "D4W", "D4W", "D4W", "D4W",
-];
+/// Order in which seats take turns
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    /// Increasing seat numbers, wrapping around
+    #[default]
+    Clockwise,
+    /// Decreasing seat numbers, wrapping around
+    CounterClockwise,
+}
+
+impl Direction {
+    /// The opposite direction, as after a Reverse card
+    pub fn reversed(self) -> Self {
+        match self {
+            Direction::Clockwise => Direction::CounterClockwise,
+            Direction::CounterClockwise => Direction::Clockwise,
+        }
+    }
+}
+
+/// How much of the discard pile players may see
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscardVisibility {
+    /// Any number of recent discards
+    #[default]
+    Full,
+    /// Only the top card, so earlier plays cannot be counted
+    TopOnly,
+}
+
+/// How a card came to be in a player's hand
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardOrigin {
+    /// Part of the initial deal, covered by the hand commitment in the proof
+    Initial,
+    /// Drawn from the draw pile after the deal
+    Drawn,
+}
+
+impl CardOrigin {
+    /// Origins for freshly dealt hands: every card `Initial`
+    pub fn dealt(player_hands: &[Vec<u8>]) -> Vec<Vec<CardOrigin>> {
+        player_hands
+            .iter()
+            .map(|hand| vec![CardOrigin::Initial; hand.len()])
+            .collect()
+    }
+}
+
+/// An opponent's seat and how many cards they hold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct OpponentInfo {
+    pub player_id: PlayerId,
+    pub hand_count: usize,
+}
+
+/// How much of the deck is still in the draw pile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeckHealth {
+    Healthy,
+    Low,
+    Critical,
+}
+
+impl DeckHealth {
+    /// Classify by the draw pile's share of all cards in play
+    ///
+    /// At or below `critical_fraction` is `Critical`, at or below `low_fraction` is `Low`.
+    pub fn from_counts(
+        draw_pile: usize,
+        total: usize,
+        low_fraction: f64,
+        critical_fraction: f64,
+    ) -> Self {
+        if total == 0 {
+            return Self::Critical;
+        }
+
+        let fraction = draw_pile as f64 / total as f64;
+        if fraction <= critical_fraction {
+            Self::Critical
+        } else if fraction <= low_fraction {
+            Self::Low
+        } else {
+            Self::Healthy
+        }
+    }
+}
+
+/// How a game ended
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum GameOutcome {
+    /// No winner: the game could not progress
+    Draw { reason: String },
+    /// Nobody moved for longer than the idle timeout
+    Abandoned {
+        /// Player with the fewest cards left, when awarding idle games is enabled and unique
+        winner: Option<PlayerId>,
+        idle_seconds: u64,
+    },
+}
+
+/// Number of client action ids remembered per game for retried draws
+pub const MAX_RECENT_ACTIONS: usize = 64;
+
+/// A draw applied on behalf of a client-supplied action id
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppliedDraw {
+    pub action_id: Uuid,
+    pub player_id: PlayerId,
+    pub cards: Vec<u8>,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
@@ -24,8 +141,127 @@ pub struct GameState {
     pub discard_pile: Vec<u8>,
     pub is_shuffled: bool,
     pub seed_metadata: BlockchainSeed,
-    pub proof_cid: String, // IPFS CID
+    /// IPFS CID of the proof; `None` for OS-seeded games and while an upload is dead-lettered
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub proof_cid: Option<String>,
+    /// IPFS providers that pinned the proof
+    #[serde(default)]
+    pub proof_pinned_by: Vec<String>,
     pub verification_tx: String,
+    /// Full zkVerify result; `verification_tx` mirrors its `tx_hash`
+    #[serde(default)]
+    pub verification: Option<VerificationResult>,
+    /// Color chosen for the wild card on top of the discard pile
+    #[serde(default)]
+    pub current_color: Option<Color>,
+    /// Cards dealt to each player at the start of the game
+    #[serde(default)]
+    pub cards_per_player: u8,
+    /// Ordered moves applied since the deal (used for replays)
+    #[serde(default)]
+    pub history: Vec<GameAction>,
+    /// Human-readable label chosen at game start
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Unix time the game was started
+    #[serde(default)]
+    pub created_at: u64,
+    /// Deck skin identifier chosen at game start
+    #[serde(default = "default_theme")]
+    pub theme: String,
+    /// Number of discard-into-draw reshuffles so far (drives the reshuffle seed)
+    #[serde(default)]
+    pub reshuffle_count: u32,
+    /// Current direction of play
+    #[serde(default)]
+    pub direction: Direction,
+    /// Most recent idempotent draws, oldest first, capped at `MAX_RECENT_ACTIONS`
+    #[serde(default)]
+    pub recent_draws: VecDeque<AppliedDraw>,
+    /// Hex ABI-encoded public values committed by the deal proof
+    #[serde(default)]
+    pub public_values: String,
+    /// Seat whose turn it is
+    #[serde(default)]
+    pub current_player: PlayerId,
+    /// Seat that took the first turn
+    #[serde(default)]
+    pub starting_player: PlayerId,
+    /// Reject moves made out of turn and apply per-turn limits
+    #[serde(default)]
+    pub enforce_turns: bool,
+    /// Most cards the current player may draw before playing or passing (unlimited when unset)
+    #[serde(default)]
+    pub max_draws_per_turn: Option<u8>,
+    /// Cards drawn by the current player this turn
+    #[serde(default)]
+    pub draws_this_turn: u8,
+    /// Passing requires having drawn at least once this turn
+    #[serde(default)]
+    pub require_draw_before_pass: bool,
+    /// Hand hashes in the proof are salted per player
+    #[serde(default = "default_true")]
+    pub salted_hands: bool,
+    /// The proof commits a per-card Merkle root
+    #[serde(default = "default_true")]
+    pub merkle_built: bool,
+    /// End the game as a draw once `is_stuck` holds
+    #[serde(default)]
+    pub auto_end_when_stuck: bool,
+    /// Set once the game is over; further moves are rejected
+    #[serde(default)]
+    pub outcome: Option<GameOutcome>,
+    /// Dealt from the server-wide tournament seed instead of a VRF value
+    #[serde(default)]
+    pub tournament: bool,
+    /// False when dealt from OS randomness: there is no proof, VRF value or commitment
+    #[serde(default = "default_true")]
+    pub verifiable: bool,
+    /// Unix time of the last successful move; drives the idle timeout
+    #[serde(default)]
+    pub last_activity: u64,
+    /// Session this game was forked from; forks share its seed metadata only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forked_from: Option<String>,
+    /// Finished session this game was started as a rematch of, with the same seats
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rematch_of: Option<String>,
+    /// Discards beyond the top card are hidden under `TopOnly`
+    #[serde(default)]
+    pub discard_visibility: DiscardVisibility,
+    /// Origin of each card in `player_hands`, position for position; empty for games dealt
+    /// before origins were tracked
+    #[serde(default)]
+    pub card_origin: Vec<Vec<CardOrigin>>,
+    /// Token per seat required by that seat's private endpoints; empty for games dealt
+    /// before tokens were issued, which stay open
+    #[serde(default)]
+    pub player_tokens: Vec<String>,
+}
+
+/// Seat that opens a game when none was requested: the seed value modulo the player count
+///
+/// Derived from the VRF value alone, so anyone holding the seed can check the first turn.
+pub fn seed_starting_player(seed: U256, num_players: u8) -> PlayerId {
+    let seats = U256::from(num_players.max(1));
+    (seed % seats).to::<u8>()
+}
+
+fn default_theme() -> String {
+    DEFAULT_THEME.to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Snapshots from before `proof_cid` was optional stored a missing CID as ""
+fn empty_string_as_none<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let cid: Option<String> = Option::deserialize(deserializer)?;
+    Ok(cid.filter(|cid| !cid.is_empty()))
 }
 
 impl GameState {
@@ -36,8 +272,38 @@ impl GameState {
             discard_pile: Vec::new(),
             is_shuffled: false,
             seed_metadata: BlockchainSeed::default(),
-            proof_cid: String::new(),
+            proof_cid: None,
+            proof_pinned_by: Vec::new(),
             verification_tx: String::new(),
+            verification: None,
+            current_color: None,
+            cards_per_player: 0,
+            history: Vec::new(),
+            name: None,
+            created_at: 0,
+            theme: default_theme(),
+            reshuffle_count: 0,
+            direction: Direction::default(),
+            recent_draws: VecDeque::new(),
+            public_values: String::new(),
+            current_player: 0,
+            starting_player: 0,
+            enforce_turns: false,
+            max_draws_per_turn: None,
+            draws_this_turn: 0,
+            require_draw_before_pass: false,
+            salted_hands: true,
+            merkle_built: true,
+            auto_end_when_stuck: false,
+            outcome: None,
+            tournament: false,
+            verifiable: true,
+            last_activity: 0,
+            forked_from: None,
+            rematch_of: None,
+            discard_visibility: DiscardVisibility::default(),
+            card_origin: Vec::new(),
+            player_tokens: Vec::new(),
         }
     }
 
@@ -49,13 +315,775 @@ impl GameState {
         (player_id as usize) < self.player_hands.len()
     }
 
+    /// Whether `token` unlocks `player_id`'s private view
+    pub fn player_token_matches(&self, player_id: PlayerId, token: Option<&str>) -> bool {
+        match (self.player_tokens.get(player_id as usize), token) {
+            // Constant-time, so response timing doesn't leak how much of a guess was right
+            (Some(expected), Some(token)) => expected.as_bytes().ct_eq(token.as_bytes()).into(),
+            (Some(_), None) => false,
+            (None, _) => self.player_tokens.is_empty(),
+        }
+    }
+
     pub fn is_initialized(&self) -> bool {
         self.is_shuffled && !self.player_hands.is_empty()
     }
 
+    /// Re-deal the initial hands and draw pile from the stored seed
+    pub fn initial_deal(&self) -> Result<ShuffleOutcome> {
+        perform_shuffle(
+            self.player_count() as u8,
+            self.cards_per_player,
+            self.seed_metadata.shuffle_seed(),
+        )
+    }
+
+    /// Every dealt card as `(seat, card)` in the order it left the deck
+    ///
+    /// Follows the round-robin order of `deal_seat`, so replaying it rebuilds the initial hands.
+    pub fn deal_sequence(&self) -> Result<Vec<(PlayerId, u8)>> {
+        let deal = self.initial_deal()?;
+        let num_players = deal.player_hands.len();
+        let dealt = num_players * self.cards_per_player as usize;
+
+        Ok((0..dealt)
+            .map(|position| {
+                let seat = deal_seat(position, num_players);
+                (
+                    seat as PlayerId,
+                    deal.player_hands[seat][position / num_players],
+                )
+            })
+            .collect())
+    }
+
+    /// Whether both games started from the same hands and draw pile, whatever was played since
+    pub fn deals_equal(&self, other: &GameState) -> bool {
+        match (self.initial_deal(), other.initial_deal()) {
+            (Ok(ours), Ok(theirs)) => {
+                ours.player_hands == theirs.player_hands && ours.draw_pile == theirs.draw_pile
+            }
+            _ => false,
+        }
+    }
+
+    /// Recompute the public values the deal proof commits, as the program does
+    ///
+    /// Deals from `seed` with this game's parameters and commitment options, so the
+    /// result can be compared field-for-field with the decoded proof public values.
+    pub fn to_public_values(&self, seed: &BlockchainSeed) -> Result<PublicValuesStruct> {
+        let num_players = self.player_count() as u8;
+        let outcome = perform_shuffle(num_players, self.cards_per_player, seed.shuffle_seed())?;
+
+        let mut public_values = build_public_values(
+            num_players,
+            self.cards_per_player,
+            u256_to_bytes32(seed.value),
+            seed.instance_salt.0,
+            &outcome,
+            self.salted_hands,
+            self.merkle_built,
+        );
+        if let Some(master) = &seed.master {
+            commit_seed_derivation(&mut public_values, &master.derivation());
+        }
+
+        Ok(public_values)
+    }
+
+    /// Origin of each card in a player's hand, in hand order
+    ///
+    /// `None` for an unknown player or a game whose origins weren't tracked.
+    pub fn hand_origins(&self, player_id: PlayerId) -> Option<&[CardOrigin]> {
+        let hand = self.player_hands.get(player_id as usize)?;
+        self.card_origin
+            .get(player_id as usize)
+            .filter(|origins| origins.len() == hand.len())
+            .map(Vec::as_slice)
+    }
+
+    /// Whether other games may derive their seeds from this game's VRF value
+    pub fn can_be_master(&self) -> bool {
+        self.verifiable && !self.tournament
+    }
+
+    /// Other players in turn order starting after `player_id`, with their hand sizes
+    pub fn opponents_of(&self, player_id: PlayerId) -> Vec<OpponentInfo> {
+        let count = self.player_count();
+        let seat = player_id as usize;
+
+        (1..count)
+            .map(|step| match self.direction {
+                Direction::Clockwise => (seat + step) % count,
+                Direction::CounterClockwise => (seat + count - step) % count,
+            })
+            .map(|index| OpponentInfo {
+                player_id: index as PlayerId,
+                hand_count: self.player_hands[index].len(),
+            })
+            .collect()
+    }
+
+    /// `opponents_of`, keeping only the `window` seats on either side of `player_id`
+    ///
+    /// Upcoming seats come first, then the ones that play just before `player_id`, both in
+    /// turn order. A window covering the whole table returns every opponent.
+    pub fn opponents_window(&self, player_id: PlayerId, window: usize) -> Vec<OpponentInfo> {
+        let mut opponents = self.opponents_of(player_id);
+        if window.saturating_mul(2) < opponents.len() {
+            opponents.drain(window..opponents.len() - window);
+        }
+        opponents
+    }
+
+    /// Seat that plays after the current player, following `direction`
+    pub fn next_player(&self) -> PlayerId {
+        let count = self.player_count().max(1);
+        let seat = self.current_player as usize;
+        let next = match self.direction {
+            Direction::Clockwise => (seat + 1) % count,
+            Direction::CounterClockwise => (seat + count - 1) % count,
+        };
+        next as PlayerId
+    }
+
+    /// Hand the turn to the next player and reset per-turn counters
+    pub fn advance_turn(&mut self) {
+        self.current_player = self.next_player();
+        self.draws_this_turn = 0;
+    }
+
+    /// Error if turns are enforced and it isn't `player_id`'s turn
+    pub fn check_turn(&self, player_id: PlayerId) -> Result<()> {
+        if self.enforce_turns && player_id != self.current_player {
+            return Err(anyhow!(
+                "Not player {}'s turn (current player is {})",
+                player_id,
+                self.current_player
+            ));
+        }
+        Ok(())
+    }
+
+    /// Card currently on top of the discard pile
+    pub fn top_discard(&self) -> Option<u8> {
+        self.discard_pile.last().copied()
+    }
+
+    /// Color the next card must match
+    ///
+    /// The chosen `current_color` when a wild is on top, otherwise the top card's own color.
+    /// `None` for an empty discard pile or a wild with no color chosen yet.
+    pub fn active_color(&self) -> Option<Color> {
+        let top = self.top_discard()?;
+        if is_wild(top) {
+            self.current_color
+        } else {
+            Some(card_color(top))
+        }
+    }
+
+    /// Last `count` discarded cards in pile order (top card last), clamped to the pile size
+    pub fn recent_discards(&self, count: usize) -> &[u8] {
+        let start = self.discard_pile.len().saturating_sub(count);
+        &self.discard_pile[start..]
+    }
+
+    /// Recent discards players may see: `count` under `Full`, the top card under `TopOnly`
+    pub fn visible_discards(&self, count: usize) -> &[u8] {
+        match self.discard_visibility {
+            DiscardVisibility::Full => self.recent_discards(count),
+            DiscardVisibility::TopOnly => self.recent_discards(count.min(1)),
+        }
+    }
+
+    /// Draw previously applied under `action_id`, if it is still remembered
+    pub fn applied_draw(&self, action_id: Uuid) -> Option<&AppliedDraw> {
+        self.recent_draws
+            .iter()
+            .find(|draw| draw.action_id == action_id)
+    }
+
+    /// Remember a draw so a retry with the same action id returns the same cards
+    pub fn record_draw(&mut self, action_id: Uuid, player_id: PlayerId, cards: Vec<u8>) {
+        if self.recent_draws.len() >= MAX_RECENT_ACTIONS {
+            self.recent_draws.pop_front();
+        }
+        self.recent_draws.push_back(AppliedDraw {
+            action_id,
+            player_id,
+            cards,
+        });
+    }
+
+    /// Draw pile health given the configured thresholds
+    pub fn deck_health(&self, low_fraction: f64, critical_fraction: f64) -> DeckHealth {
+        DeckHealth::from_counts(
+            self.draw_pile.len(),
+            self.total_cards(),
+            low_fraction,
+            critical_fraction,
+        )
+    }
+
+    /// No card can be drawn, nothing can be reshuffled, and no player holds a playable card
+    pub fn is_stuck(&self) -> bool {
+        if !self.is_initialized() || !self.draw_pile.is_empty() || self.discard_pile.len() > 1 {
+            return false;
+        }
+
+        let top = self.top_discard();
+        let active_color = self.active_color();
+        !self
+            .player_hands
+            .iter()
+            .flatten()
+            .any(|&card| is_playable(card, top, active_color))
+    }
+
+    /// Whether the game has an outcome
+    pub fn is_finished(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    /// Seconds since the last move, or since the deal if nobody has moved
+    pub fn idle_seconds(&self, now: u64) -> u64 {
+        now.saturating_sub(self.last_activity.max(self.created_at))
+    }
+
+    /// The single player holding the fewest cards; `None` on a tie
+    pub fn most_progressed_player(&self) -> Option<PlayerId> {
+        let fewest = self.player_hands.iter().map(Vec::len).min()?;
+        let mut leaders = self
+            .player_hands
+            .iter()
+            .enumerate()
+            .filter(|(_, hand)| hand.len() == fewest);
+
+        match (leaders.next(), leaders.next()) {
+            (Some((player, _)), None) => Some(player as PlayerId),
+            _ => None,
+        }
+    }
+
+    /// End the game as abandoned, optionally awarding it to the most-progressed player
+    pub fn abandon(&mut self, idle_seconds: u64, award_win: bool) {
+        self.outcome = Some(GameOutcome::Abandoned {
+            winner: award_win.then(|| self.most_progressed_player()).flatten(),
+            idle_seconds,
+        });
+    }
+
     /// Get total cards in circulation (for debugging)
     pub fn total_cards(&self) -> usize {
         let hands_total: usize = self.player_hands.iter().map(|hand| hand.len()).sum();
         hands_total + self.draw_pile.len() + self.discard_pile.len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Dealt game with the given hands and the rest of the deck as the draw pile
+    fn table(player_hands: Vec<Vec<u8>>) -> GameState {
+        let dealt: Vec<u8> = player_hands.iter().flatten().copied().collect();
+        GameState {
+            card_origin: CardOrigin::dealt(&player_hands),
+            draw_pile: (0..108).filter(|card| !dealt.contains(card)).collect(),
+            player_hands,
+            is_shuffled: true,
+            ..GameState::new()
+        }
+    }
+
+    #[test]
+    fn player_token_unlocks_only_its_own_seat() {
+        let mut game = table(vec![vec![0], vec![1]]);
+        game.player_tokens = vec!["seat-0".to_string(), "seat-1".to_string()];
+
+        assert!(game.player_token_matches(0, Some("seat-0")));
+        assert!(game.player_token_matches(1, Some("seat-1")));
+        assert!(!game.player_token_matches(0, Some("seat-1")));
+        assert!(!game.player_token_matches(0, Some("seat-")));
+        assert!(!game.player_token_matches(0, Some("seat-00")));
+        assert!(!game.player_token_matches(0, None));
+        assert!(!game.player_token_matches(2, Some("seat-0")));
+    }
+
+    #[test]
+    fn games_without_tokens_stay_open() {
+        let game = table(vec![vec![0], vec![1]]);
+
+        assert!(game.player_token_matches(0, None));
+        assert!(game.player_token_matches(1, Some("anything")));
+    }
+
+    #[test]
+    fn theme_survives_serialization_and_defaults_to_classic() {
+        let mut game = table(vec![vec![0], vec![1]]);
+        game.theme = "neon".to_string();
+
+        let json = serde_json::to_value(&game).unwrap();
+        let restored: GameState = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(restored.theme, "neon");
+
+        let mut legacy = json;
+        legacy.as_object_mut().unwrap().remove("theme");
+        let restored: GameState = serde_json::from_value(legacy).unwrap();
+        assert_eq!(restored.theme, DEFAULT_THEME);
+    }
+
+    #[test]
+    fn recent_discards_end_with_the_top_card() {
+        let mut game = table(vec![vec![0], vec![1]]);
+        game.discard_pile = vec![30, 31, 57];
+
+        assert_eq!(game.recent_discards(1), [57]);
+        assert_eq!(game.recent_discards(2), [31, 57]);
+    }
+
+    #[test]
+    fn recent_discards_clamp_to_the_pile() {
+        let mut game = table(vec![vec![0], vec![1]]);
+        game.discard_pile = vec![30, 31, 57];
+
+        assert_eq!(game.recent_discards(10), [30, 31, 57]);
+    }
+
+    #[test]
+    fn recent_discards_of_an_empty_pile_are_empty() {
+        let game = table(vec![vec![0], vec![1]]);
+
+        assert!(game.recent_discards(1).is_empty());
+        assert!(game.recent_discards(10).is_empty());
+    }
+
+    #[test]
+    fn top_only_discards_ignore_the_requested_count() {
+        let mut game = table(vec![vec![0], vec![1]]);
+        game.discard_pile = vec![30, 31, 57];
+        game.discard_visibility = DiscardVisibility::TopOnly;
+
+        for count in [1, 2, 3, 10] {
+            assert_eq!(game.visible_discards(count), [57], "count {}", count);
+        }
+        assert!(game.visible_discards(0).is_empty());
+    }
+
+    #[test]
+    fn full_discards_honor_the_requested_count() {
+        let mut game = table(vec![vec![0], vec![1]]);
+        game.discard_pile = vec![30, 31, 57];
+        assert_eq!(game.discard_visibility, DiscardVisibility::Full);
+
+        assert_eq!(game.visible_discards(1), [57]);
+        assert_eq!(game.visible_discards(2), [31, 57]);
+        assert_eq!(game.visible_discards(10), [30, 31, 57]);
+    }
+
+    /// Four seats holding 1, 2, 3 and 4 cards
+    fn four_player_table() -> GameState {
+        table(vec![
+            vec![0],
+            vec![1, 2],
+            vec![3, 4, 5],
+            vec![9, 10, 19, 20],
+        ])
+    }
+
+    fn seats_and_counts(opponents: &[OpponentInfo]) -> Vec<(PlayerId, usize)> {
+        opponents
+            .iter()
+            .map(|opponent| (opponent.player_id, opponent.hand_count))
+            .collect()
+    }
+
+    #[test]
+    fn opponents_follow_clockwise_play_from_each_seat() {
+        let game = four_player_table();
+
+        assert_eq!(
+            seats_and_counts(&game.opponents_of(0)),
+            [(1, 2), (2, 3), (3, 4)]
+        );
+        assert_eq!(
+            seats_and_counts(&game.opponents_of(2)),
+            [(3, 4), (0, 1), (1, 2)]
+        );
+        assert_eq!(
+            seats_and_counts(&game.opponents_of(3)),
+            [(0, 1), (1, 2), (2, 3)]
+        );
+    }
+
+    #[test]
+    fn opponents_follow_counter_clockwise_play_from_each_seat() {
+        let mut game = four_player_table();
+        game.direction = Direction::CounterClockwise;
+
+        assert_eq!(
+            seats_and_counts(&game.opponents_of(0)),
+            [(3, 4), (2, 3), (1, 2)]
+        );
+        assert_eq!(
+            seats_and_counts(&game.opponents_of(2)),
+            [(1, 2), (0, 1), (3, 4)]
+        );
+        assert_eq!(
+            seats_and_counts(&game.opponents_of(3)),
+            [(2, 3), (1, 2), (0, 1)]
+        );
+    }
+
+    #[test]
+    fn next_player_follows_the_direction() {
+        let mut game = four_player_table();
+        game.current_player = 0;
+        assert_eq!(game.next_player(), 1);
+
+        game.direction = game.direction.reversed();
+        assert_eq!(game.direction, Direction::CounterClockwise);
+        assert_eq!(game.next_player(), 3);
+        assert_eq!(game.direction.reversed(), Direction::Clockwise);
+    }
+
+    #[test]
+    fn public_values_mirror_the_game_hashing_mode() {
+        let seed = BlockchainSeed {
+            value: U256::from(0xfeed_u64),
+            instance_salt: [0x44; 32].into(),
+            ..BlockchainSeed::default()
+        };
+        let mut game = table(vec![vec![], vec![], vec![]]);
+        game.cards_per_player = 7;
+
+        for salted in [true, false] {
+            game.salted_hands = salted;
+            let public_values = game.to_public_values(&seed).unwrap();
+
+            assert_eq!(public_values.salted_hands, salted);
+            let expected = zunnogame_lib::compute_hand_hashes(
+                u256_to_bytes32(seed.value),
+                seed.instance_salt.0,
+                3,
+                7,
+                salted,
+            )
+            .unwrap();
+            let committed: Vec<Vec<u8>> = public_values
+                .initial_hands_hash
+                .iter()
+                .map(|hash| hash.to_vec())
+                .collect();
+            assert_eq!(committed, expected);
+        }
+    }
+
+    #[test]
+    fn a_number_top_sets_its_own_color() {
+        let mut game = table(vec![vec![0], vec![1]]);
+        game.discard_pile = vec![100, 59];
+        // A color left over from an earlier wild must not leak through
+        game.current_color = Some(Color::Yellow);
+
+        assert_eq!(game.active_color(), Some(Color::Blue));
+    }
+
+    #[test]
+    fn a_wild_top_uses_the_chosen_color() {
+        let mut game = table(vec![vec![0], vec![1]]);
+        game.discard_pile = vec![59, 104];
+
+        assert_eq!(game.active_color(), None);
+
+        game.current_color = Some(Color::Green);
+        assert_eq!(game.active_color(), Some(Color::Green));
+    }
+
+    #[test]
+    fn an_empty_discard_pile_has_no_active_color() {
+        let mut game = table(vec![vec![0], vec![1]]);
+        game.current_color = Some(Color::Red);
+
+        assert_eq!(game.active_color(), None);
+    }
+
+    #[test]
+    fn deck_health_degrades_as_the_draw_pile_shrinks() {
+        let mut game = table(vec![vec![0], vec![1]]);
+        let mut transitions = vec![(game.deck_health(0.25, 0.10), game.draw_pile.len())];
+
+        // Play the draw pile out onto the discard pile, so the total stays 108
+        while let Some(card) = game.draw_pile.pop() {
+            game.discard_pile.push(card);
+            let health = game.deck_health(0.25, 0.10);
+            if transitions.last().map(|(last, _)| *last) != Some(health) {
+                transitions.push((health, game.draw_pile.len()));
+            }
+        }
+
+        assert_eq!(game.total_cards(), 108);
+        // 27 of 108 is exactly a quarter, 10 is the last count at or below a tenth
+        assert_eq!(
+            transitions,
+            vec![
+                (DeckHealth::Healthy, 106),
+                (DeckHealth::Low, 27),
+                (DeckHealth::Critical, 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn deck_health_thresholds_are_inclusive() {
+        assert_eq!(
+            DeckHealth::from_counts(26, 100, 0.25, 0.10),
+            DeckHealth::Healthy
+        );
+        assert_eq!(
+            DeckHealth::from_counts(25, 100, 0.25, 0.10),
+            DeckHealth::Low
+        );
+        assert_eq!(
+            DeckHealth::from_counts(11, 100, 0.25, 0.10),
+            DeckHealth::Low
+        );
+        assert_eq!(
+            DeckHealth::from_counts(10, 100, 0.25, 0.10),
+            DeckHealth::Critical
+        );
+        assert_eq!(
+            DeckHealth::from_counts(0, 0, 0.25, 0.10),
+            DeckHealth::Critical
+        );
+    }
+
+    /// Seats holding 5R and 4B under a 3G, with nothing left to draw
+    fn exhausted_table() -> GameState {
+        let mut game = table(vec![vec![9], vec![57]]);
+        game.draw_pile.clear();
+        game.discard_pile = vec![30];
+        game
+    }
+
+    #[test]
+    fn a_table_where_nobody_can_move_is_stuck() {
+        assert!(exhausted_table().is_stuck());
+    }
+
+    #[test]
+    fn a_playable_card_keeps_the_game_going() {
+        let mut game = exhausted_table();
+        // 3Y matches the 3G by value
+        game.player_hands[1].push(80);
+
+        assert!(!game.is_stuck());
+    }
+
+    #[test]
+    fn cards_left_to_draw_or_reshuffle_keep_the_game_going() {
+        let mut drawable = exhausted_table();
+        drawable.draw_pile.push(0);
+        let mut reshuffleable = exhausted_table();
+        reshuffleable.discard_pile.insert(0, 0);
+
+        assert!(!drawable.is_stuck());
+        assert!(!reshuffleable.is_stuck());
+        assert!(!GameState::new().is_stuck());
+    }
+
+    #[test]
+    fn a_drawn_game_reports_no_winner() {
+        let outcome = GameOutcome::Draw {
+            reason: "Deck exhausted and no player can move".to_string(),
+        };
+
+        let json = serde_json::to_value(&outcome).unwrap();
+
+        assert_eq!(json["result"], "draw");
+        assert_eq!(json["reason"], "Deck exhausted and no player can move");
+        assert!(json.get("winner").is_none());
+    }
+
+    #[test]
+    fn idle_time_counts_from_the_last_move_or_the_deal() {
+        let mut game = table(vec![vec![0], vec![1]]);
+        game.created_at = 1_000;
+        assert_eq!(game.idle_seconds(1_030), 30);
+
+        game.last_activity = 1_020;
+        assert_eq!(game.idle_seconds(1_030), 10);
+        // A clock that stepped back never reports negative idle time
+        assert_eq!(game.idle_seconds(900), 0);
+    }
+
+    #[test]
+    fn abandoning_awards_only_a_unique_leader() {
+        let mut leader = table(vec![vec![0, 1], vec![2], vec![3, 4]]);
+        leader.abandon(90, true);
+        assert_eq!(
+            leader.outcome,
+            Some(GameOutcome::Abandoned {
+                winner: Some(1),
+                idle_seconds: 90,
+            })
+        );
+        assert!(leader.is_finished());
+
+        let mut tied = table(vec![vec![0], vec![1], vec![2, 3]]);
+        assert_eq!(tied.most_progressed_player(), None);
+        tied.abandon(90, true);
+        assert_eq!(
+            tied.outcome,
+            Some(GameOutcome::Abandoned {
+                winner: None,
+                idle_seconds: 90,
+            })
+        );
+
+        let mut declared_draw = table(vec![vec![0, 1], vec![2]]);
+        declared_draw.abandon(90, false);
+        assert_eq!(
+            declared_draw.outcome,
+            Some(GameOutcome::Abandoned {
+                winner: None,
+                idle_seconds: 90,
+            })
+        );
+    }
+
+    /// Fresh two-seat table dealt from `value` the way the orchestrator deals it
+    fn seeded_table(value: u64) -> GameState {
+        let seed_metadata = BlockchainSeed {
+            value: U256::from(value),
+            ..BlockchainSeed::default()
+        };
+        let deal = perform_shuffle(2, 7, seed_metadata.shuffle_seed()).unwrap();
+        GameState {
+            seed_metadata,
+            cards_per_player: 7,
+            draw_pile: deal.draw_pile,
+            ..table(deal.player_hands)
+        }
+    }
+
+    #[test]
+    fn games_from_the_same_seed_are_deal_equal_whatever_was_played() {
+        let fresh = seeded_table(0xdea1);
+        let mut played = seeded_table(0xdea1);
+        let card = played.player_hands[0].pop().unwrap();
+        played.discard_pile.push(card);
+        let drawn = played.draw_pile.pop().unwrap();
+        played.player_hands[1].push(drawn);
+
+        assert!(fresh.deals_equal(&played));
+        assert!(played.deals_equal(&fresh));
+    }
+
+    #[test]
+    fn games_from_different_seeds_are_not_deal_equal() {
+        let ours = seeded_table(0xdea1);
+        let theirs = seeded_table(0xdea2);
+
+        assert!(!ours.deals_equal(&theirs));
+    }
+
+    #[test]
+    fn deals_of_different_sizes_are_not_equal() {
+        let seven = seeded_table(0xdea1);
+        let five = GameState {
+            cards_per_player: 5,
+            ..seeded_table(0xdea1)
+        };
+
+        assert!(!seven.deals_equal(&five));
+    }
+
+    #[test]
+    fn the_seed_derived_starting_player_is_the_seed_modulo_the_seats() {
+        assert_eq!(seed_starting_player(U256::from(10), 4), 2);
+        assert_eq!(seed_starting_player(U256::from(10), 5), 0);
+        assert_eq!(seed_starting_player(U256::MAX, 3), 0);
+        assert_eq!(
+            seed_starting_player(U256::from(0xdea1), 4),
+            seed_starting_player(U256::from(0xdea1), 4)
+        );
+        assert!((0..64).all(|seed| seed_starting_player(U256::from(seed), 7) < 7));
+    }
+
+    /// Six seats where seat `n` holds `n + 1` cards
+    fn six_player_table() -> GameState {
+        table(
+            (0..6u8)
+                .map(|seat| (seat * 10..=seat * 11).collect())
+                .collect(),
+        )
+    }
+
+    fn seats(opponents: &[OpponentInfo]) -> Vec<PlayerId> {
+        opponents
+            .iter()
+            .map(|opponent| opponent.player_id)
+            .collect()
+    }
+
+    #[test]
+    fn a_window_keeps_the_seats_either_side_in_turn_order() {
+        let mut game = six_player_table();
+
+        assert_eq!(seats(&game.opponents_window(0, 1)), [1, 5]);
+        assert_eq!(seats(&game.opponents_window(0, 2)), [1, 2, 4, 5]);
+        assert_eq!(seats(&game.opponents_window(3, 1)), [4, 2]);
+        assert_eq!(
+            seats_and_counts(&game.opponents_window(0, 1)),
+            [(1, 2), (5, 6)]
+        );
+        assert!(game.opponents_window(0, 0).is_empty());
+
+        game.direction = Direction::CounterClockwise;
+        assert_eq!(seats(&game.opponents_window(0, 1)), [5, 1]);
+        assert_eq!(seats(&game.opponents_window(0, 2)), [5, 4, 2, 1]);
+    }
+
+    #[test]
+    fn a_window_wider_than_the_table_lists_every_opponent() {
+        let game = six_player_table();
+
+        for window in [3, 5, 100, usize::MAX] {
+            assert_eq!(
+                seats_and_counts(&game.opponents_window(2, window)),
+                seats_and_counts(&game.opponents_of(2))
+            );
+        }
+    }
+
+    #[test]
+    fn the_deal_sequence_goes_round_the_table_one_card_at_a_time() {
+        let game = seeded_table(0xdea1);
+
+        let sequence = game.deal_sequence().unwrap();
+
+        assert_eq!(sequence.len(), 2 * 7);
+        for (position, &(seat, card)) in sequence.iter().enumerate() {
+            assert_eq!(seat as usize, position % 2);
+            assert_eq!(card, game.player_hands[seat as usize][position / 2]);
+        }
+    }
+
+    #[test]
+    fn replaying_the_deal_sequence_rebuilds_the_initial_hands() {
+        let mut game = seeded_table(0xbeef);
+        let initial = game.player_hands.clone();
+        // Play since the deal doesn't change the sequence
+        let card = game.player_hands[1].remove(0);
+        game.discard_pile.push(card);
+
+        let mut rebuilt = vec![Vec::new(); 2];
+        for (seat, card) in game.deal_sequence().unwrap() {
+            rebuilt[seat as usize].push(card);
+        }
+
+        assert_eq!(rebuilt, initial);
+    }
+}
@@ -3,6 +3,7 @@
 pub mod api;
 pub mod blockchain;
 pub mod game;
+pub mod log_buffer;
 pub mod orchestrator;
 pub mod proof_management;
 
@@ -0,0 +1,206 @@
+// backend/src/log_buffer.rs
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex, OnceLock};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+/// Log lines kept when `LOG_BUFFER_LINES` is unset
+pub const DEFAULT_LOG_BUFFER_LINES: usize = 500;
+
+static LOG_BUFFER: OnceLock<Arc<LogBuffer>> = OnceLock::new();
+
+/// A captured log event
+#[derive(Debug, Clone, Serialize)]
+pub struct LogEntry {
+    pub timestamp: String,
+    pub level: String,
+    pub target: String,
+    /// Event message followed by its fields as `key=value`
+    pub message: String,
+    #[serde(skip)]
+    severity: Level,
+}
+
+/// Bounded ring buffer of the most recent log events
+pub struct LogBuffer {
+    capacity: usize,
+    entries: Mutex<VecDeque<LogEntry>>,
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: Mutex::new(VecDeque::with_capacity(capacity.max(1))),
+        }
+    }
+
+    fn push(&self, entry: LogEntry) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    /// Buffered entries at `min_level` or more severe, oldest first
+    pub fn entries(&self, min_level: Level) -> Vec<LogEntry> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries
+            .iter()
+            .filter(|entry| entry.severity <= min_level)
+            .cloned()
+            .collect()
+    }
+}
+
+/// `tracing` layer writing every event it sees into a `LogBuffer`
+pub struct LogBufferLayer {
+    buffer: Arc<LogBuffer>,
+}
+
+impl LogBufferLayer {
+    pub fn new(buffer: Arc<LogBuffer>) -> Self {
+        Self { buffer }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for LogBufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        self.buffer.push(LogEntry {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            level: metadata.level().to_string(),
+            target: metadata.target().to_string(),
+            message: visitor.finish(),
+            severity: *metadata.level(),
+        });
+    }
+}
+
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        if self.fields.is_empty() {
+            self.message
+        } else {
+            format!("{}{}", self.message, self.fields)
+        }
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        } else {
+            let _ = write!(self.fields, " {}={:?}", field.name(), value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={}", field.name(), value);
+        }
+    }
+}
+
+/// Create the process-wide buffer and return a layer feeding it
+///
+/// Size comes from `LOG_BUFFER_LINES`; only the first call installs a buffer.
+pub fn install_from_env() -> LogBufferLayer {
+    let capacity = std::env::var("LOG_BUFFER_LINES")
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(DEFAULT_LOG_BUFFER_LINES);
+
+    let buffer = LOG_BUFFER.get_or_init(|| Arc::new(LogBuffer::new(capacity)));
+    LogBufferLayer::new(buffer.clone())
+}
+
+/// The process-wide buffer, if logging was set up with one
+pub fn global() -> Option<&'static LogBuffer> {
+    LOG_BUFFER.get().map(|buffer| buffer.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    /// Run `emit` with only a layer feeding `buffer` installed
+    fn capture(buffer: &Arc<LogBuffer>, emit: impl FnOnce()) {
+        let subscriber = tracing_subscriber::registry().with(LogBufferLayer::new(buffer.clone()));
+        tracing::subscriber::with_default(subscriber, emit);
+    }
+
+    #[test]
+    fn events_are_captured_with_their_fields() {
+        let buffer = Arc::new(LogBuffer::new(10));
+
+        capture(&buffer, || {
+            tracing::info!(session_id = "abc", players = 3, "Game started");
+        });
+
+        let entries = buffer.entries(Level::TRACE);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].level, "INFO");
+        assert_eq!(entries[0].target, module_path!());
+        assert_eq!(entries[0].message, "Game started session_id=abc players=3");
+    }
+
+    #[test]
+    fn entries_are_filtered_by_minimum_level() {
+        let buffer = Arc::new(LogBuffer::new(10));
+
+        capture(&buffer, || {
+            tracing::debug!("noise");
+            tracing::info!("progress");
+            tracing::warn!("slow prover");
+            tracing::error!("upload failed");
+        });
+
+        let messages = |level| -> Vec<String> {
+            buffer
+                .entries(level)
+                .into_iter()
+                .map(|entry| entry.message)
+                .collect()
+        };
+        assert_eq!(messages(Level::WARN), ["slow prover", "upload failed"]);
+        assert_eq!(messages(Level::ERROR), ["upload failed"]);
+        assert_eq!(messages(Level::DEBUG).len(), 4);
+    }
+
+    #[test]
+    fn only_the_newest_lines_are_kept() {
+        let buffer = Arc::new(LogBuffer::new(3));
+
+        capture(&buffer, || {
+            for line in 0..5 {
+                tracing::info!("line {}", line);
+            }
+        });
+
+        let messages: Vec<String> = buffer
+            .entries(Level::TRACE)
+            .into_iter()
+            .map(|entry| entry.message)
+            .collect();
+        assert_eq!(messages, ["line 2", "line 3", "line 4"]);
+    }
+}
@@ -3,17 +3,14 @@
 mod api;
 mod blockchain;
 mod game;
+mod log_buffer;
 mod orchestrator;
 mod proof_management;
 
 use anyhow::Result;
-use axum::{
-    routing::{get, post},
-    Router,
-};
 use rustls::crypto::{ring, CryptoProvider};
 use std::sync::Arc;
-use tower_http::trace::TraceLayer;
+use tracing_subscriber::prelude::*;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -23,19 +20,22 @@ async fn main() -> Result<()> {
     // Load .env file
     dotenvy::dotenv().ok();
 
-    // Initialize tracing
-    tracing_subscriber::fmt()
-        .with_target(false)
-        .with_env_filter(
+    // Initialize tracing (stdout plus the in-memory buffer behind /api/debug/logs)
+    tracing_subscriber::registry()
+        .with(
             tracing_subscriber::EnvFilter::from_default_env()
                 .add_directive("backend=debug".parse().unwrap())
                 .add_directive("tower_http=debug".parse().unwrap())
                 .add_directive("zunnogame_script=info".parse().unwrap()),
         )
+        .with(tracing_subscriber::fmt::layer().with_target(false))
+        .with(log_buffer::install_from_env())
         .init();
 
     tracing::info!("Starting Zunno Game Server");
 
+    game::init_card_names_from_env()?;
+
     // Initialize blockchain adapter
     tracing::info!("Initializing blockchain adapter...");
     let blockchain = blockchain::BlockchainAdapter::new().await?;
@@ -43,23 +43,19 @@ async fn main() -> Result<()> {
 
     // Initialize orchestrator
     tracing::info!("Initializing game orchestrator...");
-    let orchestrator = Arc::new(orchestrator::GameOrchestrator::new(blockchain).await?);
+    let config = orchestrator::OrchestratorConfig::from_env()?;
+    let orchestrator = Arc::new(orchestrator::GameOrchestrator::new(blockchain, config).await?);
     tracing::info!("Game orchestrator initialized");
 
+    orchestrator.restore_snapshot().await?;
+
     // Start background tasks
     tracing::info!("Starting background tasks...");
     orchestrator.clone().start_background_tasks();
     tracing::info!("Background tasks started");
 
     // Build API routes
-    let app = Router::new()
-        .route("/api/game/start", post(api::start_game))
-        .route("/api/game/:session_id/status", get(api::get_game_status))
-        .route("/api/game/:session_id", get(api::get_game_state))
-        .route("/api/game/:session_id/proof", get(api::get_game_proof))
-        .route("/health", get(|| async { "OK" }))
-        .layer(TraceLayer::new_for_http())
-        .with_state(orchestrator);
+    let app = api::build_router(orchestrator.clone());
 
     // Start server
     let addr = "0.0.0.0:3000";
@@ -71,9 +67,69 @@ async fn main() -> Result<()> {
     tracing::info!("  GET    /api/game/:session_id/status");
     tracing::info!("  GET    /api/game/:session_id");
     tracing::info!("  GET    /api/game/:session_id/proof");
+    tracing::info!("  POST   /api/game/:session_id/proof (ADMIN)");
+    tracing::info!("  GET    /api/game/:session_id/proof/download");
+    tracing::info!("  GET    /api/game/:session_id/discard");
+    tracing::info!("  GET    /api/game/:session_id/replay");
+    tracing::info!("  GET    /api/game/:session_id/stats (ADMIN)");
+    tracing::info!("  POST   /api/game/:session_id/pass");
+    tracing::info!("  POST   /api/game/:session_id/set-color (ADMIN)");
+    tracing::info!("  POST   /api/game/:session_id/fork");
+    tracing::info!("  POST   /api/game/:session_id/rematch");
+    tracing::info!("  GET    /api/game/:session_id/verify/draw-pile");
+    tracing::info!("  GET    /api/game/:session_id/player/:player_id/hand");
+    tracing::info!("  GET    /api/game/:session_id/player/:player_id/playable");
+    tracing::info!("  GET    /api/game/:session_id/player/:player_id/opponents");
+    tracing::info!("  GET    /api/game/:session_id/player/:player_id/info");
+    tracing::info!("  POST   /api/game/:session_id/player/:player_id/draw");
+    tracing::info!("  POST   /api/game/:session_id/debug/reshuffle (DEV_MODE)");
+    tracing::info!("  GET    /api/debug/logs (DEV_MODE)");
+    tracing::info!("  GET    /api/estimate");
+    tracing::info!("  GET    /api/proof/manifest");
+    tracing::info!("  GET    /api/proof/image-id");
+    tracing::info!("  GET    /api/proof/vk");
+    tracing::info!("  GET    /api/admin/games (ADMIN)");
+    tracing::info!("  GET    /api/admin/config (ADMIN)");
+    tracing::info!("  GET    /api/admin/compare (ADMIN)");
+    tracing::info!("  GET    /api/admin/vrf/:request_id (ADMIN)");
+    tracing::info!("  POST   /api/admin/game/:session_id/rerequest-vrf (ADMIN)");
+    tracing::info!("  POST   /api/admin/rpc/swap (ADMIN)");
     tracing::info!("  GET    /health");
+    tracing::info!("  GET    /health/deep");
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await?;
 
-    axum::serve(listener, app).await?;
+    tracing::info!("Server stopped, flushing state");
+    orchestrator.flush_all().await;
 
     Ok(())
 }
+
+/// Resolves on Ctrl+C or SIGTERM
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("Failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    tracing::info!("Shutdown signal received");
+}
@@ -0,0 +1,278 @@
+// backend/src/orchestrator/config.rs
+
+use alloy::primitives::{keccak256, B256, U256};
+use anyhow::{anyhow, Result};
+use std::env;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use zunnogame_script::ProofSizeLimits;
+
+use super::storage::PENDING_GAME_TTL_SECS;
+
+/// Ceiling for the doubled finalization backoff; the game sits in `GeneratingProof` meanwhile
+const MAX_FINALIZE_RETRY_DELAY: Duration = Duration::from_secs(300);
+
+/// Tunable settings for the game orchestrator
+#[derive(Debug, Clone)]
+pub struct OrchestratorConfig {
+    /// Number of times a failed finalization (shuffle, proof, upload) is retried
+    pub finalize_max_retries: u32,
+    /// Delay before the first finalization retry, doubled on every further attempt
+    pub finalize_retry_backoff: Duration,
+    /// Maximum number of proofs generated concurrently; further jobs queue
+    pub prover_threads: usize,
+    /// Maximum accepted size of a JSON request body, in bytes
+    pub max_request_body_bytes: usize,
+    /// Fail finalization when a VRF value was already used by another game (otherwise warn)
+    pub reject_seed_reuse: bool,
+    /// Fail finalization when a VRF value looks weak (otherwise warn); zero always fails
+    pub reject_weak_seeds: bool,
+    /// VRF values with fewer significant bits than this are flagged as weak
+    pub min_seed_bits: usize,
+    /// Approximate prover throughput used for proof time estimates
+    pub prover_cycles_per_sec: u64,
+    /// Enables debug-only endpoints
+    pub dev_mode: bool,
+    /// Token required in the `x-admin-token` header; admin endpoints are disabled when unset
+    pub admin_token: Option<String>,
+    /// Salt mixed into every shuffle seed so instances sharing a VRF contract deal differently
+    pub instance_salt: B256,
+    /// Serialized proofs shorter than this are treated as corrupt
+    pub proof_min_bytes: usize,
+    /// Serialized proofs longer than this are treated as corrupt
+    pub proof_max_bytes: usize,
+    /// Draw pile share of all cards at or below which deck health is `Low`
+    pub deck_low_fraction: f64,
+    /// Draw pile share of all cards at or below which deck health is `Critical`
+    pub deck_critical_fraction: f64,
+    /// End games as a draw when the deck is exhausted and nobody can move
+    pub auto_end_stuck_games: bool,
+    /// File every in-memory game is written to on shutdown and before proving, and restored
+    /// from at startup; no persistence when unset
+    pub shutdown_snapshot_path: Option<PathBuf>,
+    /// Upper bound on the shutdown flush
+    pub shutdown_flush_timeout: Duration,
+    /// Seed every game is dealt from instead of a VRF value, so all tables are identical
+    pub tournament_seed: Option<U256>,
+    /// Hex SHA-256 the embedded program ELF must have; startup fails on mismatch
+    pub expected_elf_hash: Option<String>,
+    /// Dealt games with no move for this long are marked abandoned; disabled when unset
+    pub game_idle_timeout: Option<Duration>,
+    /// Abandoned games go to the player with the fewest cards instead of ending as a draw
+    pub idle_award_win: bool,
+    /// Verify each proof locally and against the host deal before it is submitted or stored
+    pub verify_before_ready: bool,
+    /// Proofs whose IPFS upload exhausts its retries are written here and re-uploaded at
+    /// startup; without it a failed upload fails the game
+    pub ipfs_dead_letter_dir: Option<PathBuf>,
+    /// Games stay `Committed` this long after their VRF request before the fulfillment is
+    /// even read; disabled when unset
+    pub commit_reveal_delay: Option<Duration>,
+    /// Suggested per-card deal animation time sent to frontends
+    pub deal_timing_ms: u64,
+}
+
+impl Default for OrchestratorConfig {
+    fn default() -> Self {
+        Self {
+            finalize_max_retries: 3,
+            finalize_retry_backoff: Duration::from_secs(5),
+            prover_threads: 1,
+            max_request_body_bytes: 16 * 1024,
+            reject_seed_reuse: false,
+            reject_weak_seeds: false,
+            min_seed_bits: 128,
+            prover_cycles_per_sec: 1_000_000,
+            dev_mode: false,
+            admin_token: None,
+            instance_salt: B256::ZERO,
+            proof_min_bytes: ProofSizeLimits::default().min_bytes,
+            proof_max_bytes: ProofSizeLimits::default().max_bytes,
+            deck_low_fraction: 0.25,
+            deck_critical_fraction: 0.10,
+            auto_end_stuck_games: false,
+            shutdown_snapshot_path: None,
+            shutdown_flush_timeout: Duration::from_secs(10),
+            tournament_seed: None,
+            expected_elf_hash: None,
+            game_idle_timeout: None,
+            idle_award_win: false,
+            verify_before_ready: false,
+            ipfs_dead_letter_dir: None,
+            commit_reveal_delay: None,
+            deal_timing_ms: 150,
+        }
+    }
+}
+
+impl OrchestratorConfig {
+    /// Load configuration from env, falling back to defaults for unset values
+    pub fn from_env() -> Result<Self> {
+        let defaults = Self::default();
+
+        let proof_min_bytes = env_or("PROOF_MIN_BYTES", defaults.proof_min_bytes)?;
+        let proof_max_bytes = env_or("PROOF_MAX_BYTES", defaults.proof_max_bytes)?;
+        if proof_min_bytes > proof_max_bytes {
+            return Err(anyhow!(
+                "PROOF_MIN_BYTES ({}) exceeds PROOF_MAX_BYTES ({})",
+                proof_min_bytes,
+                proof_max_bytes
+            ));
+        }
+
+        let deck_low_fraction = env_or("DECK_LOW_FRACTION", defaults.deck_low_fraction)?;
+        let deck_critical_fraction =
+            env_or("DECK_CRITICAL_FRACTION", defaults.deck_critical_fraction)?;
+        if !(0.0..=1.0).contains(&deck_low_fraction)
+            || !(0.0..=deck_low_fraction).contains(&deck_critical_fraction)
+        {
+            return Err(anyhow!(
+                "Deck health thresholds must satisfy 0 <= DECK_CRITICAL_FRACTION ({}) <= DECK_LOW_FRACTION ({}) <= 1",
+                deck_critical_fraction,
+                deck_low_fraction
+            ));
+        }
+
+        let tournament_seed = tournament_seed_from_env()?;
+
+        // Zero keeps the delay disabled; it must end before the pending game is cleaned up
+        let commit_reveal_delay = Some(env_or("COMMIT_REVEAL_DELAY_SECS", 0u64)?)
+            .filter(|&secs| secs > 0)
+            .map(Duration::from_secs);
+        if let Some(delay) = commit_reveal_delay {
+            if delay.as_secs() >= PENDING_GAME_TTL_SECS {
+                return Err(anyhow!(
+                    "COMMIT_REVEAL_DELAY_SECS ({}) must be below the pending game TTL ({}s)",
+                    delay.as_secs(),
+                    PENDING_GAME_TTL_SECS
+                ));
+            }
+        }
+
+        Ok(Self {
+            finalize_max_retries: env_or("FINALIZE_MAX_RETRIES", defaults.finalize_max_retries)?,
+            finalize_retry_backoff: Duration::from_secs(env_or(
+                "FINALIZE_RETRY_BACKOFF_SECS",
+                defaults.finalize_retry_backoff.as_secs(),
+            )?),
+            prover_threads: env_or("PROVER_THREADS", defaults.prover_threads)?.max(1),
+            max_request_body_bytes: env_or(
+                "MAX_REQUEST_BODY_BYTES",
+                defaults.max_request_body_bytes,
+            )?,
+            reject_seed_reuse: env_or("REJECT_SEED_REUSE", defaults.reject_seed_reuse)?,
+            reject_weak_seeds: env_or("REJECT_WEAK_SEEDS", defaults.reject_weak_seeds)?,
+            min_seed_bits: env_or("MIN_SEED_BITS", defaults.min_seed_bits)?.min(256),
+            prover_cycles_per_sec: env_or("PROVER_CYCLES_PER_SEC", defaults.prover_cycles_per_sec)?
+                .max(1),
+            dev_mode: env_or("DEV_MODE", defaults.dev_mode)?,
+            admin_token: env::var("ADMIN_TOKEN")
+                .ok()
+                .filter(|token| !token.trim().is_empty()),
+            instance_salt: instance_salt_from_env(),
+            proof_min_bytes,
+            proof_max_bytes,
+            deck_low_fraction,
+            deck_critical_fraction,
+            auto_end_stuck_games: env_or("AUTO_END_STUCK_GAMES", defaults.auto_end_stuck_games)?,
+            shutdown_snapshot_path: env::var("SHUTDOWN_SNAPSHOT_PATH")
+                .ok()
+                .filter(|path| !path.trim().is_empty())
+                .map(PathBuf::from),
+            shutdown_flush_timeout: Duration::from_secs(env_or(
+                "SHUTDOWN_FLUSH_TIMEOUT_SECS",
+                defaults.shutdown_flush_timeout.as_secs(),
+            )?),
+            tournament_seed,
+            expected_elf_hash: env::var("EXPECTED_ELF_HASH")
+                .ok()
+                .filter(|hash| !hash.trim().is_empty()),
+            // Zero keeps the timeout disabled
+            game_idle_timeout: Some(env_or("GAME_IDLE_TIMEOUT_SECS", 0u64)?)
+                .filter(|&secs| secs > 0)
+                .map(Duration::from_secs),
+            idle_award_win: env_or("GAME_IDLE_AWARD_WIN", defaults.idle_award_win)?,
+            verify_before_ready: env_or("VERIFY_BEFORE_READY", defaults.verify_before_ready)?,
+            ipfs_dead_letter_dir: env::var("IPFS_DEAD_LETTER_DIR")
+                .ok()
+                .filter(|dir| !dir.trim().is_empty())
+                .map(PathBuf::from),
+            commit_reveal_delay,
+            deal_timing_ms: env_or("DEAL_TIMING_MS", defaults.deal_timing_ms)?,
+        })
+    }
+
+    /// Backoff before the given retry attempt (1-based), capped at five minutes
+    pub fn finalize_backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.finalize_retry_backoff
+            .checked_mul(factor)
+            .unwrap_or(MAX_FINALIZE_RETRY_DELAY)
+            .min(MAX_FINALIZE_RETRY_DELAY)
+    }
+
+    /// Accepted serialized proof size range
+    pub fn proof_size_limits(&self) -> ProofSizeLimits {
+        ProofSizeLimits {
+            min_bytes: self.proof_min_bytes,
+            max_bytes: self.proof_max_bytes,
+        }
+    }
+}
+
+/// `INSTANCE_SALT` hashed to 32 bytes, or zero when unset
+fn instance_salt_from_env() -> B256 {
+    match env::var("INSTANCE_SALT") {
+        Ok(salt) if !salt.trim().is_empty() => keccak256(salt.trim().as_bytes()),
+        _ => B256::ZERO,
+    }
+}
+
+/// `TOURNAMENT_SEED` as decimal or `0x` hex, or `None` when unset
+fn tournament_seed_from_env() -> Result<Option<U256>> {
+    let seed = match env::var("TOURNAMENT_SEED") {
+        Ok(seed) if !seed.trim().is_empty() => U256::from_str(seed.trim())
+            .map_err(|e| anyhow!("Invalid value for TOURNAMENT_SEED: {}", e))?,
+        _ => return Ok(None),
+    };
+
+    if seed.is_zero() {
+        return Err(anyhow!("TOURNAMENT_SEED must be non-zero"));
+    }
+
+    Ok(Some(seed))
+}
+
+/// Parse an env var, returning `default` when it is unset or empty
+pub(crate) fn env_or<T: FromStr>(key: &str, default: T) -> Result<T>
+where
+    T::Err: std::fmt::Display,
+{
+    match env::var(key) {
+        Ok(value) if !value.trim().is_empty() => value
+            .trim()
+            .parse()
+            .map_err(|e| anyhow!("Invalid value for {}: {}", key, e)),
+        _ => Ok(default),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finalize_backoff_doubles_up_to_its_cap() {
+        let config = OrchestratorConfig {
+            finalize_retry_backoff: Duration::from_secs(10),
+            ..OrchestratorConfig::default()
+        };
+        let schedule: Vec<u64> = (1..=8)
+            .map(|attempt| config.finalize_backoff(attempt).as_secs())
+            .collect();
+
+        assert_eq!(schedule, [10, 20, 40, 80, 160, 300, 300, 300]);
+        assert_eq!(config.finalize_backoff(u32::MAX), MAX_FINALIZE_RETRY_DELAY);
+    }
+}
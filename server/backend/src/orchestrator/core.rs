@@ -1,24 +1,124 @@
 // backend/src/orchestrator/core.rs
 
-use alloy::primitives::U256;
+use alloy::primitives::{hex, Address, U256};
+use alloy_sol_types::SolType;
 use anyhow::{anyhow, Result};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::{RwLock, Semaphore};
+use tokio::time::Instant;
+use url::Url;
 use uuid::Uuid;
 
+use super::config::OrchestratorConfig;
+use super::services::{ProofSink, Prover, RelayerSink};
 use super::storage::{
-    current_timestamp, u256_to_bytes32, ActionOutput, GameInitiation, GameStatus,
-    GameStatusResponse, PendingGame,
+    current_timestamp, u256_to_bytes32, ActionOutput, FailureReason, GameFailure, GameInitiation,
+    GameOptions, GameSnapshot, GameStatus, GameStatusResponse, GameSummary, PendingGame,
+    ProofEstimate, SeedSource, VrfRequestLookup,
+};
+use crate::blockchain::{seed_weakness, BlockchainAdapter, BlockchainSeed, MasterSeed, VrfMode};
+use crate::game::{
+    perform_shuffle, seed_starting_player, validate_game_params, CardOrigin, Direction, GameState,
 };
-use crate::blockchain::{BlockchainAdapter, BlockchainSeed};
-use crate::game::{perform_shuffle, GameState};
 use crate::proof_management::{
-    config::IpfsProvider,
-    proof_verification::verify_proof,
-    retry_service::{IpfsService, IpfsUploadConfig},
+    dead_letter::{self, DeadLetter},
+    proof_verification::VerificationResult,
+    retry_service::PinReceipt,
 };
-use zunnogame_script::{ProofGenerator, ProofInput, ProofOutput};
+use zunnogame_lib::{build_public_values, commit_seed_derivation, PublicValuesStruct};
+use zunnogame_script::{ProofGenerator, ProofInput, ProofManifest, ProofOutput};
+
+/// Prover probes tried before the prover is reported unhealthy
+const PROVER_HEALTH_ATTEMPTS: u32 = 2;
+
+/// How long a prover probe result is reused before the prover is probed again
+const PROVER_HEALTH_TTL: Duration = Duration::from_secs(30);
+
+/// Longest wait for the RPC to answer a health probe
+const RPC_HEALTH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What earlier finalization attempts got through, so a retry resumes after it
+#[derive(Default)]
+struct FinalizeProgress {
+    proof: Option<ProofOutput>,
+    verification: Option<VerificationResult>,
+}
+
+/// Status reported for a game that has been dealt
+fn dealt_game_status(game_state: &GameState) -> GameStatus {
+    if game_state.is_finished() {
+        GameStatus::Finished
+    } else {
+        GameStatus::Ready
+    }
+}
+
+/// Snapshot writes started by this process, so concurrent writes never share a temp file
+static SNAPSHOT_WRITES: AtomicU64 = AtomicU64::new(0);
+
+/// Temp file a snapshot is written to before being renamed over `snapshot_path`
+///
+/// Unique per process and write, so a second flush or another instance on the same path
+/// can't truncate a file that is about to be renamed.
+fn snapshot_tmp_path(snapshot_path: &Path) -> PathBuf {
+    let mut name = snapshot_path.as_os_str().to_owned();
+    name.push(format!(
+        ".{}.{}.tmp",
+        std::process::id(),
+        SNAPSHOT_WRITES.fetch_add(1, Ordering::Relaxed)
+    ));
+    PathBuf::from(name)
+}
+
+/// Journal of games that started proving since the snapshot at `snapshot_path` was written
+fn seed_journal_path(snapshot_path: &Path) -> PathBuf {
+    let mut name = snapshot_path.as_os_str().to_owned();
+    name.push(".seeds");
+    PathBuf::from(name)
+}
+
+/// Append one game to the seed journal as a JSON line
+async fn append_seed_journal(journal_path: &Path, game: &PendingGame) -> Result<()> {
+    let mut line = serde_json::to_vec(game)?;
+    line.push(b'\n');
+
+    let mut journal = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(journal_path)
+        .await?;
+    journal.write_all(&line).await?;
+    journal.flush().await?;
+    Ok(())
+}
+
+/// Games in the seed journal in the order they were appended
+///
+/// A line cut short by a crash mid-append is skipped.
+async fn read_seed_journal(journal_path: &Path) -> Result<Vec<PendingGame>> {
+    let bytes = match tokio::fs::read(journal_path).await {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    Ok(bytes
+        .split(|&byte| byte == b'\n')
+        .filter(|line| !line.is_empty())
+        .filter_map(|line| match serde_json::from_slice(line) {
+            Ok(game) => Some(game),
+            Err(e) => {
+                tracing::warn!(error = %e, "Skipping unreadable seed journal entry");
+                None
+            }
+        })
+        .collect())
+}
 
 /// Main orchestrator that coordinates VRF requests, game initialization, and state management
 #[derive(Clone)]
@@ -30,29 +130,379 @@ pub struct GameOrchestrator {
     /// Blockchain adapter for VRF operations
     blockchain: Arc<BlockchainAdapter>,
     // Proof generator (expensive to create, reuse)
-    proof_generator: Arc<ProofGenerator>,
+    prover: Arc<dyn Prover>,
+    /// zkVerify submission and IPFS storage for generated proofs
+    sink: Arc<dyn ProofSink>,
+    /// Seed values already dealt, mapped to the session that used them
+    used_seeds: Arc<RwLock<HashMap<U256, String>>>,
+    /// Cycle counts per (num_players, cards_per_player), deterministic per params
+    cycle_estimates: Arc<RwLock<HashMap<(u8, u8), u64>>>,
+    /// Every VRF request id issued, mapped to the session it was issued for
+    vrf_index: Arc<RwLock<HashMap<U256, String>>>,
+    /// Sessions with a fulfillment check currently running
+    in_flight_checks: Arc<RwLock<HashSet<String>>>,
+    /// Manifest for the embedded program, derived once at startup
+    proof_manifest: Arc<ProofManifest>,
+    /// Bounds concurrent proof jobs so they don't starve the blocking pool
+    proof_slots: Arc<Semaphore>,
+    /// Sessions waiting for a prover slot, in the order they will get one
+    proof_queue: Arc<RwLock<VecDeque<String>>>,
+    /// Last prover probe result and when it was taken
+    prover_health: Arc<RwLock<Option<(Instant, bool)>>>,
+    /// Orchestrator settings
+    config: Arc<OrchestratorConfig>,
 }
 
 impl GameOrchestrator {
     /// Create a new game orchestrator
-    pub async fn new(blockchain: BlockchainAdapter) -> Result<Self> {
+    pub async fn new(blockchain: BlockchainAdapter, config: OrchestratorConfig) -> Result<Self> {
         tracing::info!("Initializing proof generator...");
-        let proof_generator = Arc::new(ProofGenerator::new()?);
-        tracing::info!("Proof generator ready");
+        let proof_generator =
+            ProofGenerator::with_expected_elf_hash(config.expected_elf_hash.as_deref())?
+                .with_size_limits(config.proof_size_limits())
+                .with_local_verification(config.verify_before_ready);
 
-        Ok(Self {
+        Ok(Self::with_services(
+            blockchain,
+            config,
+            Arc::new(proof_generator),
+            Arc::new(RelayerSink),
+        ))
+    }
+
+    /// Create an orchestrator around the given prover and proof sink instead of the SP1
+    /// prover, zkVerify relayer and IPFS providers
+    pub fn with_services(
+        blockchain: BlockchainAdapter,
+        config: OrchestratorConfig,
+        prover: Arc<dyn Prover>,
+        sink: Arc<dyn ProofSink>,
+    ) -> Self {
+        let proof_manifest = Arc::new(prover.manifest());
+        tracing::info!(
+            image_id = %proof_manifest.image_id,
+            program_version = %proof_manifest.program_version,
+            "Proof generator ready"
+        );
+
+        Self {
             pending_games: Arc::new(RwLock::new(HashMap::new())),
             completed_games: Arc::new(RwLock::new(HashMap::new())),
             blockchain: Arc::new(blockchain),
-            proof_generator,
-        })
+            prover,
+            sink,
+            used_seeds: Arc::new(RwLock::new(HashMap::new())),
+            cycle_estimates: Arc::new(RwLock::new(HashMap::new())),
+            vrf_index: Arc::new(RwLock::new(HashMap::new())),
+            in_flight_checks: Arc::new(RwLock::new(HashSet::new())),
+            proof_manifest,
+            proof_slots: Arc::new(Semaphore::new(config.prover_threads)),
+            proof_queue: Arc::new(RwLock::new(VecDeque::new())),
+            prover_health: Arc::new(RwLock::new(None)),
+            config: Arc::new(config),
+        }
+    }
+
+    /// Effective orchestrator configuration
+    pub fn config(&self) -> &OrchestratorConfig {
+        &self.config
+    }
+
+    /// How the blockchain adapter waits for VRF fulfillments
+    pub fn vrf_mode(&self) -> VrfMode {
+        self.blockchain.vrf_mode
+    }
+
+    /// VRF coordinator contract the adapter talks to
+    pub fn contract_address(&self) -> Address {
+        self.blockchain.contract_address
+    }
+
+    /// WebSocket and HTTP RPC endpoints currently in use
+    pub fn rpc_urls(&self) -> (String, Url) {
+        self.blockchain.rpc_urls()
+    }
+
+    /// Point the blockchain adapter at new RPC endpoints; returns the chain id they serve
+    pub async fn swap_rpc_providers(&self, ws_rpc_url: &str, http_rpc_url: &str) -> Result<u64> {
+        self.blockchain
+            .swap_providers(ws_rpc_url, http_rpc_url)
+            .await
+    }
+
+    /// Flush every in-memory game to `SHUTDOWN_SNAPSHOT_PATH` before exit
+    ///
+    /// Bounded by `shutdown_flush_timeout` so a slow disk can't hang shutdown.
+    pub async fn flush_all(&self) {
+        let Some(path) = self.config.shutdown_snapshot_path.as_deref() else {
+            tracing::info!("No shutdown snapshot path configured, skipping flush");
+            return;
+        };
+
+        match tokio::time::timeout(
+            self.config.shutdown_flush_timeout,
+            self.write_snapshot(path),
+        )
+        .await
+        {
+            Ok(Ok(count)) => tracing::info!(
+                path = %path.display(),
+                games = count,
+                "Flushed games on shutdown"
+            ),
+            Ok(Err(e)) => tracing::error!(
+                path = %path.display(),
+                error = %e,
+                "Failed to flush games on shutdown"
+            ),
+            Err(_) => tracing::error!(
+                path = %path.display(),
+                timeout = ?self.config.shutdown_flush_timeout,
+                "Shutdown flush timed out"
+            ),
+        }
+    }
+
+    /// Write all games as JSON, via a temp file so a partial write never replaces a good one
+    async fn write_snapshot(&self, path: &Path) -> Result<usize> {
+        let snapshot = GameSnapshot {
+            taken_at: current_timestamp(),
+            pending: self.pending_games.read().await.clone(),
+            completed: self.completed_games.read().await.clone(),
+        };
+        let count = snapshot.pending.len() + snapshot.completed.len();
+
+        let bytes = serde_json::to_vec(&snapshot)?;
+        let tmp_path = snapshot_tmp_path(path);
+        tokio::fs::write(&tmp_path, bytes).await?;
+        if let Err(e) = tokio::fs::rename(&tmp_path, path).await {
+            tokio::fs::remove_file(&tmp_path).await.ok();
+            return Err(e.into());
+        }
+
+        // Every journaled game is in the snapshot now
+        if let Err(e) = tokio::fs::remove_file(seed_journal_path(path)).await {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(e.into());
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Load games from `SHUTDOWN_SNAPSHOT_PATH` and its seed journal, if either was written
+    ///
+    /// A crash before the first clean shutdown leaves only the journal, which is restored on
+    /// its own. Call before `start_background_tasks`, which resumes interrupted proofs.
+    pub async fn restore_snapshot(&self) -> Result<usize> {
+        let Some(path) = self.config.shutdown_snapshot_path.as_deref() else {
+            return Ok(0);
+        };
+
+        let snapshot: Option<GameSnapshot> = match tokio::fs::read(path).await {
+            Ok(bytes) => Some(serde_json::from_slice(&bytes)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+        // Games that started proving after the snapshot was written
+        let journal = read_seed_journal(&seed_journal_path(path)).await?;
+        if snapshot.is_none() && journal.is_empty() {
+            return Ok(0);
+        }
+        let mut snapshot = snapshot.unwrap_or_else(|| GameSnapshot {
+            taken_at: 0,
+            pending: HashMap::new(),
+            completed: HashMap::new(),
+        });
+        for game in journal {
+            if !snapshot.completed.contains_key(&game.session_id) {
+                snapshot.pending.insert(game.session_id.clone(), game);
+            }
+        }
+        let count = snapshot.pending.len() + snapshot.completed.len();
+
+        {
+            let mut vrf_index = self.vrf_index.write().await;
+            for game in snapshot.pending.values() {
+                for request_id in game
+                    .vrf_request_history
+                    .iter()
+                    .chain(std::iter::once(&game.vrf_request_id))
+                    .filter(|id| !id.is_zero())
+                {
+                    vrf_index.insert(*request_id, game.session_id.clone());
+                }
+            }
+        }
+        {
+            let mut used_seeds = self.used_seeds.write().await;
+            for (session_id, game_state) in &snapshot.completed {
+                if !game_state.tournament && game_state.verifiable {
+                    used_seeds
+                        .entry(game_state.seed_metadata.value)
+                        .or_insert_with(|| session_id.clone());
+                }
+            }
+        }
+
+        self.pending_games.write().await.extend(snapshot.pending);
+        self.completed_games
+            .write()
+            .await
+            .extend(snapshot.completed);
+
+        tracing::info!(
+            path = %path.display(),
+            games = count,
+            taken_at = snapshot.taken_at,
+            "Restored games from snapshot"
+        );
+
+        Ok(count)
+    }
+
+    /// Re-run proving for games that were interrupted in `GeneratingProof`
+    ///
+    /// Uses the persisted seed, so no new VRF request is made.
+    async fn resume_interrupted_proofs(self: Arc<Self>) {
+        let interrupted: Vec<PendingGame> = self
+            .pending_games
+            .read()
+            .await
+            .values()
+            .filter(|game| game.status == GameStatus::GeneratingProof)
+            .cloned()
+            .collect();
+
+        for game in interrupted {
+            let Some(seed) = game.proof_seed else {
+                tracing::warn!(
+                    session_id = %game.session_id,
+                    "Interrupted game has no persisted seed, marking failed"
+                );
+                let mut games = self.pending_games.write().await;
+                if let Some(game) = games.get_mut(&game.session_id) {
+                    game.status = GameStatus::Failed {
+                        reason: FailureReason::Internal,
+                        detail: "Proving was interrupted before its seed was persisted".to_string(),
+                    };
+                }
+                continue;
+            };
+
+            tracing::info!(
+                session_id = %game.session_id,
+                "Resuming interrupted proof from the persisted seed"
+            );
+
+            let orchestrator = self.clone();
+            tokio::spawn(async move {
+                // Failures are recorded on the pending game by the retry wrapper
+                let _ = orchestrator.finalize_with_retry(&game, seed).await;
+            });
+        }
+    }
+
+    /// Persist the seed a game is about to be proven with
+    ///
+    /// Appends just this game to the seed journal next to the snapshot, which
+    /// `restore_snapshot` merges back in. Best effort: a failed write only costs the
+    /// ability to resume after a crash.
+    async fn persist_proof_seed(&self, session_id: &str, seed: U256) {
+        let game = {
+            let mut games = self.pending_games.write().await;
+            let Some(game) = games.get_mut(session_id) else {
+                return;
+            };
+            game.proof_seed = Some(seed);
+            game.clone()
+        };
+
+        let Some(path) = self.config.shutdown_snapshot_path.as_deref() else {
+            tracing::warn!(
+                session_id = session_id,
+                "SHUTDOWN_SNAPSHOT_PATH is not set, so this proof can't resume after a crash"
+            );
+            return;
+        };
+        if let Err(e) = append_seed_journal(&seed_journal_path(path), &game).await {
+            tracing::warn!(
+                session_id = session_id,
+                error = %e,
+                "Failed to persist proof seed"
+            );
+        }
+    }
+
+    /// Manifest of the program proofs are generated against
+    pub fn proof_manifest(&self) -> &ProofManifest {
+        &self.proof_manifest
+    }
+
+    /// Probe the prover, retrying so a single hiccup doesn't report it down
+    ///
+    /// Results are reused for `PROVER_HEALTH_TTL`. A probe takes a prover slot, so when every
+    /// slot is busy proving the last result stands instead (healthy if there is none yet).
+    pub async fn prover_healthy(&self) -> bool {
+        let cached = *self.prover_health.read().await;
+        if let Some((probed_at, healthy)) = cached {
+            if probed_at.elapsed() < PROVER_HEALTH_TTL {
+                return healthy;
+            }
+        }
+
+        let Ok(permit) = self.proof_slots.clone().try_acquire_owned() else {
+            return cached.map_or(true, |(_, healthy)| healthy);
+        };
+
+        let mut healthy = false;
+        for attempt in 1..=PROVER_HEALTH_ATTEMPTS {
+            let prover = self.prover.clone();
+            healthy = tokio::task::spawn_blocking(move || prover.health_check())
+                .await
+                .unwrap_or(false);
+            if healthy {
+                break;
+            }
+            tracing::warn!(
+                attempt = attempt,
+                max_attempts = PROVER_HEALTH_ATTEMPTS,
+                "Prover health check failed"
+            );
+        }
+        drop(permit);
+
+        *self.prover_health.write().await = Some((Instant::now(), healthy));
+        healthy
+    }
+
+    /// Whether the WebSocket RPC answers within `RPC_HEALTH_TIMEOUT`
+    pub async fn rpc_healthy(&self) -> bool {
+        tokio::time::timeout(RPC_HEALTH_TIMEOUT, self.blockchain.check_ws_health())
+            .await
+            .unwrap_or(false)
+    }
+
+    /// Serialized verifying key of the embedded program, shared by every game's proof
+    pub fn export_vk(&self) -> Result<Vec<u8>> {
+        self.prover.export_vk()
     }
 
     /// Start background tasks (VRF listener, cleanup)
     pub fn start_background_tasks(self: Arc<Self>) {
-        // Spawn VRF fulfillment checker
+        // One shared subscription routes fulfillments to every waiting game
+        tokio::spawn(self.blockchain.clone().run_vrf_event_listener());
+
+        // Finish proofs interrupted by a restart
+        tokio::spawn(self.clone().resume_interrupted_proofs());
+
+        // Pin proofs whose upload gave up before the last shutdown
+        tokio::spawn(self.clone().sweep_dead_letters());
+
+        // Catch up on events missed while offline, then spawn VRF fulfillment checker
         let orchestrator = self.clone();
         tokio::spawn(async move {
+            orchestrator.catch_up_missed_vrf_events().await;
             orchestrator.run_vrf_fulfillment_loop().await;
         });
 
@@ -61,6 +511,10 @@ impl GameOrchestrator {
         tokio::spawn(async move {
             orchestrator.cleanup_expired_games().await;
         });
+
+        if let Some(timeout) = self.config.game_idle_timeout {
+            tokio::spawn(self.clone().abandon_idle_games(timeout));
+        }
     }
 
     /// Initiate a new game
@@ -70,16 +524,60 @@ impl GameOrchestrator {
         &self,
         num_players: u8,
         cards_per_player: u8,
+        options: GameOptions,
     ) -> Result<GameInitiation> {
         tracing::info!(
             num_players = num_players,
             cards_per_player = cards_per_player,
+            theme = %options.theme,
             "Initiating new game"
         );
 
+        // Reject bad parameters before spending a VRF request
+        validate_game_params(num_players, cards_per_player)?;
+
+        // Each seat gets a token gating its private view, unless carried over from a rematch
+        let mut options = options;
+        if options.player_tokens.is_empty() {
+            options.player_tokens = (0..num_players)
+                .map(|_| Uuid::new_v4().to_string())
+                .collect();
+        } else if options.player_tokens.len() != num_players as usize {
+            return Err(anyhow!(
+                "Expected {} player tokens, got {}",
+                num_players,
+                options.player_tokens.len()
+            ));
+        }
+
         // Generate unique session ID
         let session_id = Uuid::new_v4().to_string();
 
+        // Tournament games skip VRF and go straight to the deal
+        let tournament_seed = self
+            .config
+            .tournament_seed
+            .filter(|_| options.seed_source == SeedSource::Vrf);
+
+        // Games attached to a master draw skip their own VRF request as well
+        let master_seed = match options.master_session.as_deref() {
+            Some(master_session) => {
+                if options.seed_source != SeedSource::Vrf || tournament_seed.is_some() {
+                    return Err(anyhow!(
+                        "A master session needs VRF seeding and no tournament seed"
+                    ));
+                }
+                Some(self.master_seed_for(master_session, &session_id).await?)
+            }
+            None => None,
+        };
+
+        let status = if tournament_seed.is_some() || master_seed.is_some() {
+            GameStatus::GeneratingProof
+        } else {
+            GameStatus::Requesting
+        };
+
         // Create pending game entry
         let pending = PendingGame {
             session_id: session_id.clone(),
@@ -88,21 +586,81 @@ impl GameOrchestrator {
             num_players,
             cards_per_player,
             requested_at: current_timestamp(),
-            status: GameStatus::Requesting,
+            status: status.clone(),
+            options,
+            vrf_request_history: Vec::new(),
+            tournament: tournament_seed.is_some(),
+            proof_seed: None,
+            committed_at: 0,
+            master_seed,
         };
 
         // Store pending game
         self.pending_games
             .write()
             .await
-            .insert(session_id.clone(), pending);
+            .insert(session_id.clone(), pending.clone());
+        let player_tokens = pending.options.player_tokens.clone();
+
+        if pending.options.seed_source == SeedSource::Os {
+            tracing::warn!(
+                session_id = %session_id,
+                "Dealing from OS randomness; the game has no proof"
+            );
+
+            let seed = U256::from_be_bytes(rand::random::<[u8; 32]>());
+            self.deal_unverified(&pending, seed).await?;
+
+            return Ok(GameInitiation {
+                session_id,
+                status: GameStatus::Ready,
+                estimated_wait_seconds: 0,
+                vrf_request_id: U256::ZERO,
+                player_tokens,
+            });
+        }
+
+        if let Some(master) = &pending.master_seed {
+            tracing::info!(
+                session_id = %session_id,
+                master_session = %master.master_session,
+                "Dealing from a seed derived from the master VRF value"
+            );
+        } else if tournament_seed.is_some() {
+            tracing::info!(session_id = %session_id, "Dealing from the tournament seed");
+        }
+
+        let direct_seed = pending
+            .master_seed
+            .as_ref()
+            .map(MasterSeed::value)
+            .or(tournament_seed);
+        if let Some(seed) = direct_seed {
+            let orchestrator = self.clone();
+            tokio::spawn(async move {
+                // Failures are recorded on the pending game by the retry wrapper
+                let _ = orchestrator.finalize_with_retry(&pending, seed).await;
+            });
+
+            return Ok(GameInitiation {
+                session_id,
+                status,
+                estimated_wait_seconds: 30, // Proof generation only
+                vrf_request_id: U256::ZERO,
+                player_tokens,
+            });
+        }
 
         // Request VRF in background (non-blocking)
         let orchestrator = self.clone();
         let session_id_clone = session_id.clone();
 
         tokio::spawn(async move {
-            if let Err(e) = orchestrator.request_vrf_for_game(&session_id_clone).await {
+            if let Err(e) = orchestrator
+                .request_vrf_for_game(&session_id_clone)
+                .await
+                .map_err(|e| anyhow::Error::new(GameFailure::new(FailureReason::VrfTimeout, e)))
+            {
                 tracing::error!(
                     session_id = %session_id_clone,
                     error = %e,
@@ -112,7 +670,7 @@ impl GameOrchestrator {
                 // Update status to failed
                 let mut games = orchestrator.pending_games.write().await;
                 if let Some(game) = games.get_mut(&session_id_clone) {
-                    game.status = GameStatus::Failed(e.to_string());
+                    game.status = GameStatus::failed(&e);
                 }
             }
         });
@@ -123,18 +681,63 @@ impl GameOrchestrator {
             status: GameStatus::Requesting,
             estimated_wait_seconds: 60, // Estimate for VRF + proof generation
             vrf_request_id: U256::ZERO, // Will be updated after VRF request
+            player_tokens,
+        })
+    }
+
+    /// Master seed for a new game `session_id` attached to `master_session`'s VRF value
+    ///
+    /// A master that was itself derived passes on its own master, so a whole batch derives
+    /// from the one on-chain draw.
+    async fn master_seed_for(&self, master_session: &str, session_id: &str) -> Result<MasterSeed> {
+        let master = self
+            .get_game_state(master_session)
+            .await
+            .map_err(|_| anyhow!("Master game not dealt or not found: {}", master_session))?;
+        if !master.can_be_master() {
+            return Err(anyhow!(
+                "Master game {} was not dealt from a VRF value",
+                master_session
+            ));
+        }
+
+        let seed = master.seed_metadata;
+        Ok(match seed.master {
+            Some(root) => MasterSeed {
+                context: session_id.to_string(),
+                ..root
+            },
+            None => MasterSeed {
+                master_session: master_session.to_string(),
+                request_id: seed.request_id,
+                master_value: seed.value,
+                context: session_id.to_string(),
+            },
         })
     }
 
     /// Get current status of a game
     pub async fn get_game_status(&self, session_id: &str) -> Result<GameStatusResponse> {
         // Check if game is completed
-        if self.completed_games.read().await.contains_key(session_id) {
+        if let Some(game_state) = self.completed_games.read().await.get(session_id) {
             return Ok(GameStatusResponse {
                 session_id: session_id.to_string(),
-                status: GameStatus::Ready,
+                status: dealt_game_status(game_state),
                 elapsed_seconds: 0, // Game is ready
                 vrf_request_id: None,
+                name: game_state.name.clone(),
+                theme: game_state.theme.clone(),
+                deck_health: Some(game_state.deck_health(
+                    self.config.deck_low_fraction,
+                    self.config.deck_critical_fraction,
+                )),
+                outcome: game_state.outcome.clone(),
+                tournament: game_state.tournament,
+                verifiable: game_state.verifiable,
+                idle_seconds: (!game_state.is_finished())
+                    .then(|| game_state.idle_seconds(current_timestamp())),
+                queue_position: None,
+                reveal_at: None,
             });
         }
 
@@ -147,12 +750,123 @@ impl GameOrchestrator {
                 status: pending.status.clone(),
                 elapsed_seconds: elapsed,
                 vrf_request_id: Some(pending.vrf_request_id),
+                name: pending.options.name.clone(),
+                theme: pending.options.theme.clone(),
+                deck_health: None,
+                outcome: None,
+                tournament: pending.tournament,
+                verifiable: pending.options.seed_source == SeedSource::Vrf,
+                idle_seconds: None,
+                queue_position: self.queue_position(session_id).await,
+                reveal_at: self.reveal_at(pending),
             });
         }
 
         Err(anyhow!("Game session not found: {}", session_id))
     }
 
+    /// When a `Committed` game's commit window closes
+    fn reveal_at(&self, game: &PendingGame) -> Option<u64> {
+        let delay = self.config.commit_reveal_delay?;
+        (game.status == GameStatus::Committed).then(|| game.committed_at + delay.as_secs())
+    }
+
+    /// Status for a game whose VRF request was just recorded
+    fn status_after_vrf_request(&self) -> GameStatus {
+        if self.config.commit_reveal_delay.is_some() {
+            GameStatus::Committed
+        } else {
+            GameStatus::WaitingForVRF
+        }
+    }
+
+    /// Move `Committed` games whose commit window has closed on to `WaitingForVRF`
+    ///
+    /// Until then their fulfillment is never read, so the deal can't depend on anything the
+    /// server saw after publishing the request.
+    async fn open_commit_windows(&self) {
+        let Some(delay) = self.config.commit_reveal_delay else {
+            return;
+        };
+        let now = current_timestamp();
+
+        let mut games = self.pending_games.write().await;
+        for game in games.values_mut() {
+            if game.status == GameStatus::Committed && now >= game.committed_at + delay.as_secs() {
+                game.status = GameStatus::WaitingForVRF;
+                tracing::info!(
+                    session_id = %game.session_id,
+                    request_id = %game.vrf_request_id,
+                    "Commit window closed, revealing deal"
+                );
+            }
+        }
+    }
+
+    /// 1-based place of a session among proofs waiting for a prover slot
+    ///
+    /// `None` once the session holds a slot, or if it never queued.
+    pub async fn queue_position(&self, session_id: &str) -> Option<usize> {
+        self.proof_queue
+            .read()
+            .await
+            .iter()
+            .position(|queued| queued == session_id)
+            .map(|index| index + 1)
+    }
+
+    /// Summaries of every known game, newest first
+    ///
+    /// `name_filter` keeps only games whose name contains it (case-insensitive).
+    pub async fn list_games(&self, name_filter: Option<&str>) -> Vec<GameSummary> {
+        let completed = self.completed_games.read().await;
+        let pending = self.pending_games.read().await;
+
+        let mut games: Vec<GameSummary> = completed
+            .iter()
+            .map(|(session_id, game_state)| GameSummary {
+                session_id: session_id.clone(),
+                name: game_state.name.clone(),
+                status: dealt_game_status(game_state),
+                num_players: game_state.player_count() as u8,
+                cards_per_player: game_state.cards_per_player,
+                theme: game_state.theme.clone(),
+                requested_at: game_state.created_at,
+                tournament: game_state.tournament,
+            })
+            .chain(
+                pending
+                    .values()
+                    .filter(|game| !completed.contains_key(&game.session_id))
+                    .map(|game| GameSummary {
+                        session_id: game.session_id.clone(),
+                        name: game.options.name.clone(),
+                        status: game.status.clone(),
+                        num_players: game.num_players,
+                        cards_per_player: game.cards_per_player,
+                        theme: game.options.theme.clone(),
+                        requested_at: game.requested_at,
+                        tournament: game.tournament,
+                    }),
+            )
+            .collect();
+
+        if let Some(filter) = name_filter.map(str::to_lowercase) {
+            games.retain(|game| {
+                game.name
+                    .as_deref()
+                    .is_some_and(|name| name.to_lowercase().contains(&filter))
+            });
+        }
+
+        games.sort_by(|a, b| {
+            b.requested_at
+                .cmp(&a.requested_at)
+                .then_with(|| a.session_id.cmp(&b.session_id))
+        });
+        games
+    }
+
     /// Get completed game state
     pub async fn get_game_state(&self, session_id: &str) -> Result<GameState> {
         self.completed_games
@@ -163,6 +877,108 @@ impl GameOrchestrator {
             .ok_or_else(|| anyhow!("Game not ready or not found: {}", session_id))
     }
 
+    /// Estimate proving cost for the given parameters by executing the program (no proof)
+    pub async fn estimate_proof_cost(
+        &self,
+        num_players: u8,
+        cards_per_player: u8,
+    ) -> Result<ProofEstimate> {
+        validate_game_params(num_players, cards_per_player)?;
+
+        let cached = self
+            .cycle_estimates
+            .read()
+            .await
+            .get(&(num_players, cards_per_player))
+            .copied();
+
+        let cycles = match cached {
+            Some(cycles) => cycles,
+            None => {
+                // Executing the program is as heavy as a proof's setup, so it takes a slot too
+                let permit = self
+                    .proof_slots
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .map_err(|e| anyhow!("Prover pool closed: {}", e))?;
+
+                // Cycle count barely depends on the seed, so any fixed seed works
+                let cycles = tokio::task::spawn_blocking({
+                    let prover = self.prover.clone();
+
+                    move || {
+                        let _permit = permit;
+                        let input = ProofInput::builder()
+                            .num_players(num_players)
+                            .cards_per_player(cards_per_player)
+                            .seed([0u8; 32])
+                            .build()?;
+                        prover.estimate_cycles(input)
+                    }
+                })
+                .await
+                .map_err(|e| anyhow!("Cycle estimate task panicked: {}", e))??;
+
+                self.cycle_estimates
+                    .write()
+                    .await
+                    .insert((num_players, cards_per_player), cycles);
+                cycles
+            }
+        };
+
+        Ok(ProofEstimate {
+            num_players,
+            cards_per_player,
+            cycles,
+            estimated_seconds: cycles.div_ceil(self.config.prover_cycles_per_sec),
+        })
+    }
+
+    /// Copy a dealt game into a new session that is played independently
+    ///
+    /// Returns the new session id and the forked state.
+    pub async fn fork_game(&self, session_id: &str) -> Result<(String, GameState)> {
+        let mut games = self.completed_games.write().await;
+        let mut fork = games
+            .get(session_id)
+            .cloned()
+            .ok_or_else(|| anyhow!("Game not ready or not found: {}", session_id))?;
+
+        let fork_id = Uuid::new_v4().to_string();
+        fork.forked_from = Some(session_id.to_string());
+        fork.created_at = current_timestamp();
+        fork.last_activity = fork.created_at;
+        games.insert(fork_id.clone(), fork.clone());
+
+        tracing::info!(
+            session_id = session_id,
+            fork_id = %fork_id,
+            "Forked game"
+        );
+
+        Ok((fork_id, fork))
+    }
+
+    /// Apply a mutation to a completed game under the write lock
+    ///
+    /// Returns `None` if the game isn't finalized or doesn't exist.
+    pub async fn update_game_state<T>(
+        &self,
+        session_id: &str,
+        update: impl FnOnce(&mut GameState) -> Result<T>,
+    ) -> Option<Result<T>> {
+        let mut games = self.completed_games.write().await;
+        games.get_mut(session_id).map(|game_state| {
+            let result = update(game_state);
+            if result.is_ok() {
+                game_state.last_activity = current_timestamp();
+            }
+            result
+        })
+    }
+
     /// Request VRF for a specific game session
     async fn request_vrf_for_game(&self, session_id: &str) -> Result<()> {
         tracing::info!(session_id = session_id, "Requesting VRF for game");
@@ -177,73 +993,238 @@ impl GameOrchestrator {
             "VRF request successful"
         );
 
+        self.vrf_index
+            .write()
+            .await
+            .insert(vrf_request.request_id, session_id.to_string());
+
         // Update pending game with VRF details
         let mut games = self.pending_games.write().await;
         if let Some(game) = games.get_mut(session_id) {
             game.vrf_request_id = vrf_request.request_id;
             game.vrf_block_number = vrf_request.block_number;
-            game.status = GameStatus::WaitingForVRF;
+            game.committed_at = current_timestamp();
+            game.status = self.status_after_vrf_request();
         }
 
         Ok(())
     }
 
-    /// Background loop that checks for VRF fulfillment
-    async fn run_vrf_fulfillment_loop(&self) {
-        tracing::info!("Starting VRF fulfillment checker loop");
+    /// Get a pending game entry
+    pub async fn get_pending_game(&self, session_id: &str) -> Option<PendingGame> {
+        self.pending_games.read().await.get(session_id).cloned()
+    }
 
-        loop {
-            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+    /// Game a VRF request id was issued for, including superseded re-requests
+    pub async fn lookup_vrf_request(&self, request_id: U256) -> Option<VrfRequestLookup> {
+        let session_id = self.vrf_index.read().await.get(&request_id).cloned()?;
 
-            // Get all pending games waiting for VRF
-            let pending_games = self.pending_games.read().await;
-            let games_to_check: Vec<_> = pending_games
-                .values()
-                .filter(|g| g.status == GameStatus::WaitingForVRF)
-                .cloned()
-                .collect();
-            drop(pending_games);
-
-            // Check each game for VRF fulfillment
-            for pending_game in games_to_check {
-                let orchestrator = self.clone();
-                let session_id = pending_game.session_id.clone();
-                let request_id = pending_game.vrf_request_id;
-                let block_number = pending_game.vrf_block_number;
-                let num_players = pending_game.num_players;
-                let cards_per_player = pending_game.cards_per_player;
-
-                tokio::spawn(async move {
-                    if let Err(e) = orchestrator
-                        .check_and_finalize_game(
-                            &session_id,
-                            request_id,
-                            block_number,
-                            num_players,
-                            cards_per_player,
-                        )
-                        .await
-                    {
-                        tracing::debug!(
-                            session_id = %session_id,
-                            error = %e,
-                            "VRF not yet fulfilled or error occurred"
-                        );
-                    }
-                });
-            }
+        if let Some(pending) = self.get_pending_game(&session_id).await {
+            return Some(VrfRequestLookup {
+                request_id,
+                superseded: pending.vrf_request_id != request_id,
+                status: pending.status,
+                session_id,
+            });
         }
+
+        // Dealt games outlive their pending entry, which cleanup drops after a while
+        let games = self.completed_games.read().await;
+        let game_state = games.get(&session_id)?;
+        Some(VrfRequestLookup {
+            request_id,
+            superseded: game_state.seed_metadata.request_id != request_id,
+            status: dealt_game_status(game_state),
+            session_id,
+        })
     }
 
-    /// Check if VRF is fulfilled and finalize the game
-    async fn check_and_finalize_game(
-        &self,
-        session_id: &str,
-        request_id: U256,
-        block_number: u64,
-        num_players: u8,
-        cards_per_player: u8,
-    ) -> Result<()> {
+    /// Issue a fresh VRF request for a game still waiting on randomness
+    ///
+    /// The superseded request id is kept in the game's request history for auditing.
+    pub async fn rerequest_vrf(&self, session_id: &str) -> Result<PendingGame> {
+        match self.get_pending_game(session_id).await {
+            Some(game) if game.status.awaits_vrf() => {}
+            Some(game) => {
+                return Err(anyhow!(
+                    "VRF can only be re-requested while waiting for VRF (status: {:?})",
+                    game.status
+                ))
+            }
+            None => return Err(anyhow!("Game session not found: {}", session_id)),
+        }
+
+        tracing::warn!(session_id = session_id, "Re-requesting VRF for game");
+
+        let vrf_request = self.blockchain.request_vrf().await?;
+
+        let mut games = self.pending_games.write().await;
+        let game = games
+            .get_mut(session_id)
+            .ok_or_else(|| anyhow!("Game session not found: {}", session_id))?;
+
+        if !game.status.awaits_vrf() {
+            return Err(anyhow!(
+                "Game moved to {:?} while re-requesting VRF",
+                game.status
+            ));
+        }
+
+        // A new request is a new commitment, so its window starts over
+        game.vrf_request_history.push(game.vrf_request_id);
+        game.vrf_request_id = vrf_request.request_id;
+        game.vrf_block_number = vrf_request.block_number;
+        game.committed_at = current_timestamp();
+        game.status = self.status_after_vrf_request();
+
+        self.vrf_index
+            .write()
+            .await
+            .insert(vrf_request.request_id, session_id.to_string());
+
+        tracing::info!(
+            session_id = session_id,
+            request_id = %vrf_request.request_id,
+            previous_requests = game.vrf_request_history.len(),
+            "VRF re-requested"
+        );
+
+        Ok(game.clone())
+    }
+
+    /// Scan historical logs for games whose VRF was fulfilled while the server was down
+    ///
+    /// Complements the live fulfillment loop: any reloaded `WaitingForVRF` game with a
+    /// fulfillment event between its request block and head is finalized immediately.
+    async fn catch_up_missed_vrf_events(&self) {
+        let games_to_check: Vec<_> = self
+            .pending_games
+            .read()
+            .await
+            .values()
+            .filter(|g| g.status == GameStatus::WaitingForVRF)
+            .cloned()
+            .collect();
+
+        if games_to_check.is_empty() {
+            return;
+        }
+
+        tracing::info!(
+            games = games_to_check.len(),
+            "Scanning for VRF events missed while offline"
+        );
+
+        for pending_game in games_to_check {
+            let session_id = pending_game.session_id.clone();
+            let request_id = pending_game.vrf_request_id;
+
+            let random_value = match self
+                .blockchain
+                .check_for_missed_event(request_id, pending_game.vrf_block_number)
+                .await
+            {
+                Ok(Some(random_value)) => random_value,
+                Ok(None) => continue,
+                Err(e) => {
+                    tracing::warn!(
+                        session_id = %session_id,
+                        error = %e,
+                        "Historical VRF scan failed, leaving game to the live loop"
+                    );
+                    continue;
+                }
+            };
+
+            tracing::info!(
+                session_id = %session_id,
+                request_id = %request_id,
+                "Found VRF fulfillment missed while offline"
+            );
+
+            // Claim the game before spawning so the live loop doesn't pick it up too
+            if !self.claim_for_finalization(&pending_game).await {
+                continue;
+            }
+
+            let orchestrator = self.clone();
+            tokio::spawn(async move {
+                if let Err(e) = orchestrator
+                    .finalize_with_retry(&pending_game, random_value)
+                    .await
+                {
+                    tracing::error!(
+                        session_id = %session_id,
+                        error = %e,
+                        "Failed to finalize caught-up game"
+                    );
+                }
+            });
+        }
+    }
+
+    /// Background loop that checks for VRF fulfillment
+    async fn run_vrf_fulfillment_loop(&self) {
+        tracing::info!("Starting VRF fulfillment checker loop");
+
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+            self.open_commit_windows().await;
+            self.spawn_fulfillment_checks().await;
+        }
+    }
+
+    /// Start a fulfillment check for every game waiting for VRF; returns how many started
+    ///
+    /// Games whose previous check is still running are skipped.
+    async fn spawn_fulfillment_checks(&self) -> usize {
+        let pending_games = self.pending_games.read().await;
+        let games_to_check: Vec<_> = pending_games
+            .values()
+            .filter(|g| g.status == GameStatus::WaitingForVRF)
+            .cloned()
+            .collect();
+        drop(pending_games);
+
+        let mut spawned = 0;
+        for pending_game in games_to_check {
+            if !self
+                .in_flight_checks
+                .write()
+                .await
+                .insert(pending_game.session_id.clone())
+            {
+                continue;
+            }
+
+            let orchestrator = self.clone();
+            spawned += 1;
+
+            tokio::spawn(async move {
+                if let Err(e) = orchestrator.check_and_finalize_game(&pending_game).await {
+                    tracing::debug!(
+                        session_id = %pending_game.session_id,
+                        error = %e,
+                        "VRF not yet fulfilled or error occurred"
+                    );
+                }
+
+                orchestrator
+                    .in_flight_checks
+                    .write()
+                    .await
+                    .remove(&pending_game.session_id);
+            });
+        }
+        spawned
+    }
+
+    /// Check if VRF is fulfilled and finalize the game
+    async fn check_and_finalize_game(&self, game: &PendingGame) -> Result<()> {
+        let session_id = game.session_id.as_str();
+        let request_id = game.vrf_request_id;
+
         tracing::debug!(
             session_id = session_id,
             request_id = %request_id,
@@ -253,7 +1234,7 @@ impl GameOrchestrator {
         // Try to get random value (with short timeout for polling approach)
         let random_value = self
             .blockchain
-            .get_randomness(request_id, block_number, 10)
+            .get_randomness(request_id, game.vrf_block_number, 10)
             .await?;
 
         tracing::info!(
@@ -263,72 +1244,228 @@ impl GameOrchestrator {
             "VRF fulfilled! Finalizing game..."
         );
 
-        // Update status to generating proof
-        {
-            let mut games = self.pending_games.write().await;
-            if let Some(game) = games.get_mut(session_id) {
-                game.status = GameStatus::GeneratingProof;
+        if !self.claim_for_finalization(game).await {
+            return Err(anyhow!(
+                "VRF request {} was superseded or the game is already finalizing",
+                request_id
+            ));
+        }
+
+        self.finalize_with_retry(game, random_value).await
+    }
+
+    /// Move a game from `WaitingForVRF` to `GeneratingProof`
+    ///
+    /// Fails if the game has moved on or its VRF request was re-issued since `game` was read,
+    /// so a stale fulfillment can never finalize the game.
+    async fn claim_for_finalization(&self, game: &PendingGame) -> bool {
+        let mut games = self.pending_games.write().await;
+        match games.get_mut(&game.session_id) {
+            Some(current)
+                if current.status == GameStatus::WaitingForVRF
+                    && current.vrf_request_id == game.vrf_request_id =>
+            {
+                current.status = GameStatus::GeneratingProof;
+                true
             }
+            _ => false,
         }
+    }
 
-        // Finalize the game
-        self.finalize_game(
-            session_id,
-            random_value,
-            request_id,
-            num_players,
-            cards_per_player,
-        )
-        .await?;
+    /// Run finalization for a claimed game, retrying transient failures
+    ///
+    /// Permanent failures such as a rejected seed or a proof that doesn't match the deal fail
+    /// the game straight away.
+    async fn finalize_with_retry(&self, game: &PendingGame, random_value: U256) -> Result<()> {
+        let session_id = game.session_id.as_str();
 
-        Ok(())
+        // Finalize the game, retrying transient failures (the seed is fixed, so this is deterministic)
+        let mut progress = FinalizeProgress::default();
+        let mut attempt = 0;
+        loop {
+            match self.finalize_game(game, random_value, &mut progress).await {
+                Ok(()) => return Ok(()),
+                Err(e)
+                    if attempt < self.config.finalize_max_retries
+                        && !GameFailure::is_permanent(&e) =>
+                {
+                    attempt += 1;
+                    let delay = self.config.finalize_backoff(attempt);
+                    tracing::warn!(
+                        session_id = session_id,
+                        attempt = attempt,
+                        max_retries = self.config.finalize_max_retries,
+                        delay_secs = delay.as_secs(),
+                        error = %e,
+                        "Finalization failed, retrying"
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => {
+                    tracing::error!(
+                        session_id = session_id,
+                        attempts = attempt + 1,
+                        error = %e,
+                        "Finalization failed, giving up"
+                    );
+
+                    let mut games = self.pending_games.write().await;
+                    if let Some(game) = games.get_mut(session_id) {
+                        game.status = GameStatus::failed(&e);
+                    }
+
+                    return Err(e);
+                }
+            }
+        }
     }
 
     /// Finalize game: shuffle, generate proof, store state
     async fn finalize_game(
         &self,
-        session_id: &str,
+        game: &PendingGame,
         random_value: U256,
-        request_id: U256,
-        num_players: u8,
-        cards_per_player: u8,
+        progress: &mut FinalizeProgress,
     ) -> Result<()> {
+        let session_id = game.session_id.as_str();
+        let num_players = game.num_players;
+        let cards_per_player = game.cards_per_player;
+
         tracing::info!(session_id = session_id, "Finalizing game with VRF seed");
 
+        self.check_seed_entropy(session_id, random_value)
+            .map_err(|e| GameFailure::permanent(FailureReason::InvalidParams, e))?;
+        // Tournament games share their seed by design
+        if !game.tournament {
+            self.check_seed_reuse(session_id, random_value)
+                .await
+                .map_err(|e| GameFailure::permanent(FailureReason::InvalidParams, e))?;
+        }
+
+        let seed_metadata = BlockchainSeed {
+            value: random_value,
+            request_id: game
+                .master_seed
+                .as_ref()
+                .map_or(game.vrf_request_id, |master| master.request_id),
+            instance_salt: self.config.instance_salt,
+            master: game.master_seed.clone(),
+        };
         let seed_bytes = u256_to_bytes32(random_value);
+        let seed_derivation = game.master_seed.as_ref().map(MasterSeed::derivation);
+        let instance_salt = seed_metadata.instance_salt.0;
+        let salted_hands = game.options.salted_hands;
+        let build_merkle = game.options.build_merkle;
 
         // Perform shuffle
-        let shuffle_outcome = perform_shuffle(num_players, cards_per_player, seed_bytes)?;
+        let shuffle_outcome =
+            perform_shuffle(num_players, cards_per_player, seed_metadata.shuffle_seed())
+                .map_err(|e| GameFailure::permanent(FailureReason::InvalidParams, e))?;
+
+        // Covers what the program asserts; failing here avoids a doomed proof run
+        shuffle_outcome
+            .check_invariants(num_players, cards_per_player)
+            .map_err(|e| {
+                GameFailure::permanent(
+                    FailureReason::Internal,
+                    format!("Shuffle produced an invalid deal: {}", e),
+                )
+            })?;
 
         tracing::info!(session_id = session_id, "Shuffle complete");
 
-        tracing::info!(session_id = session_id, "Generating ZK proof...");
+        // A retry reuses the proof and verification an earlier attempt got, so an upload
+        // failure doesn't cost another proof run
+        let proof_result = match progress.proof.clone() {
+            Some(proof_result) => proof_result,
+            None => {
+                self.persist_proof_seed(session_id, random_value).await;
+
+                // Wait for a free prover slot; jobs beyond `prover_threads` queue here. The semaphore
+                // hands out permits in FIFO order, so the queue mirrors who gets the next slot
+                self.proof_queue
+                    .write()
+                    .await
+                    .push_back(session_id.to_string());
+                let proof_permit = self.proof_slots.clone().acquire_owned().await;
+                self.proof_queue
+                    .write()
+                    .await
+                    .retain(|queued| queued != session_id);
+                let proof_permit =
+                    proof_permit.map_err(|e| anyhow!("Prover pool closed: {}", e))?;
 
-        let proof_result = tokio::task::spawn_blocking({
-            let proof_generator = self.proof_generator.clone();
+                tracing::info!(session_id = session_id, "Generating ZK proof...");
 
-            move || {
-                proof_generator.generate_proof(ProofInput {
-                    num_players,
-                    cards_per_player,
-                    seed: seed_bytes,
+                let proof_result = tokio::task::spawn_blocking({
+                    let prover = self.prover.clone();
+
+                    move || {
+                        let _permit = proof_permit;
+                        let input = ProofInput::builder()
+                            .num_players(num_players)
+                            .cards_per_player(cards_per_player)
+                            .seed(seed_bytes)
+                            .instance_salt(instance_salt)
+                            .salted_hands(salted_hands)
+                            .build_merkle(build_merkle)
+                            .seed_derivation(seed_derivation)
+                            .build()?;
+                        prover.generate_proof(input)
+                    }
                 })
-            }
-        })
-        .await
-        .map_err(|e| anyhow!("Proof generation task panicked: {}", e))??;
+                .await
+                .map_err(|e| anyhow!("Proof generation task panicked: {}", e))?
+                .map_err(|e| GameFailure::new(FailureReason::ProofGeneration, e))?;
 
-        tracing::info!(
-            session_id = session_id,
-            proof_id = %proof_result.image_id[..18],
-            "Proof generated successfully"
-        );
+                tracing::info!(
+                    session_id = session_id,
+                    proof_id = %proof_result.image_id[..18],
+                    "Proof generated successfully"
+                );
 
-        let result: ProofOutput = proof_result.clone();
-        let verification_tx = match verify_proof(result).await {
-            Ok(tx_hash) => tx_hash,
-            Err(e) => {
-                return Err(anyhow!(e));
+                if self.config.verify_before_ready {
+                    let mut expected = build_public_values(
+                        num_players,
+                        cards_per_player,
+                        seed_bytes,
+                        instance_salt,
+                        &shuffle_outcome,
+                        salted_hands,
+                        build_merkle,
+                    );
+                    if let Some(master) = &seed_metadata.master {
+                        commit_seed_derivation(&mut expected, &master.derivation());
+                    }
+                    let expected = hex::encode_prefixed(PublicValuesStruct::abi_encode(&expected));
+                    if !proof_result.pub_inputs.eq_ignore_ascii_case(&expected) {
+                        return Err(GameFailure::permanent(
+                            FailureReason::ProofGeneration,
+                            "Proof public values don't match the host deal",
+                        )
+                        .into());
+                    }
+                    tracing::info!(
+                        session_id = session_id,
+                        "Proof checked against the host deal"
+                    );
+                }
+
+                progress.proof = Some(proof_result.clone());
+                proof_result
+            }
+        };
+
+        let verification = match progress.verification.clone() {
+            Some(verification) => verification,
+            None => {
+                let verification = self
+                    .sink
+                    .verify(proof_result.clone())
+                    .await
+                    .map_err(|e| GameFailure::new(FailureReason::ProofVerification, e))?;
+                progress.verification = Some(verification.clone());
+                verification
             }
         };
 
@@ -346,26 +1483,95 @@ impl GameOrchestrator {
             }
         };
 
-        let proof_cid: String = self.upload_proof(output).await?;
+        let receipt = match self.upload_proof(output.clone()).await {
+            Ok(receipt) => Some(receipt),
+            Err(e) => {
+                let Some(dir) = self.config.ipfs_dead_letter_dir.as_deref() else {
+                    return Err(GameFailure::new(FailureReason::ProofUpload, e).into());
+                };
 
-        tracing::info!(
-            session_id = session_id.to_string(),
-            proof_cid = %proof_cid,
-            "Proof stored"
-        );
+                // Keep the verified proof and deal the game; the startup sweep pins it later
+                let path = dead_letter::write(dir, &DeadLetter::new(output, &e))
+                    .await
+                    .map_err(|write_error| {
+                        GameFailure::new(
+                            FailureReason::ProofUpload,
+                            format!("{}; dead-lettering failed too: {}", e, write_error),
+                        )
+                    })?;
+                tracing::error!(
+                    session_id = session_id,
+                    path = %path.display(),
+                    error = %e,
+                    "Proof upload failed, dead-lettered for a later sweep"
+                );
+
+                None
+            }
+        };
+
+        if let Some(receipt) = &receipt {
+            tracing::info!(
+                session_id = session_id.to_string(),
+                proof_cid = %receipt.cid,
+                pinned_by = ?receipt.pinned_by,
+                "Proof stored"
+            );
+            for (provider, error) in &receipt.failed {
+                tracing::warn!(
+                    session_id = session_id,
+                    provider = %provider,
+                    error = %error,
+                    "Proof pin failed on provider"
+                );
+            }
+        }
+        let (proof_cid, proof_pinned_by) = match receipt {
+            Some(receipt) => (Some(receipt.cid), receipt.pinned_by),
+            None => (None, Vec::new()),
+        };
+
+        let starting_player = game
+            .options
+            .starting_player
+            .unwrap_or_else(|| seed_starting_player(random_value, num_players));
 
         // Create game state
+        let card_origin = CardOrigin::dealt(&shuffle_outcome.player_hands);
         let game_state = GameState {
             player_hands: shuffle_outcome.player_hands,
+            card_origin,
             draw_pile: shuffle_outcome.draw_pile,
             discard_pile: Vec::new(),
             is_shuffled: true,
-            seed_metadata: BlockchainSeed {
-                value: random_value,
-                request_id,
-            },
+            seed_metadata,
             proof_cid,
-            verification_tx,
+            proof_pinned_by,
+            verification_tx: verification.tx_hash.clone(),
+            verification: Some(verification),
+            current_color: None,
+            cards_per_player,
+            history: Vec::new(),
+            name: game.options.name.clone(),
+            created_at: game.requested_at,
+            theme: game.options.theme.clone(),
+            reshuffle_count: 0,
+            direction: Direction::default(),
+            recent_draws: VecDeque::new(),
+            public_values: proof_result.pub_inputs.clone(),
+            enforce_turns: game.options.enforce_turns,
+            max_draws_per_turn: game.options.max_draws_per_turn,
+            require_draw_before_pass: game.options.require_draw_before_pass,
+            discard_visibility: game.options.discard_visibility,
+            current_player: starting_player,
+            starting_player,
+            rematch_of: game.options.rematch_of.clone(),
+            player_tokens: game.options.player_tokens.clone(),
+            salted_hands,
+            merkle_built: build_merkle,
+            auto_end_when_stuck: self.config.auto_end_stuck_games,
+            tournament: game.tournament,
+            ..GameState::new()
         };
 
         // Store completed game
@@ -385,46 +1591,1731 @@ impl GameOrchestrator {
         Ok(())
     }
 
-    async fn upload_proof(&self, output: ActionOutput) -> Result<String> {
-        // Initialize IPFS service
-        let provider = IpfsProvider::from_env()?;
-        let config = IpfsUploadConfig::default();
-        let ipfs_service = IpfsService::new(provider, config);
+    /// Deal a game from a local seed without generating or verifying a proof
+    async fn deal_unverified(&self, game: &PendingGame, seed: U256) -> Result<()> {
+        let seed_metadata = BlockchainSeed {
+            value: seed,
+            request_id: U256::ZERO,
+            instance_salt: self.config.instance_salt,
+            master: None,
+        };
+        let shuffle_outcome = perform_shuffle(
+            game.num_players,
+            game.cards_per_player,
+            seed_metadata.shuffle_seed(),
+        )?;
+
+        let starting_player = game
+            .options
+            .starting_player
+            .unwrap_or_else(|| seed_starting_player(seed, game.num_players));
+
+        let card_origin = CardOrigin::dealt(&shuffle_outcome.player_hands);
+        let game_state = GameState {
+            player_hands: shuffle_outcome.player_hands,
+            card_origin,
+            draw_pile: shuffle_outcome.draw_pile,
+            is_shuffled: true,
+            seed_metadata,
+            cards_per_player: game.cards_per_player,
+            name: game.options.name.clone(),
+            created_at: game.requested_at,
+            theme: game.options.theme.clone(),
+            enforce_turns: game.options.enforce_turns,
+            max_draws_per_turn: game.options.max_draws_per_turn,
+            require_draw_before_pass: game.options.require_draw_before_pass,
+            discard_visibility: game.options.discard_visibility,
+            current_player: starting_player,
+            starting_player,
+            rematch_of: game.options.rematch_of.clone(),
+            player_tokens: game.options.player_tokens.clone(),
+            salted_hands: game.options.salted_hands,
+            merkle_built: false,
+            auto_end_when_stuck: self.config.auto_end_stuck_games,
+            verifiable: false,
+            ..GameState::new()
+        };
+
+        self.completed_games
+            .write()
+            .await
+            .insert(game.session_id.clone(), game_state);
+
+        let mut games = self.pending_games.write().await;
+        if let Some(game) = games.get_mut(&game.session_id) {
+            game.status = GameStatus::Ready;
+        }
+
+        Ok(())
+    }
+
+    /// Flag VRF values that look too structured to shuffle with
+    ///
+    /// A zero value always fails; other weak values fail only with `reject_weak_seeds`.
+    fn check_seed_entropy(&self, session_id: &str, random_value: U256) -> Result<()> {
+        let Some(reason) = seed_weakness(random_value, self.config.min_seed_bits) else {
+            return Ok(());
+        };
+
+        tracing::warn!(
+            session_id = session_id,
+            random_value = %random_value,
+            reason = %reason,
+            "Suspicious low-entropy VRF seed"
+        );
+
+        if random_value.is_zero() || self.config.reject_weak_seeds {
+            return Err(anyhow!("Weak VRF seed rejected: {}", reason));
+        }
+
+        Ok(())
+    }
+
+    /// Record the seed for this session, flagging values already dealt to another game
+    async fn check_seed_reuse(&self, session_id: &str, random_value: U256) -> Result<()> {
+        let mut used_seeds = self.used_seeds.write().await;
+
+        match used_seeds.get(&random_value) {
+            Some(previous) if previous != session_id => {
+                tracing::warn!(
+                    session_id = session_id,
+                    previous_session_id = %previous,
+                    random_value = %random_value,
+                    "VRF seed already used by another game"
+                );
 
-        // Upload to IPFS
-        match ipfs_service.upload_with_retry(&output).await {
-            Ok(cid) => {
-                return Ok(cid);
+                if self.config.reject_seed_reuse {
+                    return Err(anyhow!(
+                        "Seed reuse detected: value already used by game {}",
+                        previous
+                    ));
+                }
             }
-            Err(err) => {
-                tracing::error!("Failed to upload proof to IPFS: {}", err);
-                return Err(anyhow!("Proof upload failed: {}", err));
+            Some(_) => {}
+            None => {
+                used_seeds.insert(random_value, session_id.to_string());
             }
         }
+
+        Ok(())
     }
 
-    /// Cleanup expired pending games (older than 10 minutes)
-    async fn cleanup_expired_games(&self) {
-        loop {
-            tokio::time::sleep(tokio::time::Duration::from_secs(300)).await;
+    /// Verify, pin and attach a proof generated outside this server, e.g. by a prover fleet
+    ///
+    /// The caller checks the proof against the game's deal first; this runs the same zkVerify
+    /// check and IPFS upload as finalization, with failures tagged `ProofVerification` or
+    /// `ProofUpload`. Games that can't take a proof are turned away before either. Returns
+    /// the updated game.
+    pub async fn attach_external_proof(
+        &self,
+        session_id: &str,
+        proof: ProofOutput,
+    ) -> Result<GameState> {
+        {
+            let games = self.completed_games.read().await;
+            let game_state = games
+                .get(session_id)
+                .ok_or_else(|| anyhow!("Game not ready or not found: {}", session_id))?;
+            if !game_state.verifiable {
+                return Err(anyhow!("Game {} has no proof to attach", session_id));
+            }
+            if game_state.proof_cid.is_some() {
+                return Err(anyhow!("Game {} already has a stored proof", session_id));
+            }
+        }
 
-            let mut games = self.pending_games.write().await;
-            let now = current_timestamp();
+        let verification = self
+            .sink
+            .verify(proof.clone())
+            .await
+            .map_err(|e| GameFailure::new(FailureReason::ProofVerification, e))?;
+
+        let output = ActionOutput {
+            id: session_id.to_string(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            data: serde_json::to_string_pretty(&proof)
+                .map_err(|e| anyhow!("Proof output serialization failed: {}", e))?,
+            ipfs_cid: None,
+        };
+        let receipt = self
+            .upload_proof(output)
+            .await
+            .map_err(|e| GameFailure::new(FailureReason::ProofUpload, e))?;
+
+        let mut games = self.completed_games.write().await;
+        let game_state = games
+            .get_mut(session_id)
+            .ok_or_else(|| anyhow!("Game not ready or not found: {}", session_id))?;
+        // Another submission may have landed while this one was verifying
+        if game_state.proof_cid.is_some() {
+            return Err(anyhow!("Game {} already has a stored proof", session_id));
+        }
+
+        game_state.proof_cid = Some(receipt.cid.clone());
+        game_state.proof_pinned_by = receipt.pinned_by;
+        game_state.public_values = proof.pub_inputs;
+        game_state.verification_tx = verification.tx_hash.clone();
+        game_state.verification = Some(verification);
+
+        tracing::info!(
+            session_id = session_id,
+            proof_cid = %receipt.cid,
+            "Submitted proof stored"
+        );
+
+        Ok(game_state.clone())
+    }
+
+    async fn upload_proof(&self, output: ActionOutput) -> Result<PinReceipt> {
+        self.sink.upload(output).await
+    }
+
+    /// Read an uploaded proof back from storage by its CID
+    pub async fn fetch_proof(&self, cid: &str) -> Result<ProofOutput> {
+        self.sink.fetch(cid).await
+    }
+
+    /// Re-upload every dead-lettered proof, attaching the CID to its game if still loaded
+    ///
+    /// Letters that upload are removed; the rest stay for the next startup or a manual upload.
+    async fn sweep_dead_letters(self: Arc<Self>) {
+        let Some(dir) = self.config.ipfs_dead_letter_dir.clone() else {
+            return;
+        };
+
+        let letters = match dead_letter::read_all(&dir).await {
+            Ok(letters) => letters,
+            Err(e) => {
+                tracing::warn!(dir = %dir.display(), error = %e, "Failed to read dead letters");
+                return;
+            }
+        };
+        if letters.is_empty() {
+            return;
+        }
 
-            games.retain(|session_id, game| {
-                let age = now - game.requested_at;
-                let keep = age < 600; // 10 minutes
+        tracing::info!(
+            count = letters.len(),
+            "Retrying dead-lettered proof uploads"
+        );
 
-                if !keep {
+        for (path, letter) in letters {
+            let session_id = letter.output.id.clone();
+
+            match self.upload_proof(letter.output).await {
+                Ok(receipt) => {
+                    if let Some(game_state) =
+                        self.completed_games.write().await.get_mut(&session_id)
+                    {
+                        game_state.proof_cid = Some(receipt.cid.clone());
+                        game_state.proof_pinned_by = receipt.pinned_by.clone();
+                    }
+                    if let Err(e) = tokio::fs::remove_file(&path).await {
+                        tracing::warn!(
+                            path = %path.display(),
+                            error = %e,
+                            "Uploaded dead letter could not be removed"
+                        );
+                    }
                     tracing::info!(
-                        session_id = session_id,
-                        age_seconds = age,
-                        "Cleaning up expired game"
+                        session_id = %session_id,
+                        proof_cid = %receipt.cid,
+                        "Dead-lettered proof stored"
                     );
                 }
+                Err(e) => tracing::warn!(
+                    session_id = %session_id,
+                    error = %e,
+                    "Dead-lettered proof still fails to upload, keeping it"
+                ),
+            }
+        }
+    }
+
+    /// Mark dealt games with no move within `timeout` as abandoned
+    async fn abandon_idle_games(self: Arc<Self>, timeout: Duration) {
+        let interval = timeout.clamp(Duration::from_secs(1), Duration::from_secs(60));
+
+        loop {
+            tokio::time::sleep(interval).await;
+
+            let now = current_timestamp();
+            let mut games = self.completed_games.write().await;
+
+            for (session_id, game_state) in games.iter_mut() {
+                if game_state.is_finished() {
+                    continue;
+                }
 
-                keep
-            });
+                let idle_seconds = game_state.idle_seconds(now);
+                if idle_seconds < timeout.as_secs() {
+                    continue;
+                }
+
+                game_state.abandon(idle_seconds, self.config.idle_award_win);
+                tracing::info!(
+                    session_id = %session_id,
+                    idle_seconds = idle_seconds,
+                    outcome = ?game_state.outcome,
+                    "Game abandoned after idle timeout"
+                );
+            }
         }
     }
+
+    /// Cleanup expired pending games (older than 10 minutes)
+    async fn cleanup_expired_games(&self) {
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_secs(300)).await;
+            self.remove_expired_games(current_timestamp()).await;
+        }
+    }
+
+    /// Drop pending games expired at `now`, with the VRF ids of those that were never dealt
+    async fn remove_expired_games(&self, now: u64) -> usize {
+        let mut removed = Vec::new();
+        self.pending_games.write().await.retain(|session_id, game| {
+            let keep = !game.is_expired(now, self.config.commit_reveal_delay);
+
+            if !keep {
+                tracing::info!(
+                    session_id = session_id,
+                    age_seconds = now.saturating_sub(game.requested_at),
+                    "Cleaning up expired game"
+                );
+                removed.push(session_id.clone());
+            }
+
+            keep
+        });
+
+        // Dealt games are still looked up through their completed state
+        let completed = self.completed_games.read().await;
+        let dropped: HashSet<&String> = removed
+            .iter()
+            .filter(|session_id| !completed.contains_key(*session_id))
+            .collect();
+        if !dropped.is_empty() {
+            self.vrf_index
+                .write()
+                .await
+                .retain(|_, session_id| !dropped.contains(session_id));
+        }
+
+        removed.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::mock::{fulfilled_log, uint_result};
+    use crate::game::{draw_card, GameOutcome};
+    use crate::orchestrator::mock::{Harness, MockProver, MockSink, MOCK_IMAGE_ID};
+    use crate::orchestrator::PENDING_GAME_TTL_SECS;
+    use alloy::primitives::keccak256;
+    use serde_json::json;
+
+    fn strong_seed(label: &str) -> U256 {
+        U256::from_be_bytes(keccak256(label.as_bytes()).0)
+    }
+
+    /// Tournament games go straight to finalization, so no VRF traffic is involved
+    fn tournament_config() -> OrchestratorConfig {
+        OrchestratorConfig {
+            tournament_seed: Some(strong_seed("tournament")),
+            finalize_max_retries: 3,
+            finalize_retry_backoff: Duration::from_secs(1),
+            ..OrchestratorConfig::default()
+        }
+    }
+
+    /// Game whose VRF request `request_id` was sent at block 16 and not yet fulfilled
+    fn waiting_game(session_id: &str, request_id: u64) -> PendingGame {
+        PendingGame {
+            session_id: session_id.to_string(),
+            vrf_request_id: U256::from(request_id),
+            vrf_block_number: 16,
+            num_players: 2,
+            cards_per_player: 7,
+            requested_at: current_timestamp(),
+            status: GameStatus::WaitingForVRF,
+            options: GameOptions::default(),
+            vrf_request_history: Vec::new(),
+            tournament: false,
+            proof_seed: None,
+            committed_at: current_timestamp(),
+            master_seed: None,
+        }
+    }
+
+    /// Register `game` as pending, the way a VRF request would
+    async fn insert_pending(harness: &Harness, game: PendingGame) {
+        harness
+            .orchestrator
+            .pending_games
+            .write()
+            .await
+            .insert(game.session_id.clone(), game);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_zero_seed_fails_the_game_without_proving() {
+        let harness = Harness::new(OrchestratorConfig {
+            finalize_max_retries: 0,
+            ..OrchestratorConfig::default()
+        });
+        let game = waiting_game("zero", 1);
+        insert_pending(&harness, game.clone()).await;
+
+        assert!(harness
+            .orchestrator
+            .finalize_with_retry(&game, U256::ZERO)
+            .await
+            .is_err());
+
+        let status = harness.orchestrator.get_game_status("zero").await.unwrap();
+        match status.status {
+            GameStatus::Failed { reason, detail } => {
+                assert_eq!(reason, FailureReason::InvalidParams);
+                assert!(detail.contains("value is zero"), "{}", detail);
+            }
+            other => panic!("expected the zero seed to fail, got {:?}", other),
+        }
+        assert_eq!(harness.prover.calls(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn weak_seeds_are_only_flagged_unless_configured() {
+        let lenient = Harness::new(OrchestratorConfig::default());
+        let strict = Harness::new(OrchestratorConfig {
+            reject_weak_seeds: true,
+            ..OrchestratorConfig::default()
+        });
+        let small = U256::from(12345u64);
+
+        assert!(lenient
+            .orchestrator
+            .check_seed_entropy("small", small)
+            .is_ok());
+        assert!(strict
+            .orchestrator
+            .check_seed_entropy("small", small)
+            .is_err());
+        for harness in [&lenient, &strict] {
+            assert!(harness
+                .orchestrator
+                .check_seed_entropy("strong", strong_seed("entropy"))
+                .is_ok());
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_seed_dealt_twice_is_rejected_when_configured() {
+        let harness = Harness::new(OrchestratorConfig {
+            reject_seed_reuse: true,
+            finalize_max_retries: 0,
+            ..OrchestratorConfig::default()
+        });
+        let seed = strong_seed("constant");
+        let (first, second) = (waiting_game("first", 1), waiting_game("second", 2));
+        insert_pending(&harness, first.clone()).await;
+        insert_pending(&harness, second.clone()).await;
+
+        harness
+            .orchestrator
+            .finalize_with_retry(&first, seed)
+            .await
+            .unwrap();
+        assert!(harness
+            .orchestrator
+            .finalize_with_retry(&second, seed)
+            .await
+            .is_err());
+
+        let status = harness
+            .orchestrator
+            .get_game_status("second")
+            .await
+            .unwrap();
+        match status.status {
+            GameStatus::Failed { reason, detail } => {
+                assert_eq!(reason, FailureReason::InvalidParams);
+                assert!(detail.contains("already used by game first"), "{}", detail);
+            }
+            other => panic!("expected the reused seed to fail, got {:?}", other),
+        }
+        assert_eq!(harness.prover.calls(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_seed_dealt_twice_is_only_flagged_by_default() {
+        let harness = Harness::new(OrchestratorConfig {
+            finalize_max_retries: 0,
+            ..OrchestratorConfig::default()
+        });
+        let seed = strong_seed("constant");
+        let (first, second) = (waiting_game("first", 1), waiting_game("second", 2));
+        insert_pending(&harness, first.clone()).await;
+        insert_pending(&harness, second.clone()).await;
+
+        for game in [&first, &second] {
+            harness
+                .orchestrator
+                .finalize_with_retry(game, seed)
+                .await
+                .unwrap();
+        }
+
+        let used_seeds = harness.orchestrator.used_seeds.read().await;
+        assert_eq!(used_seeds.get(&seed).map(String::as_str), Some("first"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn re_requesting_vrf_moves_to_the_new_request_and_keeps_history() {
+        let harness = Harness::new(OrchestratorConfig::default());
+        insert_pending(&harness, waiting_game("game", 3)).await;
+
+        harness.rpc.set("eth_call", uint_result(9));
+        harness.rpc.set("eth_blockNumber", json!("0x20"));
+        let game = harness.orchestrator.rerequest_vrf("game").await.unwrap();
+        assert_eq!(game.vrf_request_id, U256::from(9));
+        assert_eq!(game.vrf_block_number, 0x20);
+        assert_eq!(game.vrf_request_history, [U256::from(3)]);
+        assert_eq!(game.status, GameStatus::WaitingForVRF);
+
+        harness.rpc.set("eth_call", uint_result(10));
+        let game = harness.orchestrator.rerequest_vrf("game").await.unwrap();
+        assert_eq!(game.vrf_request_id, U256::from(10));
+        assert_eq!(game.vrf_request_history, [U256::from(3), U256::from(9)]);
+
+        let vrf_index = harness.orchestrator.vrf_index.read().await;
+        for request_id in [9, 10] {
+            assert_eq!(
+                vrf_index.get(&U256::from(request_id)).map(String::as_str),
+                Some("game")
+            );
+        }
+    }
+
+    /// `waiting_game`, indexed under its request id as if `initiate_game` had sent it
+    async fn insert_requested(harness: &Harness, game: PendingGame) {
+        harness
+            .orchestrator
+            .vrf_index
+            .write()
+            .await
+            .insert(game.vrf_request_id, game.session_id.clone());
+        insert_pending(harness, game).await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_finalized_game_is_found_by_its_vrf_request() {
+        let harness = Harness::new(OrchestratorConfig::default());
+        let game = waiting_game("dealt", 5);
+        insert_requested(&harness, game.clone()).await;
+
+        harness
+            .orchestrator
+            .finalize_with_retry(&game, strong_seed("lookup"))
+            .await
+            .unwrap();
+        let lookup = harness
+            .orchestrator
+            .lookup_vrf_request(U256::from(5))
+            .await
+            .unwrap();
+
+        assert_eq!(lookup.session_id, "dealt");
+        assert_eq!(lookup.status, GameStatus::Ready);
+        assert!(!lookup.superseded);
+        assert!(harness
+            .orchestrator
+            .lookup_vrf_request(U256::from(6))
+            .await
+            .is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cleanup_waits_out_the_commit_window_before_the_ttl() {
+        let delay = Duration::from_secs(300);
+        let harness = Harness::new(OrchestratorConfig {
+            commit_reveal_delay: Some(delay),
+            ..OrchestratorConfig::default()
+        });
+        let committed = PendingGame {
+            status: GameStatus::Committed,
+            ..waiting_game("committed", 7)
+        };
+        let committed_at = committed.committed_at;
+        insert_requested(&harness, committed).await;
+
+        let window_closes = committed_at + delay.as_secs();
+        assert_eq!(
+            harness
+                .orchestrator
+                .remove_expired_games(committed_at + PENDING_GAME_TTL_SECS)
+                .await,
+            0
+        );
+        assert_eq!(
+            harness
+                .orchestrator
+                .remove_expired_games(window_closes + PENDING_GAME_TTL_SECS)
+                .await,
+            1
+        );
+        assert!(harness
+            .orchestrator
+            .lookup_vrf_request(U256::from(7))
+            .await
+            .is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cleanup_keeps_dealt_games_findable_and_prunes_the_rest() {
+        let harness = Harness::new(OrchestratorConfig::default());
+        let dealt = waiting_game("dealt", 5);
+        insert_requested(&harness, dealt.clone()).await;
+        insert_requested(&harness, waiting_game("abandoned", 6)).await;
+        harness
+            .orchestrator
+            .finalize_with_retry(&dealt, strong_seed("cleanup"))
+            .await
+            .unwrap();
+
+        let later = current_timestamp() + PENDING_GAME_TTL_SECS;
+        assert_eq!(harness.orchestrator.remove_expired_games(later).await, 2);
+
+        let lookup = harness
+            .orchestrator
+            .lookup_vrf_request(U256::from(5))
+            .await
+            .unwrap();
+        assert_eq!(lookup.session_id, "dealt");
+        assert_eq!(lookup.status, GameStatus::Ready);
+        assert!(harness
+            .orchestrator
+            .lookup_vrf_request(U256::from(6))
+            .await
+            .is_none());
+        let vrf_index = harness.orchestrator.vrf_index.read().await;
+        assert_eq!(vrf_index.len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn re_requesting_vrf_is_rejected_once_proving_starts() {
+        let harness = Harness::new(OrchestratorConfig::default());
+        let mut game = waiting_game("game", 3);
+        game.status = GameStatus::GeneratingProof;
+        insert_pending(&harness, game).await;
+        harness.rpc.set("eth_call", uint_result(9));
+
+        assert!(harness.orchestrator.rerequest_vrf("game").await.is_err());
+        assert!(harness.orchestrator.rerequest_vrf("missing").await.is_err());
+
+        let game = harness.orchestrator.get_pending_game("game").await.unwrap();
+        assert_eq!(game.vrf_request_id, U256::from(3));
+        assert!(game.vrf_request_history.is_empty());
+        assert_eq!(harness.rpc.calls("eth_call"), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_slow_fulfillment_check_is_not_spawned_again() {
+        let harness = Harness::new(OrchestratorConfig::default());
+        let orchestrator = &harness.orchestrator;
+        // Unfulfilled: the check polls the contract for 15s before giving up
+        harness.rpc.set("eth_getLogs", json!([]));
+        harness.rpc.set("eth_call", uint_result(0));
+        insert_pending(&harness, waiting_game("slow", 3)).await;
+
+        assert_eq!(orchestrator.spawn_fulfillment_checks().await, 1);
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(orchestrator.spawn_fulfillment_checks().await, 0);
+        assert_eq!(harness.rpc.calls("eth_getLogs"), 1);
+
+        tokio::time::sleep(Duration::from_secs(15)).await;
+        assert!(orchestrator.in_flight_checks.read().await.is_empty());
+        assert_eq!(orchestrator.spawn_fulfillment_checks().await, 1);
+    }
+
+    fn snapshot_config(name: &str) -> OrchestratorConfig {
+        let path = std::env::temp_dir().join(format!("zunno-{}-{}.json", name, std::process::id()));
+        OrchestratorConfig {
+            shutdown_snapshot_path: Some(path),
+            ..tournament_config()
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shutdown_persists_games_for_the_next_start() {
+        let config = snapshot_config("shutdown");
+        let path = config.shutdown_snapshot_path.clone().unwrap();
+        let harness = Harness::new(config.clone());
+        let initiation = harness
+            .orchestrator
+            .initiate_game(2, 7, GameOptions::default())
+            .await
+            .unwrap();
+        harness
+            .wait_for(&initiation.session_id, |status| !status.is_in_progress())
+            .await;
+        insert_pending(&harness, waiting_game("waiting", 3)).await;
+
+        harness.orchestrator.flush_all().await;
+        let restarted = Harness::new(config);
+        let restored = restarted.orchestrator.restore_snapshot().await.unwrap();
+        std::fs::remove_file(&path).ok();
+
+        // Both pending entries, plus the dealt game's state
+        assert_eq!(restored, 3);
+        // Flushing folded the seed journal into the snapshot
+        assert!(!seed_journal_path(&path).exists());
+        let before = harness
+            .orchestrator
+            .get_game_state(&initiation.session_id)
+            .await
+            .unwrap();
+        let after = restarted
+            .orchestrator
+            .get_game_state(&initiation.session_id)
+            .await
+            .unwrap();
+        assert_eq!(after.player_hands, before.player_hands);
+        assert_eq!(after.draw_pile, before.draw_pile);
+        assert_eq!(
+            restarted
+                .orchestrator
+                .get_game_status("waiting")
+                .await
+                .unwrap()
+                .status,
+            GameStatus::WaitingForVRF
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_proof_seed_is_journaled_for_its_game_alone() {
+        let config = snapshot_config("journal");
+        let path = config.shutdown_snapshot_path.clone().unwrap();
+        let harness = Harness::new(config.clone());
+        insert_pending(&harness, waiting_game("proving", 1)).await;
+        insert_pending(&harness, waiting_game("idle", 2)).await;
+        harness.orchestrator.flush_all().await;
+
+        let seed = strong_seed("journaled");
+        harness
+            .orchestrator
+            .persist_proof_seed("proving", seed)
+            .await;
+        let journal = read_seed_journal(&seed_journal_path(&path)).await.unwrap();
+        let restarted = Harness::new(config);
+        restarted.orchestrator.restore_snapshot().await.unwrap();
+        std::fs::remove_file(&path).ok();
+        std::fs::remove_file(seed_journal_path(&path)).ok();
+
+        assert_eq!(journal.len(), 1);
+        assert_eq!(journal[0].session_id, "proving");
+        assert_eq!(journal[0].proof_seed, Some(seed));
+        // The snapshot predates the seed; the journal fills it in on restore
+        let restored = restarted.orchestrator.get_pending_game("proving").await;
+        assert_eq!(restored.unwrap().proof_seed, Some(seed));
+        let idle = restarted.orchestrator.get_pending_game("idle").await;
+        assert_eq!(idle.unwrap().proof_seed, None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_journal_without_a_snapshot_still_resumes_its_proofs() {
+        let config = snapshot_config("journal-only");
+        let path = config.shutdown_snapshot_path.clone().unwrap();
+        std::fs::remove_file(&path).ok();
+        std::fs::remove_file(seed_journal_path(&path)).ok();
+        let harness = Harness::new(config.clone());
+        let mut proving = waiting_game("proving", 1);
+        proving.status = GameStatus::GeneratingProof;
+        insert_pending(&harness, proving).await;
+
+        // Crash before any clean shutdown: the journal is all there is
+        let seed = strong_seed("crashed");
+        harness
+            .orchestrator
+            .persist_proof_seed("proving", seed)
+            .await;
+        assert!(!path.exists());
+
+        let restarted = Harness::new(config);
+        let restored = restarted.orchestrator.restore_snapshot().await.unwrap();
+        restarted
+            .orchestrator
+            .clone()
+            .resume_interrupted_proofs()
+            .await;
+        let status = restarted
+            .wait_for("proving", |status| !status.is_in_progress())
+            .await;
+        std::fs::remove_file(seed_journal_path(&path)).ok();
+
+        assert_eq!(restored, 1);
+        assert_eq!(status, GameStatus::Ready);
+        let game = restarted
+            .orchestrator
+            .get_game_state("proving")
+            .await
+            .unwrap();
+        assert_eq!(game.seed_metadata.value, seed);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn nothing_is_restored_without_a_snapshot_or_journal() {
+        let config = snapshot_config("absent");
+        let path = config.shutdown_snapshot_path.clone().unwrap();
+        std::fs::remove_file(&path).ok();
+        std::fs::remove_file(seed_journal_path(&path)).ok();
+        let harness = Harness::new(config);
+
+        assert_eq!(harness.orchestrator.restore_snapshot().await.unwrap(), 0);
+        assert!(harness
+            .orchestrator
+            .get_pending_game("proving")
+            .await
+            .is_none());
+    }
+
+    #[test]
+    fn snapshot_temp_files_are_unique_and_beside_the_snapshot() {
+        let path = std::env::temp_dir().join("zunno-games.json");
+        let (first, second) = (snapshot_tmp_path(&path), snapshot_tmp_path(&path));
+
+        assert_ne!(first, second);
+        for tmp in [&first, &second] {
+            assert_eq!(tmp.parent(), path.parent());
+            let name = tmp.file_name().unwrap().to_str().unwrap();
+            assert!(name.starts_with("zunno-games.json."), "{}", name);
+            assert!(name.contains(&std::process::id().to_string()), "{}", name);
+            assert!(name.ends_with(".tmp"), "{}", name);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_reloaded_proving_game_resumes_from_its_seed() {
+        let path = std::env::temp_dir().join(format!("zunno-resume-{}.json", std::process::id()));
+        let seed = strong_seed("persisted");
+        let mut proving = waiting_game("proving", 7);
+        proving.status = GameStatus::GeneratingProof;
+        proving.proof_seed = Some(seed);
+        let snapshot = GameSnapshot {
+            taken_at: current_timestamp(),
+            pending: HashMap::from([("proving".to_string(), proving)]),
+            completed: HashMap::new(),
+        };
+        std::fs::write(&path, serde_json::to_vec(&snapshot).unwrap()).unwrap();
+
+        let harness = Harness::new(OrchestratorConfig {
+            shutdown_snapshot_path: Some(path.clone()),
+            ..OrchestratorConfig::default()
+        });
+        assert_eq!(harness.orchestrator.restore_snapshot().await.unwrap(), 1);
+        harness
+            .orchestrator
+            .clone()
+            .resume_interrupted_proofs()
+            .await;
+        let status = harness
+            .wait_for("proving", |status| !status.is_in_progress())
+            .await;
+        std::fs::remove_file(&path).ok();
+        std::fs::remove_file(seed_journal_path(&path)).ok();
+
+        assert_eq!(status, GameStatus::Ready);
+        assert_eq!(harness.prover.calls(), 1);
+        // No new VRF request: neither a transaction nor a fulfillment lookup
+        assert_eq!(harness.rpc.calls("eth_sendRawTransaction"), 0);
+        assert_eq!(harness.rpc.calls("eth_getLogs"), 0);
+        let game = harness
+            .orchestrator
+            .get_game_state("proving")
+            .await
+            .unwrap();
+        assert_eq!(game.seed_metadata.value, seed);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn startup_finalizes_games_fulfilled_while_offline() {
+        let path = std::env::temp_dir().join(format!("zunno-catch-up-{}.json", std::process::id()));
+        let snapshot = GameSnapshot {
+            taken_at: current_timestamp(),
+            pending: HashMap::from([("offline".to_string(), waiting_game("offline", 7))]),
+            completed: HashMap::new(),
+        };
+        std::fs::write(&path, serde_json::to_vec(&snapshot).unwrap()).unwrap();
+
+        let random_word = strong_seed("missed");
+        let harness = Harness::new(OrchestratorConfig {
+            shutdown_snapshot_path: Some(path.clone()),
+            ..OrchestratorConfig::default()
+        });
+        harness.rpc.set(
+            "eth_getLogs",
+            json!([fulfilled_log(U256::from(7), random_word)]),
+        );
+
+        assert_eq!(harness.orchestrator.restore_snapshot().await.unwrap(), 1);
+        harness.orchestrator.catch_up_missed_vrf_events().await;
+        let status = harness
+            .wait_for("offline", |status| !status.is_in_progress())
+            .await;
+        std::fs::remove_file(&path).ok();
+        std::fs::remove_file(seed_journal_path(&path)).ok();
+
+        assert_eq!(status, GameStatus::Ready);
+        assert_eq!(harness.rpc.calls("eth_getLogs"), 1);
+        let game = harness
+            .orchestrator
+            .get_game_state("offline")
+            .await
+            .unwrap();
+        assert_eq!(game.seed_metadata.value, random_word);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn finalization_retries_until_the_prover_succeeds() {
+        let harness = Harness::with_prover(tournament_config(), MockProver::failing(2));
+
+        let initiation = harness
+            .orchestrator
+            .initiate_game(2, 7, GameOptions::default())
+            .await
+            .unwrap();
+        let status = harness
+            .wait_for(&initiation.session_id, |status| !status.is_in_progress())
+            .await;
+
+        assert_eq!(status, GameStatus::Ready);
+        assert_eq!(harness.prover.calls(), 3);
+        assert_eq!(harness.rpc.calls("eth_call"), 0);
+        assert!(harness
+            .orchestrator
+            .get_game_state(&initiation.session_id)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_finalized_game_reproduces_its_committed_public_values() {
+        let harness = Harness::new(tournament_config());
+        let initiation = harness
+            .orchestrator
+            .initiate_game(3, 5, GameOptions::default())
+            .await
+            .unwrap();
+        harness
+            .wait_for(&initiation.session_id, |status| !status.is_in_progress())
+            .await;
+
+        let game_state = harness
+            .orchestrator
+            .get_game_state(&initiation.session_id)
+            .await
+            .unwrap();
+        let recomputed = game_state
+            .to_public_values(&game_state.seed_metadata)
+            .unwrap();
+        let committed = harness.prover.committed();
+        assert_eq!(committed.len(), 1);
+        let committed =
+            PublicValuesStruct::abi_decode(&hex::decode(&committed[0]).unwrap()).unwrap();
+
+        assert_eq!(recomputed.no_of_players, committed.no_of_players);
+        assert_eq!(recomputed.cards_per_player, committed.cards_per_player);
+        assert_eq!(recomputed.initial_hands_hash, committed.initial_hands_hash);
+        assert_eq!(recomputed.draw_pile_hash, committed.draw_pile_hash);
+        assert_eq!(recomputed.merkle_root, committed.merkle_root);
+        assert_eq!(recomputed.seed, committed.seed);
+        assert_eq!(recomputed.instance_salt, committed.instance_salt);
+        assert_eq!(recomputed.salted_hands, committed.salted_hands);
+        assert_eq!(recomputed.merkle_built, committed.merkle_built);
+        assert_eq!(recomputed.master_seed, committed.master_seed);
+        assert_eq!(recomputed.seed_context, committed.seed_context);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn every_tournament_table_gets_the_same_deal() {
+        let harness = Harness::new(tournament_config());
+        let mut tables = Vec::new();
+        for _ in 0..2 {
+            let initiation = harness
+                .orchestrator
+                .initiate_game(4, 7, GameOptions::default())
+                .await
+                .unwrap();
+            harness
+                .wait_for(&initiation.session_id, |status| !status.is_in_progress())
+                .await;
+            tables.push(
+                harness
+                    .orchestrator
+                    .get_game_state(&initiation.session_id)
+                    .await
+                    .unwrap(),
+            );
+        }
+
+        let (first, second) = (&tables[0], &tables[1]);
+        assert_eq!(first.player_hands, second.player_hands);
+        assert_eq!(first.draw_pile, second.draw_pile);
+        assert_eq!(first.discard_pile, second.discard_pile);
+        assert_eq!(first.seed_metadata.value, strong_seed("tournament"));
+        assert_eq!(second.seed_metadata.value, strong_seed("tournament"));
+        // Flagged, and no VRF request was made for either table
+        assert!(first.tournament && second.tournament);
+        assert_eq!(harness.rpc.calls("eth_call"), 0);
+        assert_eq!(harness.rpc.calls("eth_sendRawTransaction"), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tournament_deals_differ_between_seeds() {
+        let deal = |label: &'static str| async move {
+            let harness = Harness::new(OrchestratorConfig {
+                tournament_seed: Some(strong_seed(label)),
+                ..tournament_config()
+            });
+            let initiation = harness
+                .orchestrator
+                .initiate_game(4, 7, GameOptions::default())
+                .await
+                .unwrap();
+            harness
+                .wait_for(&initiation.session_id, |status| !status.is_in_progress())
+                .await;
+            harness
+                .orchestrator
+                .get_game_state(&initiation.session_id)
+                .await
+                .unwrap()
+                .player_hands
+        };
+
+        assert_ne!(deal("round-1").await, deal("round-2").await);
+    }
+
+    /// Dealt tournament game, ready to play
+    async fn ready_game(harness: &Harness) -> String {
+        let initiation = harness
+            .orchestrator
+            .initiate_game(2, 7, GameOptions::default())
+            .await
+            .unwrap();
+        harness
+            .wait_for(&initiation.session_id, |status| !status.is_in_progress())
+            .await;
+        initiation.session_id
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn moves_in_a_fork_leave_the_original_alone() {
+        let harness = Harness::new(tournament_config());
+        let original = ready_game(&harness).await;
+        let (fork, forked) = harness.orchestrator.fork_game(&original).await.unwrap();
+        assert_eq!(forked.forked_from.as_deref(), Some(original.as_str()));
+
+        harness
+            .orchestrator
+            .update_game_state(&fork, |game_state| draw_card(game_state, 0))
+            .await
+            .unwrap()
+            .unwrap();
+
+        let fork_state = harness.orchestrator.get_game_state(&fork).await.unwrap();
+        let original_state = harness
+            .orchestrator
+            .get_game_state(&original)
+            .await
+            .unwrap();
+        assert_eq!(fork_state.player_hands[0].len(), 8);
+        assert_eq!(original_state.player_hands[0].len(), 7);
+        assert_eq!(
+            original_state.draw_pile.len(),
+            fork_state.draw_pile.len() + 1
+        );
+        assert!(original_state.history.is_empty());
+        // Same deal, so the same seed backs both
+        assert_eq!(
+            fork_state.seed_metadata.value,
+            original_state.seed_metadata.value
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn moves_in_the_original_leave_a_fork_alone() {
+        let harness = Harness::new(tournament_config());
+        let original = ready_game(&harness).await;
+        let (fork, _) = harness.orchestrator.fork_game(&original).await.unwrap();
+
+        for _ in 0..3 {
+            harness
+                .orchestrator
+                .update_game_state(&original, |game_state| draw_card(game_state, 1))
+                .await
+                .unwrap()
+                .unwrap();
+        }
+
+        let fork_state = harness.orchestrator.get_game_state(&fork).await.unwrap();
+        let original_state = harness
+            .orchestrator
+            .get_game_state(&original)
+            .await
+            .unwrap();
+        assert_eq!(original_state.player_hands[1].len(), 10);
+        assert_eq!(fork_state.player_hands[1].len(), 7);
+        assert!(fork_state.history.is_empty());
+        assert!(harness.orchestrator.fork_game("missing").await.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn finalization_fails_once_retries_run_out() {
+        let harness = Harness::with_prover(tournament_config(), MockProver::failing(10));
+
+        let initiation = harness
+            .orchestrator
+            .initiate_game(2, 7, GameOptions::default())
+            .await
+            .unwrap();
+        let status = harness
+            .wait_for(&initiation.session_id, |status| !status.is_in_progress())
+            .await;
+
+        assert!(matches!(
+            status,
+            GameStatus::Failed {
+                reason: FailureReason::ProofGeneration,
+                ..
+            }
+        ));
+        assert_eq!(harness.prover.calls(), 4);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn idle_games_are_abandoned_and_active_ones_are_not() {
+        let harness = Harness::new(OrchestratorConfig {
+            idle_award_win: true,
+            ..tournament_config()
+        });
+        let (idle, active) = (ready_game(&harness).await, ready_game(&harness).await);
+        {
+            // Both were dealt long ago; only `active` moves before the sweep
+            let mut games = harness.orchestrator.completed_games.write().await;
+            for session_id in [&idle, &active] {
+                let game_state = games.get_mut(session_id).unwrap();
+                game_state.created_at -= 600;
+                game_state.last_activity = game_state.created_at;
+            }
+        }
+        harness
+            .orchestrator
+            .update_game_state(&active, |game_state| draw_card(game_state, 0))
+            .await
+            .unwrap()
+            .unwrap();
+
+        let sweeper = tokio::spawn(
+            harness
+                .orchestrator
+                .clone()
+                .abandon_idle_games(Duration::from_secs(60)),
+        );
+        tokio::time::sleep(Duration::from_secs(61)).await;
+        sweeper.abort();
+
+        let idle_state = harness.orchestrator.get_game_state(&idle).await.unwrap();
+        match idle_state.outcome {
+            Some(GameOutcome::Abandoned { idle_seconds, .. }) => assert!(idle_seconds >= 600),
+            other => panic!("expected the idle game to be abandoned, got {:?}", other),
+        }
+        let idle_status = harness.orchestrator.get_game_status(&idle).await.unwrap();
+        assert_eq!(idle_status.status, GameStatus::Finished);
+        assert_eq!(idle_status.idle_seconds, None);
+
+        let active_state = harness.orchestrator.get_game_state(&active).await.unwrap();
+        assert_eq!(active_state.outcome, None);
+        let active_status = harness.orchestrator.get_game_status(&active).await.unwrap();
+        assert_eq!(active_status.status, GameStatus::Ready);
+        assert!(active_status.idle_seconds.unwrap() < 60);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_proof_failing_verification_never_becomes_ready() {
+        let config = OrchestratorConfig {
+            verify_before_ready: true,
+            finalize_max_retries: 0,
+            ..OrchestratorConfig::default()
+        };
+        let harness = Harness::with_prover(config, MockProver::tampering());
+        let game = waiting_game("tampered", 1);
+        insert_pending(&harness, game.clone()).await;
+
+        assert!(harness
+            .orchestrator
+            .finalize_with_retry(&game, strong_seed("tampered"))
+            .await
+            .is_err());
+
+        let status = harness
+            .orchestrator
+            .get_game_status("tampered")
+            .await
+            .unwrap();
+        match status.status {
+            GameStatus::Failed { reason, detail } => {
+                assert_eq!(reason, FailureReason::ProofGeneration);
+                assert!(detail.contains("don't match the host deal"), "{}", detail);
+            }
+            other => panic!("expected the tampered proof to fail, got {:?}", other),
+        }
+        assert!(harness
+            .orchestrator
+            .get_game_state("tampered")
+            .await
+            .is_err());
+        assert!(harness.sink.uploads().is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_proof_that_cannot_match_is_not_retried() {
+        let config = OrchestratorConfig {
+            verify_before_ready: true,
+            finalize_max_retries: 3,
+            ..OrchestratorConfig::default()
+        };
+        let harness = Harness::with_prover(config, MockProver::tampering());
+        let game = waiting_game("tampered", 1);
+        insert_pending(&harness, game.clone()).await;
+
+        let error = harness
+            .orchestrator
+            .finalize_with_retry(&game, strong_seed("tampered"))
+            .await
+            .unwrap_err();
+
+        assert!(GameFailure::is_permanent(&error), "{}", error);
+        assert_eq!(harness.prover.calls(), 1);
+        assert_eq!(harness.sink.verifications(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn an_upload_retry_reuses_the_verified_proof() {
+        let config = OrchestratorConfig {
+            finalize_max_retries: 3,
+            finalize_retry_backoff: Duration::from_secs(1),
+            ..OrchestratorConfig::default()
+        };
+        let harness = Harness::with_sink(config, MockSink::failing_uploads(2));
+        let game = waiting_game("flaky", 1);
+        insert_pending(&harness, game.clone()).await;
+
+        harness
+            .orchestrator
+            .finalize_with_retry(&game, strong_seed("flaky"))
+            .await
+            .unwrap();
+
+        let status = harness.orchestrator.get_game_status("flaky").await.unwrap();
+        assert_eq!(status.status, GameStatus::Ready);
+        assert_eq!(harness.prover.calls(), 1);
+        assert_eq!(harness.sink.verifications(), 1);
+        assert_eq!(harness.sink.uploads(), ["flaky"]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn proofs_are_only_checked_when_configured() {
+        for (verify_before_ready, prover) in [
+            (true, MockProver::default()),
+            (false, MockProver::tampering()),
+        ] {
+            let harness = Harness::with_prover(
+                OrchestratorConfig {
+                    verify_before_ready,
+                    ..OrchestratorConfig::default()
+                },
+                prover,
+            );
+            let game = waiting_game("game", 1);
+            insert_pending(&harness, game.clone()).await;
+
+            harness
+                .orchestrator
+                .finalize_with_retry(&game, strong_seed("checked"))
+                .await
+                .unwrap();
+
+            let status = harness.orchestrator.get_game_status("game").await.unwrap();
+            assert_eq!(status.status, GameStatus::Ready);
+            assert_eq!(harness.sink.uploads(), vec!["game".to_string()]);
+        }
+    }
+
+    fn dead_letter_config(name: &str) -> OrchestratorConfig {
+        let dir = std::env::temp_dir().join(format!(
+            "zunno-dead-letters-{}-{}",
+            std::process::id(),
+            name
+        ));
+        std::fs::remove_dir_all(&dir).ok();
+        OrchestratorConfig {
+            ipfs_dead_letter_dir: Some(dir),
+            finalize_max_retries: 0,
+            ..OrchestratorConfig::default()
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn an_exhausted_upload_is_dead_lettered_and_swept_later() {
+        let config = dead_letter_config("sweep");
+        let dir = config.ipfs_dead_letter_dir.clone().unwrap();
+        let harness = Harness::with_sink(config, MockSink::failing_uploads(1));
+        let game = waiting_game("unpinned", 1);
+        insert_pending(&harness, game.clone()).await;
+
+        harness
+            .orchestrator
+            .finalize_with_retry(&game, strong_seed("dead-letter"))
+            .await
+            .unwrap();
+
+        // Dealt without a CID, the proof waiting on disk
+        let dealt = harness
+            .orchestrator
+            .get_game_state("unpinned")
+            .await
+            .unwrap();
+        assert_eq!(dealt.proof_cid, None);
+        let letters = dead_letter::read_all(&dir).await.unwrap();
+        assert_eq!(letters.len(), 1);
+        assert_eq!(letters[0].1.output.id, "unpinned");
+        assert!(letters[0].1.error.contains("mock upload failure"));
+
+        harness.orchestrator.clone().sweep_dead_letters().await;
+
+        let swept = harness
+            .orchestrator
+            .get_game_state("unpinned")
+            .await
+            .unwrap();
+        let remaining = dead_letter::read_all(&dir).await.unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+        assert_eq!(swept.proof_cid.as_deref(), Some("bafy-unpinned"));
+        assert_eq!(swept.proof_pinned_by, ["mock"]);
+        assert!(remaining.is_empty());
+        assert_eq!(harness.sink.uploads(), ["unpinned"]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_sweep_keeps_letters_that_still_fail() {
+        let config = dead_letter_config("still-failing");
+        let dir = config.ipfs_dead_letter_dir.clone().unwrap();
+        let harness = Harness::with_sink(config, MockSink::failing_uploads(2));
+        let game = waiting_game("unpinned", 1);
+        insert_pending(&harness, game.clone()).await;
+        harness
+            .orchestrator
+            .finalize_with_retry(&game, strong_seed("still-failing"))
+            .await
+            .unwrap();
+
+        harness.orchestrator.clone().sweep_dead_letters().await;
+
+        let remaining = dead_letter::read_all(&dir).await.unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+        assert_eq!(remaining.len(), 1);
+        let game_state = harness
+            .orchestrator
+            .get_game_state("unpinned")
+            .await
+            .unwrap();
+        assert_eq!(game_state.proof_cid, None);
+        assert!(harness.sink.uploads().is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn prover_probes_are_reused_until_they_go_stale() {
+        let harness = Harness::new(OrchestratorConfig::default());
+
+        assert!(harness.orchestrator.prover_healthy().await);
+        assert_eq!(harness.prover.health_probes(), 1);
+
+        // Within the TTL the cached result stands, even though the prover went down
+        harness.prover.set_healthy(false);
+        tokio::time::advance(PROVER_HEALTH_TTL - Duration::from_secs(1)).await;
+        assert!(harness.orchestrator.prover_healthy().await);
+        assert_eq!(harness.prover.health_probes(), 1);
+
+        // Stale: probed again, retried, and the failure cached in turn
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert!(!harness.orchestrator.prover_healthy().await);
+        assert_eq!(harness.prover.health_probes(), 1 + PROVER_HEALTH_ATTEMPTS);
+        harness.prover.set_healthy(true);
+        assert!(!harness.orchestrator.prover_healthy().await);
+        assert_eq!(harness.prover.health_probes(), 1 + PROVER_HEALTH_ATTEMPTS);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_busy_prover_is_not_probed() {
+        let harness = Harness::new(OrchestratorConfig {
+            prover_threads: 1,
+            ..OrchestratorConfig::default()
+        });
+        let busy = harness
+            .orchestrator
+            .proof_slots
+            .clone()
+            .try_acquire_owned()
+            .unwrap();
+
+        // Nothing cached yet: a prover busy proving is taken to be up
+        harness.prover.set_healthy(false);
+        assert!(harness.orchestrator.prover_healthy().await);
+        assert_eq!(harness.prover.health_probes(), 0);
+
+        drop(busy);
+        assert!(!harness.orchestrator.prover_healthy().await);
+        assert_eq!(harness.prover.health_probes(), PROVER_HEALTH_ATTEMPTS);
+
+        // Busy again once the result is stale: the last result stands
+        let _busy = harness
+            .orchestrator
+            .proof_slots
+            .clone()
+            .try_acquire_owned()
+            .unwrap();
+        harness.prover.set_healthy(true);
+        tokio::time::advance(PROVER_HEALTH_TTL).await;
+        assert!(!harness.orchestrator.prover_healthy().await);
+        assert_eq!(harness.prover.health_probes(), PROVER_HEALTH_ATTEMPTS);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_probe_holds_a_prover_slot() {
+        let config = OrchestratorConfig {
+            prover_threads: 1,
+            ..OrchestratorConfig::default()
+        };
+        let harness = Harness::with_prover(config, MockProver::held());
+        let orchestrator = harness.orchestrator.clone();
+
+        let probe = tokio::spawn(async move { orchestrator.prover_healthy().await });
+        harness.prover_entered(1).await;
+        assert_eq!(harness.orchestrator.proof_slots.available_permits(), 0);
+
+        harness.prover.release();
+        assert!(probe.await.unwrap());
+        assert_eq!(harness.orchestrator.proof_slots.available_permits(), 1);
+        assert_eq!(harness.prover.health_probes(), 1);
+    }
+
+    /// Proof the mock prover would make for a public-values commitment
+    fn mock_proof(pub_inputs: String) -> ProofOutput {
+        ProofOutput {
+            proof: format!("0x{}", "ab".repeat(2048)),
+            image_id: MOCK_IMAGE_ID.to_string(),
+            pub_inputs,
+            conversion: Default::default(),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn an_external_proof_is_verified_pinned_and_attached() {
+        let harness = Harness::new(OrchestratorConfig::default());
+        let game = waiting_game("fleet", 1);
+        insert_pending(&harness, game.clone()).await;
+        harness
+            .orchestrator
+            .finalize_with_retry(&game, strong_seed("fleet"))
+            .await
+            .unwrap();
+        // As if the upload had been given up on
+        harness
+            .orchestrator
+            .update_game_state("fleet", |game_state| {
+                game_state.proof_cid = None;
+                Ok(())
+            })
+            .await
+            .unwrap()
+            .unwrap();
+
+        let proof = mock_proof(harness.prover.committed().pop().unwrap());
+        let attached = harness
+            .orchestrator
+            .attach_external_proof("fleet", proof.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(attached.proof_cid.as_deref(), Some("bafy-fleet"));
+        assert_eq!(attached.public_values, proof.pub_inputs);
+        assert_eq!(harness.sink.uploads(), ["fleet", "fleet"]);
+        let stored = harness.orchestrator.get_game_state("fleet").await.unwrap();
+        assert_eq!(stored.proof_cid.as_deref(), Some("bafy-fleet"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn games_that_cannot_take_a_proof_never_reach_the_relayer() {
+        // The relayer rejects everything, so reaching it would fail as ProofVerification
+        let harness = Harness::with_sink(OrchestratorConfig::default(), MockSink::rejecting());
+        {
+            let mut games = harness.orchestrator.completed_games.write().await;
+            games.insert(
+                "proven".to_string(),
+                GameState {
+                    verifiable: true,
+                    proof_cid: Some("bafy-proven".to_string()),
+                    ..GameState::new()
+                },
+            );
+            games.insert(
+                "os-seeded".to_string(),
+                GameState {
+                    verifiable: false,
+                    ..GameState::new()
+                },
+            );
+        }
+
+        for (session_id, expected) in [
+            ("missing", "not ready or not found"),
+            ("os-seeded", "has no proof to attach"),
+            ("proven", "already has a stored proof"),
+        ] {
+            let error = harness
+                .orchestrator
+                .attach_external_proof(session_id, mock_proof("0x".to_string()))
+                .await
+                .unwrap_err();
+            assert!(error.downcast_ref::<GameFailure>().is_none(), "{}", error);
+            assert!(error.to_string().contains(expected), "{}", error);
+        }
+        assert!(harness.sink.uploads().is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn relayer_and_upload_failures_report_their_reasons() {
+        let config = OrchestratorConfig {
+            finalize_max_retries: 0,
+            ..OrchestratorConfig::default()
+        };
+        for (sink, expected) in [
+            (MockSink::rejecting(), FailureReason::ProofVerification),
+            (MockSink::failing_uploads(1), FailureReason::ProofUpload),
+        ] {
+            let harness = Harness::with_sink(config.clone(), sink);
+            let game = waiting_game("game", 1);
+            insert_pending(&harness, game.clone()).await;
+
+            assert!(harness
+                .orchestrator
+                .finalize_with_retry(&game, strong_seed("sink"))
+                .await
+                .is_err());
+
+            let status = harness.orchestrator.get_game_status("game").await.unwrap();
+            match status.status {
+                GameStatus::Failed { reason, .. } => assert_eq!(reason, expected),
+                other => panic!("expected {:?}, got {:?}", expected, other),
+            }
+            assert_eq!(harness.prover.calls(), 1);
+            assert!(harness.sink.uploads().is_empty());
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn an_interrupted_proof_without_a_seed_fails_as_internal() {
+        let harness = Harness::new(OrchestratorConfig::default());
+        let mut game = waiting_game("interrupted", 1);
+        game.status = GameStatus::GeneratingProof;
+        insert_pending(&harness, game).await;
+
+        harness
+            .orchestrator
+            .clone()
+            .resume_interrupted_proofs()
+            .await;
+
+        let status = harness
+            .orchestrator
+            .get_game_status("interrupted")
+            .await
+            .unwrap();
+        assert!(
+            matches!(
+                status.status,
+                GameStatus::Failed {
+                    reason: FailureReason::Internal,
+                    ..
+                }
+            ),
+            "{:?}",
+            status.status
+        );
+        assert_eq!(harness.prover.calls(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn proof_jobs_beyond_the_pool_wait_for_a_slot() {
+        let config = OrchestratorConfig {
+            prover_threads: 1,
+            ..tournament_config()
+        };
+        let harness = Harness::with_prover(config, MockProver::held());
+        let orchestrator = &harness.orchestrator;
+
+        let first = orchestrator
+            .initiate_game(2, 7, GameOptions::default())
+            .await
+            .unwrap();
+        let second = orchestrator
+            .initiate_game(2, 7, GameOptions::default())
+            .await
+            .unwrap();
+        harness.prover_entered(1).await;
+        while orchestrator
+            .queue_position(&second.session_id)
+            .await
+            .is_none()
+        {
+            tokio::task::yield_now().await;
+        }
+
+        assert_eq!(orchestrator.queue_position(&first.session_id).await, None);
+        assert_eq!(
+            orchestrator.queue_position(&second.session_id).await,
+            Some(1)
+        );
+        assert_eq!(harness.prover.calls(), 1);
+
+        harness.prover.release();
+        for initiation in [&first, &second] {
+            let status = harness
+                .wait_for(&initiation.session_id, |status| !status.is_in_progress())
+                .await;
+            assert_eq!(status, GameStatus::Ready);
+        }
+        assert_eq!(harness.prover.calls(), 2);
+        assert_eq!(harness.prover.peak(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn queued_proofs_move_up_as_earlier_ones_finish() {
+        let config = OrchestratorConfig {
+            prover_threads: 1,
+            ..tournament_config()
+        };
+        let harness = Harness::with_prover(config, MockProver::held());
+        let mut sessions = Vec::new();
+        for _ in 0..4 {
+            let initiation = harness
+                .orchestrator
+                .initiate_game(2, 7, GameOptions::default())
+                .await
+                .unwrap();
+            sessions.push(initiation.session_id);
+        }
+        harness.prover_entered(1).await;
+
+        let mut previous: Option<Vec<Option<usize>>> = None;
+        for proving in 1..=4usize {
+            let waiting = 4 - proving;
+            let positions = loop {
+                let mut positions = Vec::new();
+                for session_id in &sessions {
+                    let status = harness.orchestrator.get_game_status(session_id).await;
+                    positions.push(status.unwrap().queue_position);
+                }
+                if positions.iter().flatten().count() == waiting {
+                    break positions;
+                }
+                tokio::task::yield_now().await;
+            };
+
+            let mut queued: Vec<usize> = positions.iter().flatten().copied().collect();
+            queued.sort_unstable();
+            assert_eq!(queued, (1..=waiting).collect::<Vec<_>>());
+            if let Some(previous) = &previous {
+                for (now, before) in positions.iter().zip(previous) {
+                    if let (Some(now), Some(before)) = (now, before) {
+                        assert_eq!(*now, before - 1);
+                    }
+                }
+            }
+            previous = Some(positions);
+
+            harness.prover.release_one();
+            if waiting > 0 {
+                harness.prover_entered(proving as u32 + 1).await;
+            }
+        }
+
+        for session_id in &sessions {
+            let status = harness
+                .wait_for(session_id, |status| !status.is_in_progress())
+                .await;
+            assert_eq!(status, GameStatus::Ready);
+        }
+        assert_eq!(harness.prover.peak(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cost_estimates_are_stable_and_run_once_per_params() {
+        let harness = Harness::new(OrchestratorConfig {
+            prover_cycles_per_sec: 400_000,
+            ..OrchestratorConfig::default()
+        });
+        let orchestrator = &harness.orchestrator;
+
+        let first = orchestrator.estimate_proof_cost(4, 7).await.unwrap();
+        let second = orchestrator.estimate_proof_cost(4, 7).await.unwrap();
+        let other = orchestrator.estimate_proof_cost(2, 7).await.unwrap();
+
+        assert_eq!(first.cycles, 4_000_000);
+        assert_eq!(first.estimated_seconds, 10);
+        assert_eq!(
+            (second.cycles, second.estimated_seconds),
+            (first.cycles, first.estimated_seconds)
+        );
+        assert_eq!(other.cycles, 2_000_000);
+        assert_eq!(harness.prover.estimates(), 2);
+        assert!(orchestrator.estimate_proof_cost(0, 7).await.is_err());
+        assert_eq!(harness.prover.estimates(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cost_estimates_wait_for_a_prover_slot() {
+        let config = OrchestratorConfig {
+            prover_threads: 1,
+            ..tournament_config()
+        };
+        let harness = Harness::with_prover(config, MockProver::held());
+        let orchestrator = harness.orchestrator.clone();
+
+        let initiation = orchestrator
+            .initiate_game(2, 7, GameOptions::default())
+            .await
+            .unwrap();
+        harness.prover_entered(1).await;
+
+        let estimate = tokio::spawn({
+            let orchestrator = orchestrator.clone();
+            async move { orchestrator.estimate_proof_cost(3, 7).await }
+        });
+        for _ in 0..100 {
+            tokio::task::yield_now().await;
+        }
+        assert!(!estimate.is_finished());
+
+        harness.prover.release();
+        let estimate = estimate.await.unwrap().unwrap();
+        assert_eq!(estimate.cycles, 3_000_000);
+        assert_eq!(harness.prover.peak(), 1);
+        harness
+            .wait_for(&initiation.session_id, |status| !status.is_in_progress())
+            .await;
+    }
 }
@@ -0,0 +1,384 @@
+// backend/src/orchestrator/mock.rs
+
+//! Prover and proof sink doubles, and an orchestrator wired to them over a mock chain
+
+use alloy::primitives::hex;
+use alloy_sol_types::SolType;
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+use super::config::OrchestratorConfig;
+use super::core::GameOrchestrator;
+use super::services::{BoxFuture, ProofSink, Prover};
+use super::storage::{ActionOutput, GameStatus};
+use crate::blockchain::mock::MockTransport;
+use crate::blockchain::VrfMode;
+use crate::proof_management::{proof_verification::VerificationResult, retry_service::PinReceipt};
+use zunnogame_lib::{
+    build_public_values, commit_seed_derivation, derive_shuffle_seed, perform_shuffle,
+    PublicValuesStruct,
+};
+use zunnogame_script::{ProofConversion, ProofInput, ProofManifest, ProofOutput};
+
+pub(crate) const MOCK_IMAGE_ID: &str =
+    "0x1111111111111111111111111111111111111111111111111111111111111111";
+
+/// Hex ABI-encoded public values the program commits for `input`
+pub(crate) fn committed_public_values(input: &ProofInput) -> String {
+    let outcome = perform_shuffle(
+        input.num_players,
+        input.cards_per_player,
+        derive_shuffle_seed(input.seed, input.instance_salt),
+    )
+    .expect("proof input was validated");
+    let mut public_values = build_public_values(
+        input.num_players,
+        input.cards_per_player,
+        input.seed,
+        input.instance_salt,
+        &outcome,
+        input.salted_hands,
+        input.build_merkle,
+    );
+    if let Some(derivation) = &input.seed_derivation {
+        commit_seed_derivation(&mut public_values, derivation);
+    }
+    hex::encode_prefixed(PublicValuesStruct::abi_encode(&public_values))
+}
+
+/// Calls inside the prover, and whether they are held there
+#[derive(Default)]
+struct Gate {
+    held: bool,
+    /// Held calls allowed through by `release_one` but not yet through
+    passes: u32,
+    inside: u32,
+    entered: u32,
+    peak: u32,
+}
+
+/// Prover answering at once with a placeholder proof carrying the real public values, after
+/// failing a set number of times
+#[derive(Default)]
+pub(crate) struct MockProver {
+    failures_left: AtomicU32,
+    tampers: bool,
+    calls: AtomicU32,
+    estimates: AtomicU32,
+    committed: Mutex<Vec<String>>,
+    gate: Mutex<Gate>,
+    gate_changed: Condvar,
+    unhealthy: AtomicBool,
+    health_probes: AtomicU32,
+}
+
+impl MockProver {
+    /// Fails the first `failures` proof requests
+    pub(crate) fn failing(failures: u32) -> Self {
+        Self {
+            failures_left: AtomicU32::new(failures),
+            ..Self::default()
+        }
+    }
+
+    /// Returns proofs whose public values commit to a different seed than requested
+    pub(crate) fn tampering() -> Self {
+        Self {
+            tampers: true,
+            ..Self::default()
+        }
+    }
+
+    /// Blocks every proof, estimate and health call until `release`
+    pub(crate) fn held() -> Self {
+        let prover = Self::default();
+        prover.gate.lock().unwrap().held = true;
+        prover
+    }
+
+    /// Let held calls finish, and every later one through at once
+    pub(crate) fn release(&self) {
+        self.gate.lock().unwrap().held = false;
+        self.gate_changed.notify_all();
+    }
+
+    /// Let one held call finish while the rest stay held
+    pub(crate) fn release_one(&self) {
+        self.gate.lock().unwrap().passes += 1;
+        self.gate_changed.notify_all();
+    }
+
+    /// Block until `calls` proof, estimate or health calls have entered the prover
+    pub(crate) fn wait_until_entered(&self, calls: u32) {
+        let mut gate = self.gate.lock().unwrap();
+        while gate.entered < calls {
+            gate = self.gate_changed.wait(gate).unwrap();
+        }
+    }
+
+    /// What later health probes report
+    pub(crate) fn set_healthy(&self, healthy: bool) {
+        self.unhealthy.store(!healthy, Ordering::SeqCst);
+    }
+
+    /// Health probes run so far
+    pub(crate) fn health_probes(&self) -> u32 {
+        self.health_probes.load(Ordering::SeqCst)
+    }
+
+    /// Proof requests received so far, failed ones included
+    pub(crate) fn calls(&self) -> u32 {
+        self.calls.load(Ordering::SeqCst)
+    }
+
+    /// Hex public values of every proof returned so far, in order
+    pub(crate) fn committed(&self) -> Vec<String> {
+        self.committed.lock().unwrap().clone()
+    }
+
+    /// Cycle estimates run so far
+    pub(crate) fn estimates(&self) -> u32 {
+        self.estimates.load(Ordering::SeqCst)
+    }
+
+    /// Most calls that were ever inside the prover at once
+    pub(crate) fn peak(&self) -> u32 {
+        self.gate.lock().unwrap().peak
+    }
+
+    fn pass_gate<T>(&self, work: impl FnOnce() -> T) -> T {
+        let mut gate = self.gate.lock().unwrap();
+        gate.inside += 1;
+        gate.entered += 1;
+        gate.peak = gate.peak.max(gate.inside);
+        self.gate_changed.notify_all();
+        while gate.held && gate.passes == 0 {
+            gate = self.gate_changed.wait(gate).unwrap();
+        }
+        if gate.held {
+            gate.passes -= 1;
+        }
+        drop(gate);
+
+        let result = work();
+        self.gate.lock().unwrap().inside -= 1;
+        result
+    }
+}
+
+impl Prover for MockProver {
+    fn generate_proof(&self, mut input: ProofInput) -> Result<ProofOutput> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.pass_gate(|| {
+            let failing = self
+                .failures_left
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| {
+                    left.checked_sub(1)
+                })
+                .is_ok();
+            if failing {
+                return Err(anyhow!("mock prover failure"));
+            }
+            if self.tampers {
+                input.seed[31] ^= 1;
+            }
+
+            let pub_inputs = committed_public_values(&input);
+            self.committed.lock().unwrap().push(pub_inputs.clone());
+            Ok(ProofOutput {
+                proof: format!("0x{}", "ab".repeat(2048)),
+                image_id: MOCK_IMAGE_ID.to_string(),
+                pub_inputs,
+                conversion: ProofConversion::default(),
+            })
+        })
+    }
+
+    fn estimate_cycles(&self, input: ProofInput) -> Result<u64> {
+        self.estimates.fetch_add(1, Ordering::SeqCst);
+        self.pass_gate(|| Ok(1_000_000 * input.num_players as u64))
+    }
+
+    fn health_check(&self) -> bool {
+        self.health_probes.fetch_add(1, Ordering::SeqCst);
+        self.pass_gate(|| !self.unhealthy.load(Ordering::SeqCst))
+    }
+
+    fn manifest(&self) -> ProofManifest {
+        ProofManifest {
+            image_id: MOCK_IMAGE_ID.to_string(),
+            program_version: "test".to_string(),
+            commitment_version: "test".to_string(),
+            hash_algorithm: "sha256".to_string(),
+        }
+    }
+
+    fn export_vk(&self) -> Result<Vec<u8>> {
+        Ok(vec![0x11; 32])
+    }
+}
+
+/// Sink accepting every proof and pinning it under a CID derived from the session id, unless
+/// told to reject proofs or fail uploads
+#[derive(Default)]
+pub(crate) struct MockSink {
+    uploads: Mutex<Vec<String>>,
+    /// Proof JSON of every upload, by CID
+    pinned: Mutex<HashMap<String, String>>,
+    rejects_proofs: bool,
+    upload_failures_left: AtomicU32,
+    verifications: AtomicU32,
+}
+
+impl MockSink {
+    /// Fails verification of every proof
+    pub(crate) fn rejecting() -> Self {
+        Self {
+            rejects_proofs: true,
+            ..Self::default()
+        }
+    }
+
+    /// Fails the first `failures` uploads
+    pub(crate) fn failing_uploads(failures: u32) -> Self {
+        Self {
+            upload_failures_left: AtomicU32::new(failures),
+            ..Self::default()
+        }
+    }
+
+    /// Session ids of every proof pinned so far, in order
+    pub(crate) fn uploads(&self) -> Vec<String> {
+        self.uploads.lock().unwrap().clone()
+    }
+
+    /// Number of proofs submitted for verification so far
+    pub(crate) fn verifications(&self) -> u32 {
+        self.verifications.load(Ordering::SeqCst)
+    }
+}
+
+impl ProofSink for MockSink {
+    fn verify(&self, _proof: ProofOutput) -> BoxFuture<'_, Result<VerificationResult>> {
+        Box::pin(async move {
+            self.verifications.fetch_add(1, Ordering::SeqCst);
+            if self.rejects_proofs {
+                return Err(anyhow!("mock relayer rejected the proof"));
+            }
+            Ok(VerificationResult {
+                tx_hash: "0xfeed".to_string(),
+                job_id: "job-1".to_string(),
+                status: "Finalized".to_string(),
+                aggregation_id: None,
+            })
+        })
+    }
+
+    fn upload(&self, output: ActionOutput) -> BoxFuture<'_, Result<PinReceipt>> {
+        Box::pin(async move {
+            let failing = self
+                .upload_failures_left
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| {
+                    left.checked_sub(1)
+                })
+                .is_ok();
+            if failing {
+                return Err(anyhow!("mock upload failure"));
+            }
+
+            let cid = format!("bafy-{}", output.id);
+            self.uploads.lock().unwrap().push(output.id.clone());
+            self.pinned.lock().unwrap().insert(cid.clone(), output.data);
+            Ok(PinReceipt {
+                cid,
+                pinned_by: vec!["mock".to_string()],
+                failed: Vec::new(),
+            })
+        })
+    }
+
+    fn fetch<'a>(&'a self, cid: &'a str) -> BoxFuture<'a, Result<ProofOutput>> {
+        Box::pin(async move {
+            let data = self.pinned.lock().unwrap().get(cid).cloned();
+            let data = data.ok_or_else(|| anyhow!("nothing pinned under {}", cid))?;
+            Ok(serde_json::from_str(&data)?)
+        })
+    }
+}
+
+/// Orchestrator over a polling-mode adapter on `rpc`, with the mock prover and sink
+pub(crate) struct Harness {
+    pub(crate) orchestrator: Arc<GameOrchestrator>,
+    pub(crate) rpc: MockTransport,
+    pub(crate) prover: Arc<MockProver>,
+    pub(crate) sink: Arc<MockSink>,
+}
+
+impl Harness {
+    pub(crate) fn new(config: OrchestratorConfig) -> Self {
+        Self::with_prover(config, MockProver::default())
+    }
+
+    pub(crate) fn with_prover(config: OrchestratorConfig, prover: MockProver) -> Self {
+        Self::with_services(config, prover, MockSink::default())
+    }
+
+    pub(crate) fn with_sink(config: OrchestratorConfig, sink: MockSink) -> Self {
+        Self::with_services(config, MockProver::default(), sink)
+    }
+
+    pub(crate) fn with_services(
+        config: OrchestratorConfig,
+        prover: MockProver,
+        sink: MockSink,
+    ) -> Self {
+        let rpc = MockTransport::new();
+        let prover = Arc::new(prover);
+        let sink = Arc::new(sink);
+        let orchestrator = Arc::new(GameOrchestrator::with_services(
+            rpc.adapter(VrfMode::Polling),
+            config,
+            prover.clone(),
+            sink.clone(),
+        ));
+
+        Self {
+            orchestrator,
+            rpc,
+            prover,
+            sink,
+        }
+    }
+
+    /// Wait, off the runtime, until `calls` prover calls have started
+    pub(crate) async fn prover_entered(&self, calls: u32) {
+        let prover = self.prover.clone();
+        tokio::task::spawn_blocking(move || prover.wait_until_entered(calls))
+            .await
+            .unwrap();
+    }
+
+    /// Wait until the game's status satisfies `done`, letting background tasks run
+    pub(crate) async fn wait_for(
+        &self,
+        session_id: &str,
+        done: impl Fn(&GameStatus) -> bool,
+    ) -> GameStatus {
+        for _ in 0..600 {
+            let status = self
+                .orchestrator
+                .get_game_status(session_id)
+                .await
+                .unwrap()
+                .status;
+            if done(&status) {
+                return status;
+            }
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+        panic!("game {} never reached the expected status", session_id);
+    }
+}
@@ -1,12 +1,20 @@
 // backend/src/orchestrator/mod.rs
 
+mod config;
 mod core;
+#[cfg(test)]
+pub(crate) mod mock;
+mod services;
 mod storage;
 
+pub use config::OrchestratorConfig;
 pub use core::GameOrchestrator;
+pub use services::{BoxFuture, ProofSink, Prover, RelayerSink};
 pub use storage::{
-    bytes32_to_u256, current_timestamp, u256_to_bytes32, ActionOutput, GameInitiation, GameStatus,
-    GameStatusResponse, PendingGame,
+    bytes32_to_u256, current_timestamp, u256_to_bytes32, ActionOutput, FailureReason, GameFailure,
+    GameInitiation, GameOptions, GameSnapshot, GameStatus, GameStatusResponse, GameSummary,
+    PendingGame, ProofEstimate, SeedSource, VrfRequestLookup, DEFAULT_THEME, MAX_GAME_NAME_LEN,
+    PENDING_GAME_TTL_SECS,
 };
 
 // Re-export types needed by API
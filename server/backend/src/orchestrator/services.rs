@@ -0,0 +1,93 @@
+// backend/src/orchestrator/services.rs
+
+use anyhow::{anyhow, Result};
+use std::future::Future;
+use std::pin::Pin;
+
+use super::storage::ActionOutput;
+use crate::proof_management::{
+    config::IpfsProvider,
+    gateway::fetch_proof,
+    proof_verification::{verify_proof, VerificationResult},
+    retry_service::{IpfsService, IpfsUploadConfig, PinReceipt},
+};
+use zunnogame_script::{ProofGenerator, ProofInput, ProofManifest, ProofOutput};
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Generates deal proofs; `ProofGenerator` in production
+///
+/// Every method but `manifest` may block for a long time and is called from the blocking pool.
+pub trait Prover: Send + Sync + 'static {
+    fn generate_proof(&self, input: ProofInput) -> Result<ProofOutput>;
+
+    /// Cycle count of executing the program for `input`, without proving
+    fn estimate_cycles(&self, input: ProofInput) -> Result<u64>;
+
+    fn health_check(&self) -> bool;
+
+    fn manifest(&self) -> ProofManifest;
+
+    fn export_vk(&self) -> Result<Vec<u8>>;
+}
+
+impl Prover for ProofGenerator {
+    fn generate_proof(&self, input: ProofInput) -> Result<ProofOutput> {
+        ProofGenerator::generate_proof(self, input)
+    }
+
+    fn estimate_cycles(&self, input: ProofInput) -> Result<u64> {
+        ProofGenerator::estimate_cycles(self, input)
+    }
+
+    fn health_check(&self) -> bool {
+        ProofGenerator::health_check(self)
+    }
+
+    fn manifest(&self) -> ProofManifest {
+        ProofGenerator::manifest(self)
+    }
+
+    fn export_vk(&self) -> Result<Vec<u8>> {
+        ProofGenerator::export_vk(self)
+    }
+}
+
+/// Where proofs go once generated: zkVerify for verification, IPFS for storage
+pub trait ProofSink: Send + Sync + 'static {
+    fn verify(&self, proof: ProofOutput) -> BoxFuture<'_, Result<VerificationResult>>;
+
+    fn upload(&self, output: ActionOutput) -> BoxFuture<'_, Result<PinReceipt>>;
+
+    /// Read an uploaded proof back by its CID
+    fn fetch<'a>(&'a self, cid: &'a str) -> BoxFuture<'a, Result<ProofOutput>>;
+}
+
+/// Production sink: the zkVerify relayer and every IPFS provider configured in env
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RelayerSink;
+
+impl ProofSink for RelayerSink {
+    fn verify(&self, proof: ProofOutput) -> BoxFuture<'_, Result<VerificationResult>> {
+        Box::pin(verify_proof(proof))
+    }
+
+    fn upload(&self, output: ActionOutput) -> BoxFuture<'_, Result<PinReceipt>> {
+        Box::pin(async move {
+            // Initialize IPFS service with every configured provider
+            let providers = IpfsProvider::all_from_env()?;
+            let config = IpfsUploadConfig::from_env()?;
+            let ipfs_service = IpfsService::with_providers(providers, config)?;
+
+            // Upload to IPFS; stored as long as one provider pins it
+            ipfs_service.upload_to_all(&output).await.map_err(|err| {
+                tracing::error!("Failed to upload proof to IPFS: {}", err);
+                anyhow!("Proof upload failed: {}", err)
+            })
+        })
+    }
+
+    fn fetch<'a>(&'a self, cid: &'a str) -> BoxFuture<'a, Result<ProofOutput>> {
+        Box::pin(async move { Ok(fetch_proof(cid).await?) })
+    }
+}
@@ -3,6 +3,132 @@
 use alloy::primitives::U256;
 use serde::{Deserialize, Serialize};
 
+use crate::blockchain::MasterSeed;
+use crate::game::{DeckHealth, DiscardVisibility, GameOutcome, GameState, PlayerId};
+use std::collections::HashMap;
+use std::time::Duration;
+
+// Seed conversions live in the lib so host and zkVM share one byte order
+pub use zunnogame_lib::{bytes32_to_u256, u256_to_bytes32};
+
+/// Default deck theme when a game doesn't request one
+pub const DEFAULT_THEME: &str = "classic";
+
+/// Pending games older than this are dropped by the cleanup task
+pub const PENDING_GAME_TTL_SECS: u64 = 600;
+
+/// Longest accepted human-readable game name, in characters
+pub const MAX_GAME_NAME_LEN: usize = 64;
+
+/// Where a game's shuffle seed comes from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SeedSource {
+    /// On-chain VRF, proven and verified
+    #[default]
+    Vrf,
+    /// Local OS randomness for offline play; not reproducible and never proven
+    Os,
+}
+
+/// Optional per-game settings supplied when a game is started
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameOptions {
+    /// Human-readable label for operators; the session id stays the canonical key
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Deck skin identifier echoed back to frontends
+    pub theme: String,
+    /// Salt hand commitments per player; disable for cheaper commitments in public games
+    #[serde(default = "default_salted_hands")]
+    pub salted_hands: bool,
+    /// Build the per-card Merkle tree; deal-only proofs skip it to save cycles
+    #[serde(default = "default_build_merkle")]
+    pub build_merkle: bool,
+    /// Reject out-of-turn moves and apply `max_draws_per_turn`
+    #[serde(default)]
+    pub enforce_turns: bool,
+    /// Cards a player may draw per turn when turns are enforced
+    #[serde(default)]
+    pub max_draws_per_turn: Option<u8>,
+    /// Passing requires a draw earlier in the same turn
+    #[serde(default)]
+    pub require_draw_before_pass: bool,
+    /// Source of the shuffle seed
+    #[serde(default)]
+    pub seed_source: SeedSource,
+    /// How much of the discard pile the discard endpoint reveals
+    #[serde(default)]
+    pub discard_visibility: DiscardVisibility,
+    /// Seat that takes the first turn; derived from the seed when unset
+    #[serde(default)]
+    pub starting_player: Option<PlayerId>,
+    /// Finished session this game is a rematch of
+    #[serde(default)]
+    pub rematch_of: Option<String>,
+    /// Dealt game whose VRF value this game derives its seed from instead of a new request
+    #[serde(default)]
+    pub master_session: Option<String>,
+    /// Per-seat tokens for the player endpoints; issued when the game starts if empty
+    #[serde(default)]
+    pub player_tokens: Vec<String>,
+}
+
+impl GameOptions {
+    /// Options for a rematch of a finished game: the same table settings with a fresh seed
+    ///
+    /// Seat tokens carry over so players keep their seats without re-joining. The starting
+    /// seat is left to the new seed, as the original may have been derived too.
+    pub fn for_rematch(session_id: &str, game: &GameState) -> Self {
+        Self {
+            name: game.name.clone(),
+            theme: game.theme.clone(),
+            salted_hands: game.salted_hands,
+            build_merkle: game.merkle_built,
+            enforce_turns: game.enforce_turns,
+            max_draws_per_turn: game.max_draws_per_turn,
+            require_draw_before_pass: game.require_draw_before_pass,
+            seed_source: match game.verifiable {
+                true => SeedSource::Vrf,
+                false => SeedSource::Os,
+            },
+            discard_visibility: game.discard_visibility,
+            starting_player: None,
+            rematch_of: Some(session_id.to_string()),
+            master_session: None,
+            player_tokens: game.player_tokens.clone(),
+        }
+    }
+}
+
+impl Default for GameOptions {
+    fn default() -> Self {
+        Self {
+            name: None,
+            theme: DEFAULT_THEME.to_string(),
+            salted_hands: default_salted_hands(),
+            build_merkle: default_build_merkle(),
+            enforce_turns: false,
+            max_draws_per_turn: None,
+            require_draw_before_pass: false,
+            seed_source: SeedSource::default(),
+            discard_visibility: DiscardVisibility::default(),
+            starting_player: None,
+            rematch_of: None,
+            master_session: None,
+            player_tokens: Vec::new(),
+        }
+    }
+}
+
+fn default_salted_hands() -> bool {
+    true
+}
+
+fn default_build_merkle() -> bool {
+    true
+}
+
 /// Represents a game waiting for VRF fulfillment
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PendingGame {
@@ -13,6 +139,41 @@ pub struct PendingGame {
     pub cards_per_player: u8,
     pub requested_at: u64,
     pub status: GameStatus,
+    #[serde(default)]
+    pub options: GameOptions,
+    /// Superseded VRF request ids, oldest first
+    #[serde(default)]
+    pub vrf_request_history: Vec<U256>,
+    /// Dealt from the server-wide tournament seed instead of a VRF value
+    #[serde(default)]
+    pub tournament: bool,
+    /// Seed being proven, persisted before proving so a restart can resume without a new VRF
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof_seed: Option<U256>,
+    /// Unix time the current VRF request was recorded, which opens the commit window
+    #[serde(default)]
+    pub committed_at: u64,
+    /// Master VRF draw the seed is derived from; such games never request VRF themselves
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub master_seed: Option<MasterSeed>,
+}
+
+impl PendingGame {
+    /// Whether cleanup may drop this entry at `now`
+    ///
+    /// Games being proven are kept however long their finalization retries take; dropping
+    /// one mid-retry would lose the game once the proof lands. Games awaiting their reveal
+    /// count the TTL from when the commit window closes, not from the original request.
+    pub fn is_expired(&self, now: u64, commit_reveal_delay: Option<Duration>) -> bool {
+        let since = match (&self.status, commit_reveal_delay) {
+            (GameStatus::GeneratingProof, _) => return false,
+            (GameStatus::Committed | GameStatus::WaitingForVRF, Some(delay)) => {
+                self.requested_at.max(self.committed_at + delay.as_secs())
+            }
+            _ => self.requested_at,
+        };
+        now.saturating_sub(since) >= PENDING_GAME_TTL_SECS
+    }
 }
 
 /// Status of a game in the system
@@ -20,14 +181,106 @@ pub struct PendingGame {
 pub enum GameStatus {
     /// Initial request received, requesting VRF
     Requesting,
+    /// VRF request public, deal withheld until the commit-reveal delay has passed
+    Committed,
     /// VRF request sent, waiting for fulfillment
     WaitingForVRF,
     /// VRF fulfilled, generating ZK proof
     GeneratingProof,
     /// Game ready to play
     Ready,
+    /// Game over; see `outcome` in the status response
+    Finished,
     /// Error occurred
-    Failed(String),
+    Failed {
+        reason: FailureReason,
+        detail: String,
+    },
+}
+
+/// Stage a game failed at, so clients can offer the right retry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureReason {
+    /// No VRF value was obtained: the request failed or fulfillment never arrived
+    VrfTimeout,
+    /// The shuffle proof could not be generated
+    ProofGeneration,
+    /// zkVerify rejected the proof or its job never finalized
+    ProofVerification,
+    /// The proof could not be pinned to IPFS
+    ProofUpload,
+    /// The game parameters or seed can't be dealt
+    InvalidParams,
+    /// Anything else
+    Internal,
+}
+
+/// Error tagged with the `FailureReason` it should be reported as
+#[derive(Debug, thiserror::Error)]
+#[error("{detail}")]
+pub struct GameFailure {
+    pub reason: FailureReason,
+    pub detail: String,
+    /// Retrying would fail the same way, e.g. a rejected seed
+    pub permanent: bool,
+}
+
+impl GameFailure {
+    pub fn new(reason: FailureReason, error: impl std::fmt::Display) -> Self {
+        Self {
+            reason,
+            detail: error.to_string(),
+            permanent: false,
+        }
+    }
+
+    /// Failure that no retry can fix
+    pub fn permanent(reason: FailureReason, error: impl std::fmt::Display) -> Self {
+        Self {
+            permanent: true,
+            ..Self::new(reason, error)
+        }
+    }
+
+    /// Whether `error` is a `GameFailure` that no retry can fix
+    pub fn is_permanent(error: &anyhow::Error) -> bool {
+        error
+            .downcast_ref::<GameFailure>()
+            .is_some_and(|failure| failure.permanent)
+    }
+}
+
+impl GameStatus {
+    /// `Failed` status for an error, using its `GameFailure` reason or `Internal`
+    pub fn failed(error: &anyhow::Error) -> Self {
+        match error.downcast_ref::<GameFailure>() {
+            Some(failure) => GameStatus::Failed {
+                reason: failure.reason,
+                detail: failure.detail.clone(),
+            },
+            None => GameStatus::Failed {
+                reason: FailureReason::Internal,
+                detail: error.to_string(),
+            },
+        }
+    }
+
+    /// Whether the game is still on its way to `Ready`
+    pub fn is_in_progress(&self) -> bool {
+        matches!(
+            self,
+            GameStatus::Requesting
+                | GameStatus::Committed
+                | GameStatus::WaitingForVRF
+                | GameStatus::GeneratingProof
+        )
+    }
+
+    /// Holding a VRF request that hasn't been turned into a deal yet
+    pub fn awaits_vrf(&self) -> bool {
+        matches!(self, GameStatus::Committed | GameStatus::WaitingForVRF)
+    }
 }
 
 /// Response for game initiation
@@ -37,6 +290,9 @@ pub struct GameInitiation {
     pub status: GameStatus,
     pub estimated_wait_seconds: u64,
     pub vrf_request_id: U256,
+    /// Token per seat, to be handed to each player for the `x-player-token` header
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub player_tokens: Vec<String>,
 }
 
 /// Response for game status queries
@@ -46,9 +302,77 @@ pub struct GameStatusResponse {
     pub status: GameStatus,
     pub elapsed_seconds: u64,
     pub vrf_request_id: Option<U256>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub theme: String,
+    /// Draw pile health, once the game is dealt
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deck_health: Option<DeckHealth>,
+    /// How the game ended, once it is `Finished`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outcome: Option<GameOutcome>,
+    /// Dealt from the tournament seed rather than VRF; every such table is identical
+    pub tournament: bool,
+    /// False for OS-seeded games, which have no proof
+    pub verifiable: bool,
+    /// Seconds since the last move, for dealt games still in play
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_seconds: Option<u64>,
+    /// 1-based place among proofs waiting for a prover slot; absent once proving starts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue_position: Option<usize>,
+    /// Unix time a `Committed` game's commit window closes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reveal_at: Option<u64>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// Every in-memory game, written out on shutdown
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    /// Unix time the snapshot was taken
+    pub taken_at: u64,
+    pub pending: HashMap<String, PendingGame>,
+    pub completed: HashMap<String, GameState>,
+}
+
+/// Game a VRF request id was issued for
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VrfRequestLookup {
+    pub request_id: U256,
+    pub session_id: String,
+    pub status: GameStatus,
+    /// The request was replaced by a re-request and no longer drives the deal
+    pub superseded: bool,
+}
+
+/// One row of the games list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameSummary {
+    pub session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub status: GameStatus,
+    pub num_players: u8,
+    pub cards_per_player: u8,
+    pub theme: String,
+    /// Unix time the game was started
+    pub requested_at: u64,
+    /// Dealt from the tournament seed rather than VRF
+    pub tournament: bool,
+}
+
+/// Estimated proving cost for a set of game parameters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProofEstimate {
+    pub num_players: u8,
+    pub cards_per_player: u8,
+    /// zkVM cycles for executing the shuffle program
+    pub cycles: u64,
+    /// Rough proving time derived from the configured prover throughput
+    pub estimated_seconds: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ActionOutput {
     pub id: String,
     pub timestamp: String,
@@ -65,14 +389,130 @@ pub fn current_timestamp() -> u64 {
         .as_secs()
 }
 
-/// Converts a U256 value to a 32-byte array (big-endian).
-pub fn u256_to_bytes32(value: U256) -> [u8; 32] {
-    let mut bytes = [0u8; 32];
-    bytes.copy_from_slice(&value.to_be_bytes::<32>());
-    bytes
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending(status: GameStatus, requested_at: u64) -> PendingGame {
+        PendingGame {
+            session_id: "game".to_string(),
+            vrf_request_id: U256::from(1),
+            vrf_block_number: 16,
+            num_players: 2,
+            cards_per_player: 7,
+            requested_at,
+            status,
+            options: GameOptions::default(),
+            vrf_request_history: Vec::new(),
+            tournament: false,
+            proof_seed: None,
+            committed_at: requested_at,
+            master_seed: None,
+        }
+    }
+
+    #[test]
+    fn games_expire_after_the_ttl() {
+        let now = 10_000;
+        for status in [GameStatus::WaitingForVRF, GameStatus::Ready] {
+            assert!(!pending(status.clone(), now - PENDING_GAME_TTL_SECS + 1).is_expired(now, None));
+            assert!(pending(status, now - PENDING_GAME_TTL_SECS).is_expired(now, None));
+        }
+    }
+
+    #[test]
+    fn games_awaiting_their_reveal_expire_after_the_window_plus_the_ttl() {
+        let delay = Some(Duration::from_secs(120));
+        for status in [GameStatus::Committed, GameStatus::WaitingForVRF] {
+            let game = pending(status, 10_000);
+            let window_closes = 10_000 + 120;
+
+            assert!(!game.is_expired(10_000 + PENDING_GAME_TTL_SECS, delay));
+            assert!(!game.is_expired(window_closes + PENDING_GAME_TTL_SECS - 1, delay));
+            assert!(game.is_expired(window_closes + PENDING_GAME_TTL_SECS, delay));
+            assert!(game.is_expired(10_000 + PENDING_GAME_TTL_SECS, None));
+        }
+    }
+
+    #[test]
+    fn a_rerequest_restarts_the_commit_window() {
+        let delay = Some(Duration::from_secs(120));
+        let game = PendingGame {
+            committed_at: 10_500,
+            ..pending(GameStatus::Committed, 10_000)
+        };
+
+        assert!(!game.is_expired(10_000 + PENDING_GAME_TTL_SECS + 120, delay));
+        assert!(game.is_expired(10_500 + 120 + PENDING_GAME_TTL_SECS, delay));
+    }
+
+    #[test]
+    fn the_delay_does_not_extend_games_past_their_reveal() {
+        let game = pending(GameStatus::Ready, 10_000);
+
+        assert!(game.is_expired(
+            10_000 + PENDING_GAME_TTL_SECS,
+            Some(Duration::from_secs(120))
+        ));
+    }
+
+    #[test]
+    fn failures_keep_their_tagged_reason() {
+        let tagged = anyhow::Error::new(GameFailure::new(FailureReason::ProofUpload, "no pins"));
+        assert_eq!(
+            GameStatus::failed(&tagged),
+            GameStatus::Failed {
+                reason: FailureReason::ProofUpload,
+                detail: "no pins".to_string(),
+            }
+        );
+
+        let context = tagged.context("finalizing");
+        assert!(matches!(
+            GameStatus::failed(&context),
+            GameStatus::Failed {
+                reason: FailureReason::ProofUpload,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn untagged_failures_are_internal() {
+        assert_eq!(
+            GameStatus::failed(&anyhow::anyhow!("boom")),
+            GameStatus::Failed {
+                reason: FailureReason::Internal,
+                detail: "boom".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn a_rematch_keeps_the_table_and_its_seat_tokens() {
+        let finished = GameState {
+            name: Some("friday".to_string()),
+            enforce_turns: true,
+            starting_player: 1,
+            player_tokens: vec!["seat-0".to_string(), "seat-1".to_string()],
+            verifiable: true,
+            ..GameState::new()
+        };
+
+        let options = GameOptions::for_rematch("original", &finished);
+
+        assert_eq!(options.player_tokens, finished.player_tokens);
+        assert_eq!(options.rematch_of.as_deref(), Some("original"));
+        assert_eq!(options.name.as_deref(), Some("friday"));
+        assert!(options.enforce_turns);
+        assert_eq!(options.seed_source, SeedSource::Vrf);
+        assert_eq!(options.starting_player, None);
+    }
 
-/// Converts a 32-byte array (big-endian) to a U256 value.
-pub fn bytes32_to_u256(bytes: [u8; 32]) -> U256 {
-    U256::from_be_bytes(bytes)
+    #[test]
+    fn games_being_proven_never_expire() {
+        let game = pending(GameStatus::GeneratingProof, 0);
+        assert!(!game.is_expired(PENDING_GAME_TTL_SECS * 10, None));
+        assert!(!game.is_expired(PENDING_GAME_TTL_SECS * 10, Some(Duration::from_secs(60))));
+    }
 }
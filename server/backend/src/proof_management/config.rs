@@ -1,22 +1,165 @@
 // backend/src/proof_management/config.rs
 
 use super::errors::{IpfsError, IpfsResult};
-use pinata_sdk::PinataApi;
 use std::env;
 
+/// Credentials used to authenticate against Pinata
+pub enum PinataAuth {
+    /// Bearer token auth (preferred by Pinata)
+    Jwt(String),
+    /// Legacy API key / secret pair
+    ApiKey { key: String, secret: String },
+}
+
 pub struct IpfsProvider {
-    pub pinata_api: PinataApi,
+    /// Label recorded when this provider pins a proof
+    pub name: String,
+    pub auth: PinataAuth,
 }
 
 impl IpfsProvider {
+    /// Every configured provider: `PINATA_JWT`, the key/secret pair, and each token in the
+    /// comma-separated `PINATA_BACKUP_JWTS`
+    pub fn all_from_env() -> IpfsResult<Vec<Self>> {
+        Self::all_from_vars(non_empty_var)
+    }
+
+    /// `all_from_env` over an arbitrary variable lookup, which must skip blank values
+    fn all_from_vars(var: impl Fn(&str) -> Option<String>) -> IpfsResult<Vec<Self>> {
+        let mut providers = Vec::new();
+
+        if let Some(jwt) = var("PINATA_JWT") {
+            providers.push(IpfsProvider {
+                name: "pinata-jwt".to_string(),
+                auth: PinataAuth::Jwt(jwt),
+            });
+        }
+
+        if let (Some(key), Some(secret)) = (var("PINATA_API_KEY"), var("PINATA_API_SECRET")) {
+            providers.push(IpfsProvider {
+                name: "pinata-key".to_string(),
+                auth: PinataAuth::ApiKey { key, secret },
+            });
+        }
+
+        if let Some(backups) = var("PINATA_BACKUP_JWTS") {
+            let tokens = backups.split(',').map(str::trim).filter(|t| !t.is_empty());
+            for (index, jwt) in tokens.enumerate() {
+                providers.push(IpfsProvider {
+                    name: format!("pinata-backup-{}", index + 1),
+                    auth: PinataAuth::Jwt(jwt.to_string()),
+                });
+            }
+        }
+
+        if providers.is_empty() {
+            return Err(IpfsError::ConfigError(
+                "Missing Pinata credentials: set PINATA_JWT or PINATA_API_KEY/PINATA_API_SECRET"
+                    .to_string(),
+            ));
+        }
+
+        Ok(providers)
+    }
+
+    /// Build the provider from env, preferring `PINATA_JWT` over the key/secret pair
     pub fn from_env() -> IpfsResult<Self> {
-        if let (Ok(key), Ok(secret)) = (env::var("PINATA_API_KEY"), env::var("PINATA_API_SECRET")) {
-            let api = PinataApi::new(key, secret).unwrap();
-            return Ok(IpfsProvider { pinata_api: api });
-        } else {
-            return Err(IpfsError::ConfigError(format!(
-                "Missing environment variables"
-            )));
+        Self::from_vars(non_empty_var)
+    }
+
+    /// `from_env` over an arbitrary variable lookup, which must skip blank values
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> IpfsResult<Self> {
+        if let Some(jwt) = var("PINATA_JWT") {
+            return Ok(IpfsProvider {
+                name: "pinata-jwt".to_string(),
+                auth: PinataAuth::Jwt(jwt),
+            });
         }
+
+        match (var("PINATA_API_KEY"), var("PINATA_API_SECRET")) {
+            (Some(key), Some(secret)) => Ok(IpfsProvider {
+                name: "pinata-key".to_string(),
+                auth: PinataAuth::ApiKey { key, secret },
+            }),
+            _ => Err(IpfsError::ConfigError(
+                "Missing Pinata credentials: set PINATA_JWT or PINATA_API_KEY/PINATA_API_SECRET"
+                    .to_string(),
+            )),
+        }
+    }
+}
+
+fn non_empty_var(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|value| !value.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn vars(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        move |key| {
+            vars.get(key)
+                .filter(|value| !value.trim().is_empty())
+                .cloned()
+        }
+    }
+
+    #[test]
+    fn jwt_is_preferred_over_the_key_pair() {
+        let provider = IpfsProvider::from_vars(vars(&[
+            ("PINATA_JWT", "jwt-token"),
+            ("PINATA_API_KEY", "key"),
+            ("PINATA_API_SECRET", "secret"),
+        ]))
+        .unwrap();
+
+        assert_eq!(provider.name, "pinata-jwt");
+        assert!(matches!(provider.auth, PinataAuth::Jwt(ref jwt) if jwt == "jwt-token"));
+    }
+
+    #[test]
+    fn key_pair_is_used_without_a_jwt() {
+        let provider = IpfsProvider::from_vars(vars(&[
+            ("PINATA_JWT", "  "),
+            ("PINATA_API_KEY", "key"),
+            ("PINATA_API_SECRET", "secret"),
+        ]))
+        .unwrap();
+
+        assert_eq!(provider.name, "pinata-key");
+        assert!(matches!(
+            provider.auth,
+            PinataAuth::ApiKey { ref key, ref secret } if key == "key" && secret == "secret"
+        ));
+    }
+
+    #[test]
+    fn missing_credentials_are_a_config_error() {
+        for pairs in [&[][..], &[("PINATA_API_KEY", "key-without-secret")][..]] {
+            let error = IpfsProvider::from_vars(vars(pairs)).err().unwrap();
+            assert!(matches!(error, IpfsError::ConfigError(_)), "{}", error);
+            assert!(error.to_string().contains("Missing Pinata credentials"));
+
+            let error = IpfsProvider::all_from_vars(vars(pairs)).err().unwrap();
+            assert!(matches!(error, IpfsError::ConfigError(_)), "{}", error);
+        }
+    }
+
+    #[test]
+    fn every_configured_provider_is_listed() {
+        let providers = IpfsProvider::all_from_vars(vars(&[
+            ("PINATA_JWT", "jwt-token"),
+            ("PINATA_BACKUP_JWTS", "backup-a, ,backup-b"),
+        ]))
+        .unwrap();
+
+        let names: Vec<_> = providers.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["pinata-jwt", "pinata-backup-1", "pinata-backup-2"]);
     }
 }
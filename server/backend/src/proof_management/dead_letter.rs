@@ -0,0 +1,161 @@
+// backend/src/proof_management/dead_letter.rs
+//
+// Proofs whose IPFS upload exhausted every retry, kept on disk until a later sweep pins them.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use super::errors::IpfsResult;
+use crate::orchestrator::{current_timestamp, ActionOutput};
+
+/// A proof upload that failed, written as `<session_id>.json` in the dead-letter directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetter {
+    /// Unix time the upload gave up
+    pub failed_at: u64,
+    /// Last upload error, for whoever re-uploads it by hand
+    pub error: String,
+    /// The exact envelope that would have been pinned
+    pub output: ActionOutput,
+}
+
+impl DeadLetter {
+    pub fn new(output: ActionOutput, error: impl ToString) -> Self {
+        Self {
+            failed_at: current_timestamp(),
+            error: error.to_string(),
+            output,
+        }
+    }
+}
+
+/// Write `letter` into `dir`, creating the directory if needed
+///
+/// Written to a temporary file and renamed, so a sweep never reads a partial letter.
+pub async fn write(dir: &Path, letter: &DeadLetter) -> IpfsResult<PathBuf> {
+    tokio::fs::create_dir_all(dir).await?;
+
+    let path = dir.join(format!("{}.json", file_stem(&letter.output.id)));
+    let tmp_path = path.with_extension("tmp");
+    tokio::fs::write(&tmp_path, serde_json::to_vec_pretty(letter)?).await?;
+    tokio::fs::rename(&tmp_path, &path).await?;
+
+    Ok(path)
+}
+
+/// Every readable letter in `dir`; a missing directory holds none
+///
+/// Unparseable files are logged and left in place for manual inspection.
+pub async fn read_all(dir: &Path) -> IpfsResult<Vec<(PathBuf, DeadLetter)>> {
+    let mut entries = match tokio::fs::read_dir(dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut letters = Vec::new();
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+
+        match read(&path).await {
+            Ok(letter) => letters.push((path, letter)),
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "Skipping unreadable dead letter");
+            }
+        }
+    }
+
+    letters.sort_by_key(|(_, letter)| letter.failed_at);
+    Ok(letters)
+}
+
+async fn read(path: &Path) -> IpfsResult<DeadLetter> {
+    let bytes = tokio::fs::read(path).await?;
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+/// Session ids are UUIDs, but keep anything else from escaping the directory
+fn file_stem(session_id: &str) -> String {
+    session_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("zunno-dead-letter-{}-{}", std::process::id(), name));
+        std::fs::remove_dir_all(&dir).ok();
+        dir
+    }
+
+    fn letter(session_id: &str, failed_at: u64) -> DeadLetter {
+        DeadLetter {
+            failed_at,
+            error: "gateway timeout".to_string(),
+            output: ActionOutput {
+                id: session_id.to_string(),
+                timestamp: "2024-01-01T00:00:00Z".to_string(),
+                data: "{}".to_string(),
+                ipfs_cid: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn letters_round_trip_oldest_first() {
+        let dir = temp_dir("round-trip");
+        write(&dir, &letter("newer", 20)).await.unwrap();
+        let path = write(&dir, &letter("older", 10)).await.unwrap();
+
+        let letters = read_all(&dir).await.unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(path, dir.join("older.json"));
+        let ids: Vec<&str> = letters.iter().map(|(_, l)| l.output.id.as_str()).collect();
+        assert_eq!(ids, ["older", "newer"]);
+        assert_eq!(letters[0].1.error, "gateway timeout");
+    }
+
+    #[tokio::test]
+    async fn a_missing_directory_holds_no_letters() {
+        let dir = temp_dir("missing");
+
+        assert!(read_all(&dir).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn unreadable_and_foreign_files_are_skipped() {
+        let dir = temp_dir("skipped");
+        write(&dir, &letter("good", 1)).await.unwrap();
+        std::fs::write(dir.join("broken.json"), "not json").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignore me").unwrap();
+
+        let letters = read_all(&dir).await.unwrap();
+        let broken_kept = dir.join("broken.json").exists();
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(letters.len(), 1);
+        assert_eq!(letters[0].1.output.id, "good");
+        assert!(broken_kept);
+    }
+
+    #[test]
+    fn session_ids_cannot_escape_the_directory() {
+        assert_eq!(file_stem("0b6f-41c2"), "0b6f-41c2");
+        assert_eq!(file_stem("../../etc/passwd"), "______etc_passwd");
+    }
+}
@@ -21,6 +21,9 @@ pub enum IpfsError {
 
     #[error("Configuration error: {0}")]
     ConfigError(String),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
 }
 
 pub type IpfsResult<T> = Result<T, IpfsError>;
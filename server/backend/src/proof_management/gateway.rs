@@ -0,0 +1,108 @@
+// backend/src/proof_management/gateway.rs
+
+use std::env;
+use zunnogame_script::ProofOutput;
+
+use super::errors::{IpfsError, IpfsResult};
+use super::http::build_http_client;
+use crate::orchestrator::ActionOutput;
+
+const DEFAULT_IPFS_GATEWAY_URL: &str = "https://gateway.pinata.cloud/ipfs";
+
+/// Gateway proofs are read back through: `IPFS_GATEWAY_URL` or Pinata's public gateway
+pub fn gateway_url() -> String {
+    env::var("IPFS_GATEWAY_URL")
+        .ok()
+        .filter(|url| !url.trim().is_empty())
+        .map(|url| url.trim().trim_end_matches('/').to_string())
+        .unwrap_or_else(|| DEFAULT_IPFS_GATEWAY_URL.to_string())
+}
+
+/// Fetch a pinned proof by CID and unwrap it from its `ActionOutput` envelope
+pub async fn fetch_proof(cid: &str) -> IpfsResult<ProofOutput> {
+    fetch_proof_from(&gateway_url(), cid).await
+}
+
+/// `fetch_proof` through the gateway at `gateway`
+pub async fn fetch_proof_from(gateway: &str, cid: &str) -> IpfsResult<ProofOutput> {
+    let client = build_http_client()?;
+    let url = format!("{}/{}", gateway.trim_end_matches('/'), cid);
+
+    tracing::debug!(cid = cid, url = %url, "Fetching proof from IPFS gateway");
+
+    let response = client.get(&url).send().await?;
+    if !response.status().is_success() {
+        return Err(IpfsError::RequestFailed(format!(
+            "Gateway returned {} for {}",
+            response.status(),
+            cid
+        )));
+    }
+
+    // Proofs pinned by the API key uploader before it sent objects hold the envelope as a
+    // JSON string
+    let value: serde_json::Value = response.json().await?;
+    let envelope: ActionOutput = match value {
+        serde_json::Value::String(json) => serde_json::from_str(&json)?,
+        other => serde_json::from_value(other)?,
+    };
+
+    Ok(serde_json::from_str(&envelope.data)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proof_management::http::stub::StubServer;
+    use zunnogame_script::ProofConversion;
+
+    fn proof() -> ProofOutput {
+        ProofOutput {
+            proof: "0xabcd".to_string(),
+            image_id: "0x11".to_string(),
+            pub_inputs: "0x22".to_string(),
+            conversion: ProofConversion::default(),
+        }
+    }
+
+    /// `ActionOutput` envelope around `proof()`, leaked for the stub's `'static` body
+    fn envelope(as_string: bool) -> &'static str {
+        let envelope = ActionOutput {
+            id: "game".to_string(),
+            timestamp: "2024-01-01T00:00:00Z".to_string(),
+            data: serde_json::to_string(&proof()).unwrap(),
+            ipfs_cid: None,
+        };
+        let mut body = serde_json::to_string(&envelope).unwrap();
+        if as_string {
+            body = serde_json::to_string(&body).unwrap();
+        }
+        Box::leak(body.into_boxed_str())
+    }
+
+    #[tokio::test]
+    async fn proofs_unwrap_from_object_and_string_envelopes() {
+        for as_string in [false, true] {
+            let gateway =
+                StubServer::routes(vec![("/bafy-game", "200 OK", envelope(as_string))]).await;
+
+            let fetched = fetch_proof_from(&gateway.url, "bafy-game").await.unwrap();
+
+            assert_eq!(fetched.proof, proof().proof);
+            assert_eq!(fetched.image_id, proof().image_id);
+            assert_eq!(fetched.pub_inputs, proof().pub_inputs);
+            assert_eq!(gateway.paths(), vec!["/bafy-game".to_string()]);
+        }
+    }
+
+    #[tokio::test]
+    async fn a_gateway_error_is_a_request_failure() {
+        let gateway = StubServer::routes(Vec::new()).await;
+
+        let err = fetch_proof_from(&gateway.url, "bafy-missing")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, IpfsError::RequestFailed(_)), "{}", err);
+    }
+}
@@ -0,0 +1,254 @@
+// backend/src/proof_management/http.rs
+
+use reqwest::Client;
+use std::env;
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::errors::{IpfsError, IpfsResult};
+
+const DEFAULT_HTTP_TIMEOUT_SECS: u64 = 30;
+const DEFAULT_HTTP_CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// Timeouts applied to every outbound HTTP request (zkVerify, Pinata)
+#[derive(Debug, Clone, Copy)]
+pub struct HttpTimeouts {
+    /// Whole-request timeout, including reading the response body
+    pub request: Duration,
+    /// Time allowed to establish the TCP/TLS connection
+    pub connect: Duration,
+}
+
+impl Default for HttpTimeouts {
+    fn default() -> Self {
+        HttpTimeouts {
+            request: Duration::from_secs(DEFAULT_HTTP_TIMEOUT_SECS),
+            connect: Duration::from_secs(DEFAULT_HTTP_CONNECT_TIMEOUT_SECS),
+        }
+    }
+}
+
+impl HttpTimeouts {
+    /// Read `HTTP_TIMEOUT_SECS` and `HTTP_CONNECT_TIMEOUT_SECS`, falling back to defaults
+    pub fn from_env() -> IpfsResult<Self> {
+        Ok(HttpTimeouts {
+            request: secs_var("HTTP_TIMEOUT_SECS", DEFAULT_HTTP_TIMEOUT_SECS)?,
+            connect: secs_var(
+                "HTTP_CONNECT_TIMEOUT_SECS",
+                DEFAULT_HTTP_CONNECT_TIMEOUT_SECS,
+            )?,
+        })
+    }
+}
+
+/// Build an HTTP client with the configured timeouts
+///
+/// All outbound `reqwest` clients should come from here so a hung provider
+/// can't stall a game indefinitely.
+pub fn build_http_client() -> IpfsResult<Client> {
+    http_client(HttpTimeouts::from_env()?)
+}
+
+fn http_client(timeouts: HttpTimeouts) -> IpfsResult<Client> {
+    Client::builder()
+        .timeout(timeouts.request)
+        .connect_timeout(timeouts.connect)
+        .build()
+        .map_err(|e| IpfsError::ConfigError(format!("Failed to build HTTP client: {}", e)))
+}
+
+pub(super) fn secs_var(key: &str, default: u64) -> IpfsResult<Duration> {
+    parsed_var(key, default).map(Duration::from_secs)
+}
+
+/// Parse an env var, returning `default` when it is unset or empty
+pub(super) fn parsed_var<T: FromStr>(key: &str, default: T) -> IpfsResult<T>
+where
+    T::Err: std::fmt::Display,
+{
+    parse_or(key, env::var(key).ok(), default)
+}
+
+/// Parse the value looked up for `key`, returning `default` when it is missing or empty
+pub(super) fn parse_or<T: FromStr>(key: &str, value: Option<String>, default: T) -> IpfsResult<T>
+where
+    T::Err: std::fmt::Display,
+{
+    match value {
+        Some(value) if !value.trim().is_empty() => value
+            .trim()
+            .parse()
+            .map_err(|e| IpfsError::ConfigError(format!("Invalid value for {}: {}", key, e))),
+        _ => Ok(default),
+    }
+}
+
+/// Hand-rolled HTTP server for tests that exercise real client behaviour
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    /// `(path fragment, status, body)`: answers requests whose path contains the fragment
+    pub(crate) type Route = (&'static str, &'static str, &'static str);
+
+    /// Request as the stub received it
+    #[derive(Debug, Clone, Default)]
+    pub(crate) struct StubRequest {
+        pub path: String,
+        /// Request line and headers, lowercased
+        pub headers: String,
+        pub body: String,
+    }
+
+    pub(crate) struct StubServer {
+        pub url: String,
+        hits: Arc<AtomicUsize>,
+        requests: Arc<Mutex<Vec<StubRequest>>>,
+    }
+
+    impl StubServer {
+        /// Answer every request with `status` and `body` after waiting `delay`
+        pub async fn start(delay: Duration, status: &'static str, body: &'static str) -> Self {
+            Self::serve(delay, vec![("", status, body)]).await
+        }
+
+        /// Answer each request from the first route matching its path, 404 if none does
+        pub async fn routes(routes: Vec<Route>) -> Self {
+            Self::serve(Duration::ZERO, routes).await
+        }
+
+        async fn serve(delay: Duration, routes: Vec<Route>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}/", listener.local_addr().unwrap());
+            let hits = Arc::new(AtomicUsize::new(0));
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let (counter, seen) = (hits.clone(), requests.clone());
+            let routes = Arc::new(routes);
+            tokio::spawn(async move {
+                while let Ok((mut socket, _)) = listener.accept().await {
+                    let (counter, seen, routes) = (counter.clone(), seen.clone(), routes.clone());
+                    tokio::spawn(async move {
+                        let request = read_request(&mut socket).await;
+                        counter.fetch_add(1, Ordering::SeqCst);
+                        let (_, status, body) = routes
+                            .iter()
+                            .find(|(fragment, _, _)| request.path.contains(fragment))
+                            .copied()
+                            .unwrap_or(("", "404 Not Found", "{}"));
+                        seen.lock().unwrap().push(request);
+                        tokio::time::sleep(delay).await;
+                        let response = format!(
+                            "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                            status,
+                            body.len(),
+                            body
+                        );
+                        let _ = socket.write_all(response.as_bytes()).await;
+                    });
+                }
+            });
+            StubServer {
+                url,
+                hits,
+                requests,
+            }
+        }
+
+        /// Requests received so far
+        pub fn hits(&self) -> usize {
+            self.hits.load(Ordering::SeqCst)
+        }
+
+        /// Paths requested so far, in arrival order
+        pub fn paths(&self) -> Vec<String> {
+            self.requests()
+                .into_iter()
+                .map(|request| request.path)
+                .collect()
+        }
+
+        /// Requests received so far, in arrival order
+        pub fn requests(&self) -> Vec<StubRequest> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    /// Consume the headers and the `content-length` body so the client isn't reset mid-send
+    ///
+    /// Returns an empty request if it couldn't be read.
+    async fn read_request(socket: &mut TcpStream) -> StubRequest {
+        let mut request = Vec::new();
+        let mut chunk = [0u8; 1024];
+        loop {
+            let Ok(read) = socket.read(&mut chunk).await else {
+                return StubRequest::default();
+            };
+            if read == 0 {
+                return StubRequest::default();
+            }
+            request.extend_from_slice(&chunk[..read]);
+
+            let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") else {
+                continue;
+            };
+            let headers = String::from_utf8_lossy(&request[..end]).to_ascii_lowercase();
+            let length = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .and_then(|value| value.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            if request.len() >= end + 4 + length {
+                let body = &request[end + 4..end + 4 + length];
+                return StubRequest {
+                    path: headers
+                        .split_whitespace()
+                        .nth(1)
+                        .unwrap_or_default()
+                        .to_string(),
+                    body: String::from_utf8_lossy(body).into_owned(),
+                    headers,
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stub::StubServer;
+    use super::*;
+
+    fn short_timeouts() -> HttpTimeouts {
+        HttpTimeouts {
+            request: Duration::from_millis(200),
+            connect: Duration::from_millis(200),
+        }
+    }
+
+    #[tokio::test]
+    async fn a_hung_server_times_out_instead_of_stalling() {
+        let server = StubServer::start(Duration::from_secs(30), "200 OK", "ok").await;
+        let client = http_client(short_timeouts()).unwrap();
+
+        let started = std::time::Instant::now();
+        let error = client.get(&server.url).send().await.unwrap_err();
+
+        assert!(error.is_timeout(), "{}", error);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn a_prompt_server_answers_within_the_timeout() {
+        let server = StubServer::start(Duration::ZERO, "200 OK", "ok").await;
+        let client = http_client(short_timeouts()).unwrap();
+
+        let response = client.get(&server.url).send().await.unwrap();
+
+        assert!(response.status().is_success());
+        assert_eq!(response.text().await.unwrap(), "ok");
+    }
+}
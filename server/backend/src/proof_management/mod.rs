@@ -1,5 +1,8 @@
 pub mod config;
+pub mod dead_letter;
 pub mod errors;
+pub mod gateway;
+pub mod http;
 pub mod proof_verification;
 pub mod retry_service;
 pub mod service;
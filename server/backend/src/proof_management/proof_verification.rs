@@ -1,43 +1,184 @@
-use anyhow::{anyhow, Error, Ok, Result};
-use reqwest::Client;
-use std::{env, thread, time::Duration};
+use anyhow::{anyhow, Result};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{env, time::Duration};
 use zunnogame_script::ProofOutput;
 
-pub async fn verify_proof(proof_result: ProofOutput) -> Result<String, anyhow::Error> {
+use super::http::build_http_client;
+
+/// Relayer `register-vk` response
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterVkResponse {
+    pub vk_hash: String,
+}
+
+/// Relayer `submit-proof` response
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitResponse {
+    pub job_id: String,
+    /// `success` when the relayer's optimistic verification accepted the proof
+    pub optimistic_verify: String,
+}
+
+/// Lifecycle of a relayer job, as reported in `job-status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JobStatus {
+    Queued,
+    Valid,
+    Submitted,
+    IncludedInBlock,
+    Finalized,
+    AggregationPending,
+    Aggregated,
+    Failed,
+    /// A status this client doesn't know yet; treated as still pending
+    #[serde(other)]
+    Unknown,
+}
+
+impl JobStatus {
+    /// The proof is final on zkVerify (aggregation may still follow)
+    pub fn is_final(self) -> bool {
+        matches!(
+            self,
+            JobStatus::Finalized | JobStatus::AggregationPending | JobStatus::Aggregated
+        )
+    }
+}
+
+/// Relayer `job-status` response
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobStatusResponse {
+    pub job_id: String,
+    pub status: JobStatus,
+    /// zkVerify transaction, once the proof is included in a block
+    #[serde(default)]
+    pub tx_hash: Option<String>,
+    #[serde(default)]
+    pub aggregation_id: Option<u64>,
+    /// Relayer's explanation when the job failed
+    #[serde(default)]
+    pub error_message: Option<String>,
+}
+
+/// Outcome of a zkVerify submission, taken from the final job status
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VerificationResult {
+    pub tx_hash: String,
+    pub job_id: String,
+    /// Final relayer job status (`Finalized`, `Aggregated` or `AggregationPending`)
+    pub status: String,
+    /// Aggregation the proof was included in, once aggregated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregation_id: Option<u64>,
+}
+
+impl VerificationResult {
+    /// Build from a final relayer `job-status` response, which must carry a transaction hash
+    pub fn from_job_status(job_status: &JobStatusResponse) -> Result<Self> {
+        let tx_hash = job_status.tx_hash.clone().ok_or_else(|| {
+            anyhow!(
+                "zkVerify job {} is {:?} but has no txHash",
+                job_status.job_id,
+                job_status.status
+            )
+        })?;
+
+        Ok(Self {
+            tx_hash,
+            job_id: job_status.job_id.clone(),
+            status: format!("{:?}", job_status.status),
+            aggregation_id: job_status.aggregation_id,
+        })
+    }
+}
+
+/// `register-vk` request body; `proofType` comes from the proof's conversion metadata
+fn register_vk_body(proof_result: &ProofOutput) -> serde_json::Value {
+    serde_json::json!({
+      "proofType": proof_result.conversion.proof_type,
+      "vk": proof_result.image_id,
+    })
+}
+
+/// `submit-proof` request body for a proof whose key is registered as `vk_hash`
+fn submit_proof_body(proof_result: &ProofOutput, vk_hash: &str) -> serde_json::Value {
+    serde_json::json!({
+        "proofType": proof_result.conversion.proof_type,
+        "vkRegistered": true,
+        "proofData": {
+            "proof": proof_result.proof,
+            "publicSignals": proof_result.pub_inputs,
+            "vk": vk_hash
+        }
+    })
+}
+
+/// Decode a relayer response body, quoting it in the error if it isn't the expected shape
+async fn relayer_json<T: DeserializeOwned>(
+    response: reqwest::Response,
+    endpoint: &str,
+) -> Result<T> {
+    let status = response.status();
+    let body = response.text().await?;
+
+    if !status.is_success() {
+        return Err(anyhow!(
+            "zkVerify {} returned {}: {}",
+            endpoint,
+            status,
+            body
+        ));
+    }
+
+    serde_json::from_str(&body).map_err(|e| {
+        anyhow!(
+            "Unexpected zkVerify {} response ({}): {}",
+            endpoint,
+            e,
+            body
+        )
+    })
+}
+
+pub async fn verify_proof(proof_result: ProofOutput) -> Result<VerificationResult> {
     /// Setting up the zkVerify Relayer API
     let zkv_api_base_url: String = env::var("ZKV_API_BASE_URL").unwrap();
     let relayer_api_key: String = env::var("RELAYER_API_KEY").unwrap();
 
-    let client = Client::new();
+    verify_with_relayer(&zkv_api_base_url, &relayer_api_key, proof_result).await
+}
 
-    let zkv_vk_submission = serde_json::json!({
-      "proofType": "sp1",
-      "vk": proof_result.image_id,
-    });
+/// Register the key, submit the proof and wait for the relayer job to finalize
+async fn verify_with_relayer(
+    zkv_api_base_url: &str,
+    relayer_api_key: &str,
+    proof_result: ProofOutput,
+) -> Result<VerificationResult> {
+    let client = build_http_client()?;
+
+    tracing::debug!(
+        proof_type = %proof_result.conversion.proof_type,
+        source_mode = %proof_result.conversion.source_mode,
+        encoding = %proof_result.conversion.encoding,
+        program_version = %proof_result.conversion.program_version,
+        "Submitting proof to zkVerify"
+    );
 
     let vk_reg_client_response = client
         .post(format!(
             "{}/register-vk/{}",
             zkv_api_base_url, relayer_api_key
         ))
-        .json(&zkv_vk_submission)
+        .json(&register_vk_body(&proof_result))
         .send()
         .await?;
 
-    let vk_reg_response: serde_json::Value = vk_reg_client_response.json().await?;
-
-    let vk_hash = vk_reg_response["vkHash"].as_str().unwrap();
-
-    /// proof submission payload for ZKV
-    let zkv_proof_submission = serde_json::json!({
-        "proofType": "sp1",
-        "vkRegistered": true,
-        "proofData": {
-            "proof": proof_result.proof,
-            "publicSignals": proof_result.pub_inputs,
-            "vk": vk_hash
-        }
-    });
+    let vk_reg_response: RegisterVkResponse =
+        relayer_json(vk_reg_client_response, "register-vk").await?;
+    let vk_hash = vk_reg_response.vk_hash;
 
     tracing::info!("Initiating submission to ZKV.");
 
@@ -46,46 +187,293 @@ pub async fn verify_proof(proof_result: ProofOutput) -> Result<String, anyhow::E
             "{}/submit-proof/{}",
             zkv_api_base_url, relayer_api_key
         ))
-        .json(&zkv_proof_submission)
+        .json(&submit_proof_body(&proof_result, &vk_hash))
         .send()
         .await?;
 
-    let submission_response: serde_json::Value = client_response.json().await?;
+    let submission_response: SubmitResponse = relayer_json(client_response, "submit-proof").await?;
 
-    if submission_response["optimisticVerify"] != "success" {
-        return Err(anyhow!("Proof submission for Verification failed !"));
+    if submission_response.optimistic_verify != "success" {
+        return Err(anyhow!(
+            "Proof submission for Verification failed (optimisticVerify: {})",
+            submission_response.optimistic_verify
+        ));
     }
 
-    let job_id = submission_response["jobId"].as_str().unwrap();
+    let job_id = submission_response.job_id.as_str();
     tracing::info!(
-        joib_id = job_id.to_string(),
+        job_id = job_id.to_string(),
         "Fetched proof submission job id."
     );
 
     loop {
-        let job_status = client
+        let response = client
             .get(format!(
                 "{}/job-status/{}/{}",
                 zkv_api_base_url, relayer_api_key, job_id
             ))
             .send()
-            .await?
-            .json::<serde_json::Value>()
             .await?;
+        let job_status: JobStatusResponse = relayer_json(response, "job-status").await?;
 
-        let status = job_status["status"].as_str().unwrap_or("Unknown");
-
-        if status == "Finalized" || status == "Aggregated" || status == "AggregationPending" {
-            println!("Job Finalized successfully");
-            println!("{:?}", job_status);
-            return Ok(job_status["txHash"]
-                .as_str()
-                .unwrap_or("Unknown")
-                .to_string());
+        if job_status.status.is_final() {
+            let result = VerificationResult::from_job_status(&job_status)?;
+            tracing::info!(
+                job_id = job_id,
+                status = ?job_status.status,
+                tx_hash = %result.tx_hash,
+                aggregation_id = ?result.aggregation_id,
+                "zkVerify job finalized"
+            );
+            return Ok(result);
+        } else if job_status.status == JobStatus::Failed {
+            return Err(anyhow!(
+                "zkVerify job {} failed: {}",
+                job_id,
+                job_status
+                    .error_message
+                    .as_deref()
+                    .unwrap_or("no reason given")
+            ));
         } else {
-            println!("Job status: {}", status);
-            println!("Waiting for job to finalized...");
-            thread::sleep(Duration::from_secs(5));
+            tracing::debug!(
+                job_id = job_id,
+                status = ?job_status.status,
+                "Waiting for zkVerify job to finalize"
+            );
+            tokio::time::sleep(Duration::from_secs(5)).await;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proof_management::http::stub::StubServer;
+    use zunnogame_script::{ProofConversion, ProofMode};
+
+    const REGISTERED: &str = r#"{"vkHash":"0xvkhash"}"#;
+    const ACCEPTED: &str = r#"{"jobId":"job-7","optimisticVerify":"success"}"#;
+
+    /// Run `verify_proof` against a relayer answering with `job_status`
+    async fn verify_against(
+        submitted: &'static str,
+        job_status: &'static str,
+    ) -> (StubServer, Result<VerificationResult>) {
+        let relayer = StubServer::routes(vec![
+            ("/register-vk/", "200 OK", REGISTERED),
+            ("/submit-proof/", "200 OK", submitted),
+            ("/job-status/", "200 OK", job_status),
+        ])
+        .await;
+
+        let result = verify_with_relayer(
+            relayer.url.trim_end_matches('/'),
+            "test-key",
+            proof(ProofConversion::default()),
+        )
+        .await;
+        (relayer, result)
+    }
+
+    #[tokio::test]
+    async fn a_finalized_job_fills_in_the_verification_result() {
+        let (relayer, result) = verify_against(
+            ACCEPTED,
+            r#"{"jobId":"job-7","status":"Aggregated","txHash":"0xabc","aggregationId":42}"#,
+        )
+        .await;
+
+        let result = result.unwrap();
+        assert_eq!(result.tx_hash, "0xabc");
+        assert_eq!(result.job_id, "job-7");
+        assert_eq!(result.status, "Aggregated");
+        assert_eq!(result.aggregation_id, Some(42));
+        assert_eq!(
+            relayer.paths(),
+            [
+                "/register-vk/test-key",
+                "/submit-proof/test-key",
+                "/job-status/test-key/job-7"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn a_failed_job_reports_the_relayer_reason() {
+        let (_, result) = verify_against(
+            ACCEPTED,
+            r#"{"jobId":"job-7","status":"Failed","errorMessage":"bad proof"}"#,
+        )
+        .await;
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("job-7 failed: bad proof"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn a_rejected_submission_never_polls_the_job() {
+        let (relayer, result) = verify_against(
+            r#"{"jobId":"job-7","optimisticVerify":"failed"}"#,
+            r#"{"jobId":"job-7","status":"Finalized","txHash":"0xabc"}"#,
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(relayer.hits(), 2);
+    }
+
+    #[test]
+    fn a_final_job_without_a_transaction_is_an_error() {
+        let job_status: JobStatusResponse =
+            serde_json::from_str(r#"{"jobId":"job-7","status":"Finalized"}"#).unwrap();
+
+        let error = VerificationResult::from_job_status(&job_status).unwrap_err();
+
+        assert!(error.to_string().contains("has no txHash"), "{}", error);
+    }
+
+    #[test]
+    fn finalized_pending_and_failed_job_bodies_deserialize() {
+        let finalized: JobStatusResponse = serde_json::from_str(
+            r#"{"jobId":"job-7","status":"Finalized","txHash":"0xabc","blockHash":"0xdef"}"#,
+        )
+        .unwrap();
+        assert_eq!(finalized.job_id, "job-7");
+        assert_eq!(finalized.status, JobStatus::Finalized);
+        assert_eq!(finalized.tx_hash.as_deref(), Some("0xabc"));
+        assert_eq!(finalized.aggregation_id, None);
+        assert!(finalized.status.is_final());
+
+        let pending: JobStatusResponse = serde_json::from_str(
+            r#"{"jobId":"job-7","status":"IncludedInBlock","txHash":"0xabc"}"#,
+        )
+        .unwrap();
+        assert_eq!(pending.status, JobStatus::IncludedInBlock);
+        assert!(!pending.status.is_final());
+
+        let failed: JobStatusResponse = serde_json::from_str(
+            r#"{"jobId":"job-7","status":"Failed","errorMessage":"invalid proof"}"#,
+        )
+        .unwrap();
+        assert_eq!(failed.status, JobStatus::Failed);
+        assert_eq!(failed.error_message.as_deref(), Some("invalid proof"));
+        assert_eq!(failed.tx_hash, None);
+    }
+
+    #[test]
+    fn statuses_this_client_does_not_know_stay_pending() {
+        let job_status: JobStatusResponse =
+            serde_json::from_str(r#"{"jobId":"job-7","status":"Teleported"}"#).unwrap();
+
+        assert_eq!(job_status.status, JobStatus::Unknown);
+        assert!(!job_status.status.is_final());
+    }
+
+    #[test]
+    fn missing_or_renamed_fields_are_named_in_the_error() {
+        let renamed =
+            serde_json::from_str::<JobStatusResponse>(r#"{"job_id":"job-7","status":"Finalized"}"#)
+                .unwrap_err();
+        assert!(renamed.to_string().contains("jobId"), "{}", renamed);
+
+        let submitted = serde_json::from_str::<SubmitResponse>(r#"{"jobId":"job-7"}"#).unwrap_err();
+        assert!(
+            submitted.to_string().contains("optimisticVerify"),
+            "{}",
+            submitted
+        );
+
+        let accepted: SubmitResponse = serde_json::from_str(ACCEPTED).unwrap();
+        assert_eq!(accepted.job_id, "job-7");
+        assert_eq!(accepted.optimistic_verify, "success");
+    }
+
+    #[tokio::test]
+    async fn an_unexpected_relayer_body_is_quoted_in_the_error() {
+        let relayer = StubServer::routes(vec![
+            ("/register-vk/", "200 OK", REGISTERED),
+            ("/submit-proof/", "200 OK", r#"{"error":"quota exceeded"}"#),
+        ])
+        .await;
+
+        let error = verify_with_relayer(
+            relayer.url.trim_end_matches('/'),
+            "test-key",
+            proof(ProofConversion::default()),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+
+        assert!(
+            error.contains("Unexpected zkVerify submit-proof response"),
+            "{}",
+            error
+        );
+        assert!(error.contains("quota exceeded"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn a_relayer_error_status_is_reported_with_its_body() {
+        let relayer = StubServer::routes(vec![(
+            "/register-vk/",
+            "400 Bad Request",
+            r#"{"message":"unsupported proof type"}"#,
+        )])
+        .await;
+
+        let error = verify_with_relayer(
+            relayer.url.trim_end_matches('/'),
+            "test-key",
+            proof(ProofConversion::default()),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+
+        assert!(
+            error.contains("zkVerify register-vk returned 400"),
+            "{}",
+            error
+        );
+        assert!(error.contains("unsupported proof type"), "{}", error);
+        assert_eq!(relayer.hits(), 1);
+    }
+
+    fn proof(conversion: ProofConversion) -> ProofOutput {
+        ProofOutput {
+            proof: "0xproof".to_string(),
+            image_id: "0ximage".to_string(),
+            pub_inputs: "0xinputs".to_string(),
+            conversion,
+        }
+    }
+
+    #[test]
+    fn relayer_payloads_take_the_proof_type_from_the_conversion() {
+        let compressed = proof(ProofConversion::new(ProofMode::Compressed));
+
+        let register = register_vk_body(&compressed);
+        let submit = submit_proof_body(&compressed, "0xvkhash");
+
+        assert_eq!(register["proofType"], "sp1");
+        assert_eq!(register["vk"], "0ximage");
+        assert_eq!(submit["proofType"], "sp1");
+        assert_eq!(submit["vkRegistered"], true);
+        assert_eq!(submit["proofData"]["proof"], "0xproof");
+        assert_eq!(submit["proofData"]["publicSignals"], "0xinputs");
+        assert_eq!(submit["proofData"]["vk"], "0xvkhash");
+    }
+
+    #[test]
+    fn a_different_proof_type_reaches_both_payloads() {
+        let other = proof(ProofConversion {
+            proof_type: "risc0".to_string(),
+            ..ProofConversion::default()
+        });
+
+        assert_eq!(register_vk_body(&other)["proofType"], "risc0");
+        assert_eq!(submit_proof_body(&other, "0xvkhash")["proofType"], "risc0");
+    }
+}
@@ -1,56 +1,187 @@
 // backend/src/proof_management/retry_service.rs
 
+use std::env;
 use std::time::Duration;
 
 use super::config::IpfsProvider;
 use super::errors::{IpfsError, IpfsResult};
+use super::http::parse_or;
 use super::service::IpfsUploader;
 
+const MAX_RETRIES_VAR: &str = "IPFS_MAX_RETRIES";
+const RETRY_DELAY_VAR: &str = "IPFS_RETRY_DELAY_SECS";
+const DEFAULT_IPFS_MAX_RETRIES: u32 = 3;
+const DEFAULT_IPFS_RETRY_DELAY_SECS: u64 = 2;
+/// Ceiling for the doubled retry delay
+const MAX_IPFS_RETRY_DELAY: Duration = Duration::from_secs(60);
+
 #[derive(Clone)]
 pub struct IpfsUploadConfig {
     pub max_retries: u32,
+    /// Delay before the first retry, doubled on every further attempt
     pub retry_delay: Duration,
 }
 
 impl Default for IpfsUploadConfig {
     fn default() -> Self {
         IpfsUploadConfig {
-            max_retries: 3,
-            retry_delay: Duration::from_secs(2),
+            max_retries: DEFAULT_IPFS_MAX_RETRIES,
+            retry_delay: Duration::from_secs(DEFAULT_IPFS_RETRY_DELAY_SECS),
         }
     }
 }
 
+impl IpfsUploadConfig {
+    /// Read `IPFS_MAX_RETRIES` and `IPFS_RETRY_DELAY_SECS`, falling back to defaults
+    pub fn from_env() -> IpfsResult<Self> {
+        Self::from_vars(|key| env::var(key).ok())
+    }
+
+    /// `from_env` over an arbitrary variable lookup
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> IpfsResult<Self> {
+        let retry_delay_secs = parse_or(
+            RETRY_DELAY_VAR,
+            var(RETRY_DELAY_VAR),
+            DEFAULT_IPFS_RETRY_DELAY_SECS,
+        )?;
+        Ok(IpfsUploadConfig {
+            max_retries: parse_or(
+                MAX_RETRIES_VAR,
+                var(MAX_RETRIES_VAR),
+                DEFAULT_IPFS_MAX_RETRIES,
+            )?,
+            retry_delay: Duration::from_secs(retry_delay_secs),
+        })
+    }
+
+    /// Backoff before the given retry attempt (1-based), capped at a minute
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.retry_delay
+            .checked_mul(factor)
+            .unwrap_or(MAX_IPFS_RETRY_DELAY)
+            .min(MAX_IPFS_RETRY_DELAY)
+    }
+}
+
+/// Outcome of pinning to every configured provider
+#[derive(Debug, Clone)]
+pub struct PinReceipt {
+    /// Content-addressed CID, identical across providers
+    pub cid: String,
+    /// Providers that pinned the content
+    pub pinned_by: Vec<String>,
+    /// Providers that failed, with their last error
+    pub failed: Vec<(String, String)>,
+}
+
 pub struct IpfsService {
-    uploader: IpfsUploader,
+    uploaders: Vec<IpfsUploader>,
     config: IpfsUploadConfig,
 }
 
 impl IpfsService {
-    pub fn new(provider: IpfsProvider, config: IpfsUploadConfig) -> Self {
-        IpfsService {
-            uploader: IpfsUploader::new(provider),
-            config,
+    pub fn new(provider: IpfsProvider, config: IpfsUploadConfig) -> IpfsResult<Self> {
+        Self::with_providers(vec![provider], config)
+    }
+
+    /// Service that pins to every given provider for redundancy
+    pub fn with_providers(
+        providers: Vec<IpfsProvider>,
+        config: IpfsUploadConfig,
+    ) -> IpfsResult<Self> {
+        if providers.is_empty() {
+            return Err(IpfsError::ConfigError(
+                "At least one IPFS provider is required".to_string(),
+            ));
         }
+
+        Ok(IpfsService {
+            uploaders: providers
+                .into_iter()
+                .map(IpfsUploader::new)
+                .collect::<IpfsResult<_>>()?,
+            config,
+        })
     }
 
-    /// Upload with automatic retry on failure
+    /// Upload to the first provider with automatic retry on failure
     pub async fn upload_with_retry<T: serde::Serialize>(&self, data: &T) -> IpfsResult<String> {
+        self.upload_to(&self.uploaders[0], data).await
+    }
+
+    /// Upload to every provider; succeeds if at least one of them pins the content
+    ///
+    /// The first CID returned is the proof's; a provider answering with another one counts
+    /// as failed, since its pin isn't a copy of the stored proof.
+    pub async fn upload_to_all<T: serde::Serialize>(&self, data: &T) -> IpfsResult<PinReceipt> {
+        let mut cid: Option<String> = None;
+        let mut pinned_by = Vec::new();
+        let mut failed = Vec::new();
+
+        for uploader in &self.uploaders {
+            match self.upload_to(uploader, data).await {
+                Ok(provider_cid) => match &cid {
+                    Some(cid) if *cid != provider_cid => {
+                        tracing::warn!(
+                            provider = %uploader.name,
+                            expected = %cid,
+                            got = %provider_cid,
+                            "Provider returned a different CID"
+                        );
+                        failed.push((
+                            uploader.name.clone(),
+                            format!("pinned under {} instead of {}", provider_cid, cid),
+                        ));
+                    }
+                    _ => {
+                        cid.get_or_insert(provider_cid);
+                        pinned_by.push(uploader.name.clone());
+                    }
+                },
+                Err(e) => failed.push((uploader.name.clone(), e.to_string())),
+            }
+        }
+
+        match cid {
+            Some(cid) => Ok(PinReceipt {
+                cid,
+                pinned_by,
+                failed,
+            }),
+            None => Err(IpfsError::UploadFailed(format!(
+                "All {} providers failed: {}",
+                failed.len(),
+                failed
+                    .iter()
+                    .map(|(name, e)| format!("{}: {}", name, e))
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ))),
+        }
+    }
+
+    async fn upload_to<T: serde::Serialize>(
+        &self,
+        uploader: &IpfsUploader,
+        data: &T,
+    ) -> IpfsResult<String> {
         let mut attempts = 0;
 
         loop {
-            match self.uploader.upload_json(data).await {
+            match uploader.upload_json(data).await {
                 Ok(cid) => {
                     println!("✓ Upload successful. CID: {}", cid);
                     return Ok(cid);
                 }
                 Err(e) if attempts < self.config.max_retries => {
                     attempts += 1;
+                    let delay = self.config.delay_for(attempts);
                     eprintln!(
                         "⚠ Upload attempt {} failed: {}. Retrying in {:?}...",
-                        attempts, e, self.config.retry_delay
+                        attempts, e, delay
                     );
-                    tokio::time::sleep(self.config.retry_delay).await;
+                    tokio::time::sleep(delay).await;
                 }
                 Err(e) => {
                     eprintln!("✗ Upload failed after {} attempts: {}", attempts + 1, e);
@@ -60,3 +191,161 @@ impl IpfsService {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proof_management::config::PinataAuth;
+    use crate::proof_management::http::stub::StubServer;
+
+    const PINNED: &str = r#"{"IpfsHash":"QmSameContent","PinSize":42}"#;
+
+    fn uploader(name: &str, server: &StubServer) -> IpfsUploader {
+        let provider = IpfsProvider {
+            name: name.to_string(),
+            auth: PinataAuth::Jwt("test-jwt".to_string()),
+        };
+        IpfsUploader::with_endpoint(provider, &server.url).unwrap()
+    }
+
+    fn service(uploaders: Vec<IpfsUploader>, max_retries: u32) -> IpfsService {
+        IpfsService {
+            uploaders,
+            config: IpfsUploadConfig {
+                max_retries,
+                retry_delay: Duration::from_millis(1),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn one_failing_provider_still_stores_the_proof() {
+        let healthy = StubServer::start(Duration::ZERO, "200 OK", PINNED).await;
+        let broken = StubServer::start(Duration::ZERO, "500 Internal Server Error", "{}").await;
+        let ipfs = service(
+            vec![uploader("primary", &broken), uploader("backup", &healthy)],
+            1,
+        );
+
+        let receipt = ipfs
+            .upload_to_all(&serde_json::json!({"game": 1}))
+            .await
+            .unwrap();
+
+        assert_eq!(receipt.cid, "QmSameContent");
+        assert_eq!(receipt.pinned_by, ["backup"]);
+        assert_eq!(receipt.failed.len(), 1);
+        assert_eq!(receipt.failed[0].0, "primary");
+        assert!(
+            receipt.failed[0].1.contains("500"),
+            "{}",
+            receipt.failed[0].1
+        );
+        // The failing provider was retried before being given up on
+        assert_eq!(broken.hits(), 2);
+        assert_eq!(healthy.hits(), 1);
+    }
+
+    #[tokio::test]
+    async fn every_provider_pinning_is_recorded() {
+        let first = StubServer::start(Duration::ZERO, "200 OK", PINNED).await;
+        let second = StubServer::start(Duration::ZERO, "200 OK", PINNED).await;
+        let ipfs = service(vec![uploader("a", &first), uploader("b", &second)], 0);
+
+        let receipt = ipfs
+            .upload_to_all(&serde_json::json!({"game": 1}))
+            .await
+            .unwrap();
+
+        assert_eq!(receipt.cid, "QmSameContent");
+        assert_eq!(receipt.pinned_by, ["a", "b"]);
+        assert!(receipt.failed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_provider_pinning_other_content_is_not_counted() {
+        let first = StubServer::start(Duration::ZERO, "200 OK", PINNED).await;
+        let other = StubServer::start(
+            Duration::ZERO,
+            "200 OK",
+            r#"{"IpfsHash":"QmOtherContent","PinSize":42}"#,
+        )
+        .await;
+        let ipfs = service(vec![uploader("a", &first), uploader("b", &other)], 0);
+
+        let receipt = ipfs
+            .upload_to_all(&serde_json::json!({"game": 1}))
+            .await
+            .unwrap();
+
+        assert_eq!(receipt.cid, "QmSameContent");
+        assert_eq!(receipt.pinned_by, ["a"]);
+        assert_eq!(receipt.failed.len(), 1);
+        assert_eq!(receipt.failed[0].0, "b");
+        assert!(
+            receipt.failed[0].1.contains("QmOtherContent"),
+            "{}",
+            receipt.failed[0].1
+        );
+    }
+
+    #[tokio::test]
+    async fn the_upload_fails_only_when_every_provider_does() {
+        let broken = StubServer::start(Duration::ZERO, "503 Service Unavailable", "{}").await;
+        let also_broken = StubServer::start(Duration::ZERO, "401 Unauthorized", "{}").await;
+        let ipfs = service(vec![uploader("a", &broken), uploader("b", &also_broken)], 0);
+
+        let error = ipfs
+            .upload_to_all(&serde_json::json!({"game": 1}))
+            .await
+            .unwrap_err();
+
+        let message = error.to_string();
+        assert!(message.contains("All 2 providers failed"), "{}", message);
+        assert!(
+            message.contains("a: ") && message.contains("b: "),
+            "{}",
+            message
+        );
+    }
+
+    #[test]
+    fn retry_delays_double_up_to_a_minute() {
+        let config = IpfsUploadConfig {
+            max_retries: 10,
+            retry_delay: Duration::from_secs(2),
+        };
+
+        let schedule: Vec<u64> = (1..=8)
+            .map(|attempt| config.delay_for(attempt).as_secs())
+            .collect();
+
+        assert_eq!(schedule, [2, 4, 8, 16, 32, 60, 60, 60]);
+        assert_eq!(config.delay_for(u32::MAX), MAX_IPFS_RETRY_DELAY);
+    }
+
+    #[test]
+    fn env_values_override_the_upload_defaults() {
+        let config = |pairs: &[(&str, &str)]| {
+            IpfsUploadConfig::from_vars(|key| {
+                pairs
+                    .iter()
+                    .find(|(name, _)| *name == key)
+                    .map(|(_, value)| value.to_string())
+            })
+        };
+
+        let tuned = config(&[("IPFS_MAX_RETRIES", "5"), ("IPFS_RETRY_DELAY_SECS", "7")]).unwrap();
+        let invalid = config(&[("IPFS_MAX_RETRIES", "many")]);
+        let defaults = config(&[("IPFS_RETRY_DELAY_SECS", " ")]).unwrap();
+
+        assert_eq!(tuned.max_retries, 5);
+        assert_eq!(tuned.retry_delay, Duration::from_secs(7));
+        assert!(invalid.is_err());
+        assert_eq!(defaults.max_retries, DEFAULT_IPFS_MAX_RETRIES);
+        assert_eq!(
+            defaults.retry_delay,
+            Duration::from_secs(DEFAULT_IPFS_RETRY_DELAY_SECS)
+        );
+    }
+}
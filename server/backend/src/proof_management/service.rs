@@ -1,14 +1,13 @@
 // backend/src/proof_management/service.rs
 
-use pinata_sdk::{
-    // ApiError,
-    PinByJson,
-    PinataApi,
-};
+use reqwest::{Client, RequestBuilder};
 use serde::Deserialize;
 
-use super::config::IpfsProvider;
+use super::config::{IpfsProvider, PinataAuth};
 use super::errors::{IpfsError, IpfsResult};
+use super::http::build_http_client;
+
+const PINATA_PIN_JSON_URL: &str = "https://api.pinata.cloud/pinning/pinJSONToIPFS";
 
 #[derive(Deserialize, Debug)]
 pub struct PinataUploadResponse {
@@ -19,29 +18,133 @@ pub struct PinataUploadResponse {
 }
 
 pub struct IpfsUploader {
-    pub api: PinataApi,
+    pub name: String,
+    pub auth: PinataAuth,
+    client: Client,
+    /// Pinata's pin-JSON endpoint; only tests point it elsewhere
+    endpoint: String,
 }
 
 impl IpfsUploader {
-    pub fn new(provider: IpfsProvider) -> Self {
-        let api = provider.pinata_api;
-        IpfsUploader { api }
+    pub fn new(provider: IpfsProvider) -> IpfsResult<Self> {
+        Ok(IpfsUploader {
+            name: provider.name,
+            auth: provider.auth,
+            client: build_http_client()?,
+            endpoint: PINATA_PIN_JSON_URL.to_string(),
+        })
+    }
+
+    /// Uploader that pins through `endpoint` instead of Pinata
+    #[cfg(test)]
+    pub(crate) fn with_endpoint(provider: IpfsProvider, endpoint: &str) -> IpfsResult<Self> {
+        Ok(IpfsUploader {
+            endpoint: endpoint.to_string(),
+            ..Self::new(provider)?
+        })
     }
 
     /// Upload JSON data to IPFS and return the CID
+    ///
+    /// Both auth modes send the same body through the timeout-bounded client, so they pin
+    /// identical content under the same CID.
     pub async fn upload_json<T: serde::Serialize>(&self, data: &T) -> IpfsResult<String> {
-        let json_data = serde_json::to_string_pretty(data)?;
-
-        match self.api.pin_json(PinByJson::new(json_data)).await {
-            Ok(pinned_object) => {
-                return Ok(pinned_object.ipfs_hash);
-            }
-            Err(e) => {
-                return Err(IpfsError::UploadFailed(format!(
-                    "Pinata upload failed: {}",
-                    e
-                )));
-            }
+        let content = serde_json::to_value(data)?;
+
+        let response = self
+            .authorize(self.client.post(&self.endpoint))
+            .json(&serde_json::json!({ "pinataContent": content }))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(IpfsError::UploadFailed(format!(
+                "Pinata upload failed ({}): {}",
+                status, body
+            )));
+        }
+
+        let pinned: PinataUploadResponse = response.json().await?;
+        Ok(pinned.ipfs_hash)
+    }
+
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.auth {
+            PinataAuth::Jwt(jwt) => request.bearer_auth(jwt),
+            PinataAuth::ApiKey { key, secret } => request
+                .header("pinata_api_key", key)
+                .header("pinata_secret_api_key", secret),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proof_management::http::stub::StubServer;
+    use std::time::Duration;
+
+    const PINNED: &str = r#"{"IpfsHash":"QmSameContent","PinSize":42}"#;
+
+    fn uploader(auth: PinataAuth, server: &StubServer) -> IpfsUploader {
+        let provider = IpfsProvider {
+            name: "pinata".to_string(),
+            auth,
+        };
+        IpfsUploader::with_endpoint(provider, &server.url).unwrap()
+    }
+
+    #[tokio::test]
+    async fn both_auth_modes_pin_the_same_body() {
+        let server = StubServer::start(Duration::ZERO, "200 OK", PINNED).await;
+        let data = serde_json::json!({ "id": "game", "data": "{\"proof\":\"0xab\"}" });
+
+        let jwt = uploader(PinataAuth::Jwt("test-jwt".to_string()), &server);
+        let api_key = uploader(
+            PinataAuth::ApiKey {
+                key: "test-key".to_string(),
+                secret: "test-secret".to_string(),
+            },
+            &server,
+        );
+        assert_eq!(jwt.upload_json(&data).await.unwrap(), "QmSameContent");
+        assert_eq!(api_key.upload_json(&data).await.unwrap(), "QmSameContent");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].body, requests[1].body);
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(body["pinataContent"], data);
+
+        assert!(requests[0]
+            .headers
+            .contains("authorization: bearer test-jwt"));
+        assert!(requests[1].headers.contains("pinata_api_key: test-key"));
+        assert!(requests[1]
+            .headers
+            .contains("pinata_secret_api_key: test-secret"));
+        assert!(!requests[1].headers.contains("authorization:"));
+    }
+
+    #[tokio::test]
+    async fn a_rejected_pin_reports_the_status() {
+        let server = StubServer::start(Duration::ZERO, "401 Unauthorized", "{}").await;
+        let api_key = uploader(
+            PinataAuth::ApiKey {
+                key: "bad-key".to_string(),
+                secret: "bad-secret".to_string(),
+            },
+            &server,
+        );
+
+        let error = api_key
+            .upload_json(&serde_json::json!({ "game": 1 }))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, IpfsError::UploadFailed(_)), "{}", error);
+        assert!(error.to_string().contains("401"), "{}", error);
+    }
+}
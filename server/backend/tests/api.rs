@@ -0,0 +1,1905 @@
+// backend/tests/api.rs
+
+//! End-to-end tests of the API router with the chain, prover and proof sink mocked out
+
+use alloy::{
+    primitives::{hex, Address, B256, U256},
+    providers::ProviderBuilder,
+    rpc::{
+        client::RpcClient,
+        json_rpc::{ErrorPayload, RequestPacket, Response, ResponsePacket, ResponsePayload},
+    },
+    transports::{TransportError, TransportFut},
+};
+use alloy_sol_types::{SolEvent, SolType};
+use anyhow::Result;
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    Router,
+};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tower::ServiceExt;
+use url::Url;
+use zunnogame_backend::{
+    api::build_router,
+    blockchain::{adapter::WsProvider, vrf::RequestFulfilled, BlockchainAdapter, VrfMode},
+    game::{GameOutcome, GameState},
+    orchestrator::{
+        ActionOutput, BoxFuture, GameOrchestrator, OrchestratorConfig, ProofSink, Prover,
+    },
+    proof_management::{proof_verification::VerificationResult, retry_service::PinReceipt},
+};
+use zunnogame_lib::{
+    build_public_values, deal_game, deal_game_salted, derive_game_seed, derive_shuffle_seed,
+    perform_shuffle, PublicValuesStruct,
+};
+use zunnogame_script::{ProofConversion, ProofGenerator, ProofInput, ProofManifest, ProofOutput};
+
+const CONTRACT: Address = Address::repeat_byte(0x42);
+const IMAGE_ID: &str = "0x1111111111111111111111111111111111111111111111111111111111111111";
+const REQUEST_ID: u64 = 7;
+
+/// JSON-RPC node answering each method with a fixed result
+#[derive(Clone, Default)]
+struct MockRpc {
+    results: Arc<Mutex<HashMap<String, Value>>>,
+}
+
+impl MockRpc {
+    /// Chain 1 at block 16, where VRF request `REQUEST_ID` is already fulfilled
+    fn fulfilled(random_word: U256) -> Self {
+        let rpc = Self::default();
+        rpc.set("eth_chainId", json!("0x1"));
+        rpc.set("eth_blockNumber", json!("0x10"));
+        rpc.set("eth_call", json!(format!("0x{:064x}", REQUEST_ID)));
+        rpc.set(
+            "eth_getLogs",
+            json!([fulfilled_log(U256::from(REQUEST_ID), random_word)]),
+        );
+        rpc
+    }
+
+    fn set(&self, method: &str, result: Value) {
+        self.results
+            .lock()
+            .unwrap()
+            .insert(method.to_string(), result);
+    }
+
+    fn provider(&self) -> WsProvider {
+        ProviderBuilder::new().connect_client(RpcClient::new(self.clone(), true))
+    }
+
+    fn answer(&self, request: &alloy::rpc::json_rpc::SerializedRequest) -> Response {
+        let payload = match self.results.lock().unwrap().get(request.method()) {
+            Some(result) => {
+                ResponsePayload::Success(serde_json::value::to_raw_value(result).unwrap())
+            }
+            None => ResponsePayload::Failure(ErrorPayload {
+                code: -32601,
+                message: format!("method {} not mocked", request.method()).into(),
+                data: None,
+            }),
+        };
+        Response {
+            id: request.id().clone(),
+            payload,
+        }
+    }
+}
+
+impl tower::Service<RequestPacket> for MockRpc {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let response = match request {
+            RequestPacket::Single(request) => ResponsePacket::Single(self.answer(&request)),
+            RequestPacket::Batch(requests) => {
+                ResponsePacket::Batch(requests.iter().map(|r| self.answer(r)).collect())
+            }
+        };
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+fn fulfilled_log(request_id: U256, random_word: U256) -> Value {
+    let log = alloy::rpc::types::Log {
+        inner: alloy::primitives::Log {
+            address: CONTRACT,
+            data: RequestFulfilled {
+                requestId: request_id,
+                randomWord: random_word,
+            }
+            .encode_log_data(),
+        },
+        block_number: Some(0x10),
+        ..Default::default()
+    };
+    serde_json::to_value(log).unwrap()
+}
+
+/// Hex ABI-encoded public values the program commits for `input`
+fn committed_public_values(input: &ProofInput) -> String {
+    let outcome = perform_shuffle(
+        input.num_players,
+        input.cards_per_player,
+        derive_shuffle_seed(input.seed, input.instance_salt),
+    )
+    .unwrap();
+    let public_values = build_public_values(
+        input.num_players,
+        input.cards_per_player,
+        input.seed,
+        input.instance_salt,
+        &outcome,
+        input.salted_hands,
+        input.build_merkle,
+    );
+    hex::encode_prefixed(PublicValuesStruct::abi_encode(&public_values))
+}
+
+/// Prover that answers instantly with a placeholder proof and the real public values
+#[derive(Default)]
+struct MockProver {
+    proofs: AtomicUsize,
+    manifests: AtomicUsize,
+    /// Health probes fail while set
+    unhealthy: AtomicBool,
+}
+
+impl Prover for MockProver {
+    fn generate_proof(&self, input: ProofInput) -> Result<ProofOutput> {
+        self.proofs.fetch_add(1, Ordering::SeqCst);
+        Ok(ProofOutput {
+            proof: format!("0x{}", "ab".repeat(2048)),
+            image_id: IMAGE_ID.to_string(),
+            pub_inputs: committed_public_values(&input),
+            conversion: ProofConversion::default(),
+        })
+    }
+
+    fn estimate_cycles(&self, input: ProofInput) -> Result<u64> {
+        Ok(1_000_000 * input.num_players as u64)
+    }
+
+    fn health_check(&self) -> bool {
+        !self.unhealthy.load(Ordering::SeqCst)
+    }
+
+    fn manifest(&self) -> ProofManifest {
+        self.manifests.fetch_add(1, Ordering::SeqCst);
+        ProofManifest {
+            image_id: IMAGE_ID.to_string(),
+            program_version: "test".to_string(),
+            commitment_version: "test".to_string(),
+            hash_algorithm: "sha256".to_string(),
+        }
+    }
+
+    fn export_vk(&self) -> Result<Vec<u8>> {
+        Ok(vec![0x11; 32])
+    }
+}
+
+/// Sink that accepts every proof and pins it under a CID derived from the session
+#[derive(Default)]
+struct MockSink {
+    /// Uploads still to fail before the sink starts pinning
+    upload_failures: AtomicUsize,
+    uploads: Mutex<Vec<String>>,
+    /// Proof JSON of every upload, by CID
+    pinned: Mutex<HashMap<String, String>>,
+}
+
+impl ProofSink for MockSink {
+    fn verify(&self, _proof: ProofOutput) -> BoxFuture<'_, Result<VerificationResult>> {
+        Box::pin(async {
+            Ok(VerificationResult {
+                tx_hash: "0xfeed".to_string(),
+                job_id: "job-1".to_string(),
+                status: "Finalized".to_string(),
+                aggregation_id: None,
+            })
+        })
+    }
+
+    fn upload(&self, output: ActionOutput) -> BoxFuture<'_, Result<PinReceipt>> {
+        Box::pin(async move {
+            let failing = self
+                .upload_failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| {
+                    left.checked_sub(1)
+                })
+                .is_ok();
+            if failing {
+                anyhow::bail!("mock upload failure");
+            }
+
+            let cid = format!("bafy-{}", output.id);
+            self.uploads.lock().unwrap().push(output.id.clone());
+            self.pinned.lock().unwrap().insert(cid.clone(), output.data);
+            Ok(PinReceipt {
+                cid,
+                pinned_by: vec!["mock".to_string()],
+                failed: Vec::new(),
+            })
+        })
+    }
+
+    fn fetch<'a>(&'a self, cid: &'a str) -> BoxFuture<'a, Result<ProofOutput>> {
+        Box::pin(async move {
+            let data = self.pinned.lock().unwrap().get(cid).cloned();
+            let data = data.ok_or_else(|| anyhow::anyhow!("nothing pinned under {}", cid))?;
+            Ok(serde_json::from_str(&data)?)
+        })
+    }
+}
+
+/// Router over an orchestrator whose chain, prover and sink are all mocks
+struct TestServer {
+    router: Router,
+    orchestrator: Arc<GameOrchestrator>,
+    prover: Arc<MockProver>,
+    sink: Arc<MockSink>,
+}
+
+/// Polling-mode adapter whose HTTP and WebSocket providers both talk to `rpc`
+fn mock_chain(rpc: &MockRpc) -> BlockchainAdapter {
+    BlockchainAdapter::with_providers(
+        rpc.provider(),
+        rpc.provider(),
+        "ws://mock".to_string(),
+        Url::parse("http://mock").unwrap(),
+        CONTRACT,
+        VrfMode::Polling,
+        true,
+    )
+}
+
+impl TestServer {
+    fn new(rpc: MockRpc, config: OrchestratorConfig) -> Self {
+        Self::with_sink(rpc, config, MockSink::default())
+    }
+
+    fn with_sink(rpc: MockRpc, config: OrchestratorConfig, sink: MockSink) -> Self {
+        let blockchain = mock_chain(&rpc);
+        let prover = Arc::new(MockProver::default());
+        let sink = Arc::new(sink);
+        let orchestrator = Arc::new(GameOrchestrator::with_services(
+            blockchain,
+            config,
+            prover.clone(),
+            sink.clone(),
+        ));
+        orchestrator.clone().start_background_tasks();
+
+        Self {
+            router: build_router(orchestrator.clone()),
+            orchestrator,
+            prover,
+            sink,
+        }
+    }
+
+    async fn send(&self, request: Request<Body>) -> (StatusCode, Value) {
+        let response = self.router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = match body.is_empty() {
+            true => Value::Null,
+            false => serde_json::from_slice(&body).unwrap_or(Value::Null),
+        };
+        (status, body)
+    }
+
+    async fn get(&self, uri: &str) -> (StatusCode, Value) {
+        self.send(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+    }
+
+    /// GET returning the body as sent, for checking its layout
+    async fn get_text(&self, uri: &str) -> (StatusCode, String) {
+        let request = Request::get(uri).body(Body::empty()).unwrap();
+        let response = self.router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    /// GET with a seat token in `x-player-token`
+    async fn get_as(&self, uri: &str, token: &str) -> (StatusCode, Value) {
+        self.send(
+            Request::get(uri)
+                .header("x-player-token", token)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+    }
+
+    /// GET with the admin token in `x-admin-token`
+    async fn get_admin(&self, uri: &str, token: &str) -> (StatusCode, Value) {
+        self.send(
+            Request::get(uri)
+                .header("x-admin-token", token)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+    }
+
+    async fn post(&self, uri: &str, body: Value) -> (StatusCode, Value) {
+        self.send(
+            Request::post(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+    }
+
+    /// Start an OS-seeded game, which is dealt at once; returns its session id and seat tokens
+    async fn start_os_game(&self, request: Value) -> (String, Vec<String>) {
+        let mut request = request;
+        request["seed_source"] = json!("os");
+        let (status, started) = self.post("/api/game/start", request).await;
+        assert_eq!(status, StatusCode::OK, "{}", started);
+
+        let tokens = started["player_tokens"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|token| token.as_str().unwrap().to_string())
+            .collect();
+        (started["session_id"].as_str().unwrap().to_string(), tokens)
+    }
+
+    /// Poll the status endpoint until the game reports `status`, letting background tasks run
+    async fn wait_for_status(&self, session_id: &str, status: &str) -> Value {
+        for _ in 0..120 {
+            let (_, body) = self.get(&format!("/api/game/{}/status", session_id)).await;
+            if body["status"] == status {
+                return body;
+            }
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+        panic!("game {} never reached {}", session_id, status);
+    }
+}
+
+fn random_word() -> U256 {
+    U256::from_be_bytes([0x5a; 32])
+}
+
+#[tokio::test(start_paused = true)]
+async fn vrf_game_goes_from_start_to_proof() {
+    let server = TestServer::new(
+        MockRpc::fulfilled(random_word()),
+        OrchestratorConfig::default(),
+    );
+
+    let (status, started) = server
+        .post(
+            "/api/game/start",
+            json!({ "num_players": 4, "cards_per_player": 7 }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", started);
+    assert_eq!(started["status"], "Requesting");
+    let session_id = started["session_id"].as_str().unwrap().to_string();
+
+    let ready = server.wait_for_status(&session_id, "Ready").await;
+    assert_eq!(ready["vrf_request_id"], json!(format!("{:#x}", REQUEST_ID)));
+    assert_eq!(ready["verifiable"], true);
+
+    let (status, state) = server.get(&format!("/api/game/{}", session_id)).await;
+    assert_eq!(status, StatusCode::OK);
+    let hands = state["game_state"]["player_hands"].as_array().unwrap();
+    assert_eq!(hands.len(), 4);
+    assert!(hands.iter().all(|hand| hand.as_array().unwrap().len() == 7));
+    assert_eq!(
+        state["game_state"]["draw_pile"].as_array().unwrap().len(),
+        108 - 4 * 7
+    );
+    assert_eq!(
+        state["game_state"]["seed_used"],
+        json!(format!("{:#x}", random_word()))
+    );
+
+    let (status, proof) = server.get(&format!("/api/game/{}/proof", session_id)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(proof["proof_cid"], json!(format!("bafy-{}", session_id)));
+    assert_eq!(proof["pinned_by"], json!(["mock"]));
+
+    assert_eq!(server.prover.proofs.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        *server.sink.uploads.lock().unwrap(),
+        vec![session_id.clone()]
+    );
+    assert!(server
+        .orchestrator
+        .get_game_state(&session_id)
+        .await
+        .is_ok());
+}
+
+#[tokio::test(start_paused = true)]
+async fn lib_deal_game_matches_the_finalized_deal() {
+    for instance_salt in [B256::ZERO, B256::repeat_byte(0x17)] {
+        let server = TestServer::new(
+            MockRpc::fulfilled(random_word()),
+            OrchestratorConfig {
+                instance_salt,
+                ..OrchestratorConfig::default()
+            },
+        );
+        let (_, started) = server
+            .post(
+                "/api/game/start",
+                json!({ "num_players": 3, "cards_per_player": 5 }),
+            )
+            .await;
+        let session_id = started["session_id"].as_str().unwrap().to_string();
+        server.wait_for_status(&session_id, "Ready").await;
+
+        let (_, raw) = server
+            .get(&format!("/api/game/{}?format=raw", session_id))
+            .await;
+        let core = deal_game_salted(3, 5, random_word(), instance_salt.0).unwrap();
+        assert_eq!(raw["game_state"]["player_hands"], json!(core.player_hands));
+        assert_eq!(raw["game_state"]["draw_pile"], json!(core.draw_pile));
+        if instance_salt.is_zero() {
+            assert_eq!(deal_game(3, 5, random_word()).unwrap(), core);
+        }
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn status_codes_follow_the_game_status() {
+    let server = TestServer::new(
+        MockRpc::fulfilled(random_word()),
+        OrchestratorConfig::default(),
+    );
+    let (_, started) = server
+        .post(
+            "/api/game/start",
+            json!({ "num_players": 2, "cards_per_player": 7 }),
+        )
+        .await;
+    let session_id = started["session_id"].as_str().unwrap().to_string();
+
+    server.wait_for_status(&session_id, "Ready").await;
+    let (status, ready) = server
+        .get(&format!("/api/game/{}/status", session_id))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(ready["status"], "Ready");
+}
+
+#[tokio::test(start_paused = true)]
+async fn a_failed_game_reports_its_reason_with_a_200() {
+    // No method is mocked, so the VRF request itself fails
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let (_, started) = server
+        .post(
+            "/api/game/start",
+            json!({ "num_players": 2, "cards_per_player": 7 }),
+        )
+        .await;
+    let uri = format!(
+        "/api/game/{}/status",
+        started["session_id"].as_str().unwrap()
+    );
+
+    for _ in 0..60 {
+        let (status, body) = server.get(&uri).await;
+        if body["status"].get("Failed").is_some() {
+            assert_eq!(status, StatusCode::OK, "{}", body);
+            assert_eq!(body["status"]["Failed"]["reason"], "vrf_timeout");
+            assert!(body["status"]["Failed"]["detail"].is_string());
+            return;
+        }
+        assert_eq!(status, StatusCode::ACCEPTED, "{}", body);
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    panic!("game never failed");
+}
+
+#[tokio::test(start_paused = true)]
+async fn game_names_round_trip_and_filter_the_games_list() {
+    let server = TestServer::new(
+        MockRpc::default(),
+        OrchestratorConfig {
+            admin_token: Some("admin-secret".to_string()),
+            ..OrchestratorConfig::default()
+        },
+    );
+    let mut named = Vec::new();
+    for name in [
+        json!("  Friday Night  "),
+        json!("friday rematch"),
+        Value::Null,
+    ] {
+        let (session_id, _) = server
+            .start_os_game(json!({ "num_players": 2, "cards_per_player": 7, "name": name }))
+            .await;
+        named.push(session_id);
+    }
+
+    let (_, status) = server.get(&format!("/api/game/{}/status", named[0])).await;
+    assert_eq!(status["name"], "Friday Night");
+    let (_, status) = server.get(&format!("/api/game/{}/status", named[2])).await;
+    assert!(status.get("name").is_none(), "{}", status);
+
+    let (status, _) = server.get("/api/admin/games?name=friday").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let list = |query: &str| format!("/api/admin/games?{}", query);
+    let (status, fridays) = server.get_admin(&list("name=FRIDAY"), "admin-secret").await;
+    assert_eq!(status, StatusCode::OK, "{}", fridays);
+    assert_eq!(fridays["total"], 2);
+    let mut found: Vec<&str> = fridays["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|game| game["session_id"].as_str().unwrap())
+        .collect();
+    found.sort();
+    let mut expected = vec![named[0].as_str(), named[1].as_str()];
+    expected.sort();
+    assert_eq!(found, expected);
+
+    let (_, rematches) = server
+        .get_admin(&list("name=rematch"), "admin-secret")
+        .await;
+    assert_eq!(rematches["total"], 1);
+    assert_eq!(rematches["items"][0]["name"], "friday rematch");
+
+    let (_, everything) = server.get_admin(&list(""), "admin-secret").await;
+    assert_eq!(everything["total"], 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn config_reports_the_settings_in_use_without_secrets() {
+    let tournament_seed = U256::from(0xdead_beef_cafe_f00d_u64) << 128;
+    let instance_salt = B256::repeat_byte(0x5a);
+    let server = TestServer::new(
+        MockRpc::default(),
+        OrchestratorConfig {
+            admin_token: Some("admin-secret".to_string()),
+            finalize_max_retries: 7,
+            prover_threads: 3,
+            min_seed_bits: 100,
+            tournament_seed: Some(tournament_seed),
+            instance_salt,
+            ..OrchestratorConfig::default()
+        },
+    );
+
+    let (status, _) = server.get("/api/admin/config").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, config) = server.get_admin("/api/admin/config", "admin-secret").await;
+    assert_eq!(status, StatusCode::OK, "{}", config);
+    assert_eq!(config["finalize_max_retries"], 7);
+    assert_eq!(config["prover_threads"], 3);
+    assert_eq!(config["min_seed_bits"], 100);
+    assert_eq!(config["admin_token_set"], true);
+    assert_eq!(config["instance_salt_set"], true);
+    assert_eq!(config["tournament_mode"], true);
+    assert_eq!(config["vrf_mode"], "polling");
+    assert_eq!(config["ws_rpc_url"], "ws://mock");
+    assert_eq!(config["http_rpc_url"], "http://mock");
+
+    let body = config.to_string().to_lowercase();
+    for secret in [
+        "admin-secret".to_string(),
+        hex::encode(tournament_seed.to_be_bytes::<32>()),
+        tournament_seed.to_string(),
+        hex::encode(instance_salt),
+    ] {
+        assert!(!body.contains(&secret), "config leaks {}: {}", secret, body);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn compare_reports_whether_two_tables_were_dealt_alike() {
+    let server = TestServer::new(
+        MockRpc::fulfilled(random_word()),
+        OrchestratorConfig {
+            admin_token: Some("admin-secret".to_string()),
+            ..OrchestratorConfig::default()
+        },
+    );
+    // Both VRF games are fulfilled with the same random word
+    let mut same_seed = Vec::new();
+    for _ in 0..2 {
+        let (_, started) = server
+            .post(
+                "/api/game/start",
+                json!({ "num_players": 3, "cards_per_player": 7 }),
+            )
+            .await;
+        let session_id = started["session_id"].as_str().unwrap().to_string();
+        server.wait_for_status(&session_id, "Ready").await;
+        same_seed.push(session_id);
+    }
+    let (os_seeded, _) = server
+        .start_os_game(json!({ "num_players": 3, "cards_per_player": 7 }))
+        .await;
+
+    let compare = |a: &str, b: &str| format!("/api/admin/compare?a={}&b={}", a, b);
+    let (status, equal) = server
+        .get_admin(&compare(&same_seed[0], &same_seed[1]), "admin-secret")
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", equal);
+    assert_eq!(equal["deals_equal"], true);
+    assert_eq!(equal["a"], same_seed[0]);
+    assert_eq!(equal["b"], same_seed[1]);
+
+    let (_, different) = server
+        .get_admin(&compare(&same_seed[0], &os_seeded), "admin-secret")
+        .await;
+    assert_eq!(different["deals_equal"], false);
+
+    let (status, _) = server
+        .get_admin(&compare(&same_seed[0], "missing"), "admin-secret")
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = server.get(&compare(&same_seed[0], &same_seed[1])).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test(start_paused = true)]
+async fn overlong_game_names_are_rejected() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+
+    let (status, body) = server
+        .post(
+            "/api/game/start",
+            json!({ "num_players": 2, "cards_per_player": 7, "name": "x".repeat(65) }),
+        )
+        .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "INVALID_GAME_NAME");
+}
+
+#[tokio::test(start_paused = true)]
+async fn deal_stats_add_up_to_the_whole_deck() {
+    let server = TestServer::new(
+        MockRpc::default(),
+        OrchestratorConfig {
+            admin_token: Some("admin-secret".to_string()),
+            ..OrchestratorConfig::default()
+        },
+    );
+    let (session_id, _) = server
+        .start_os_game(json!({ "num_players": 4, "cards_per_player": 7 }))
+        .await;
+    let uri = format!("/api/game/{}/stats", session_id);
+
+    let (status, _) = server.get(&uri).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    for near_miss in ["admin-secre", "admin-secret!", "Admin-secret"] {
+        let (status, _) = server.get_admin(&uri, near_miss).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED, "{}", near_miss);
+    }
+
+    let (status, stats) = server.get_admin(&uri, "admin-secret").await;
+    assert_eq!(status, StatusCode::OK, "{}", stats);
+    assert_eq!(stats["hands"]["total"], 28);
+    assert_eq!(stats["draw_pile"]["total"], 80);
+    assert_eq!(
+        stats["combined"],
+        json!({
+            "total": 108,
+            "colors": { "R": 25, "G": 25, "B": 25, "Y": 25, "W": 8 },
+            "kinds": {
+                "number": 76,
+                "skip": 8,
+                "reverse": 8,
+                "draw_two": 8,
+                "wild": 4,
+                "wild_draw_four": 4,
+            },
+        })
+    );
+    // Hands and draw pile split every color between them
+    for color in ["R", "G", "B", "Y", "W"] {
+        let hands = stats["hands"]["colors"][color].as_u64().unwrap_or(0);
+        let pile = stats["draw_pile"]["colors"][color].as_u64().unwrap_or(0);
+        assert_eq!(
+            hands + pile,
+            stats["combined"]["colors"][color].as_u64().unwrap()
+        );
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn players_can_read_deal_stats_once_the_game_is_over() {
+    let server = TestServer::new(
+        MockRpc::default(),
+        OrchestratorConfig {
+            admin_token: Some("admin-secret".to_string()),
+            ..OrchestratorConfig::default()
+        },
+    );
+    let (session_id, tokens) = server
+        .start_os_game(json!({ "num_players": 2, "cards_per_player": 7 }))
+        .await;
+    let uri = format!("/api/game/{}/stats", session_id);
+
+    // Mid-game the deal stays hidden from the players
+    let (status, _) = server.get_as(&uri, &tokens[1]).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    server
+        .orchestrator
+        .update_game_state(&session_id, |game_state| {
+            game_state.outcome = Some(GameOutcome::Draw {
+                reason: "called off".to_string(),
+            });
+            Ok(())
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+    let (status, stats) = server.get_as(&uri, &tokens[1]).await;
+    assert_eq!(status, StatusCode::OK, "{}", stats);
+    assert_eq!(stats["hands"]["total"], 14);
+    assert_eq!(stats["combined"]["total"], 108);
+
+    for (status, body) in [
+        server.get(&uri).await,
+        server.get_as(&uri, "not-a-seat-token").await,
+    ] {
+        assert_eq!(status, StatusCode::UNAUTHORIZED, "{}", body);
+    }
+    let (status, _) = server.get_admin(&uri, "admin-secret").await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test(start_paused = true)]
+async fn an_unhealthy_prover_degrades_the_deep_health_check() {
+    let server = TestServer::new(
+        MockRpc::fulfilled(random_word()),
+        OrchestratorConfig::default(),
+    );
+
+    let (status, health) = server.get("/health/deep").await;
+    assert_eq!(status, StatusCode::OK, "{}", health);
+    assert_eq!(
+        health,
+        json!({ "status": "ok", "prover": true, "rpc": true })
+    );
+
+    // Probe results are cached, so the outage shows once the last one goes stale
+    server.prover.unhealthy.store(true, Ordering::SeqCst);
+    let (status, _) = server.get("/health/deep").await;
+    assert_eq!(status, StatusCode::OK);
+    tokio::time::advance(Duration::from_secs(30)).await;
+
+    let (status, health) = server.get("/health/deep").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(
+        health,
+        json!({ "status": "degraded", "prover": false, "rpc": true })
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn unknown_sessions_are_not_found() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+
+    for uri in [
+        "/api/game/missing/status",
+        "/api/game/missing",
+        "/api/game/missing/proof",
+    ] {
+        let (status, body) = server.get(uri).await;
+        assert_eq!(status, StatusCode::NOT_FOUND, "{}", uri);
+        assert!(body["code"].is_string(), "{}: {}", uri, body);
+    }
+
+    let (status, body) = server.get("/api/nothing/here").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["code"], "ROUTE_NOT_FOUND");
+}
+
+#[tokio::test(start_paused = true)]
+async fn invalid_start_is_rejected_before_any_rpc_call() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+
+    let (status, body) = server
+        .post(
+            "/api/game/start",
+            json!({ "num_players": 1, "cards_per_player": 7 }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "INVALID_GAME_PARAMS");
+}
+
+/// Start a VRF game, wait for its deal and return the dealt state
+async fn start_vrf_game(server: &TestServer, request: Value) -> GameState {
+    let (status, started) = server.post("/api/game/start", request).await;
+    assert_eq!(status, StatusCode::OK, "{}", started);
+    let session_id = started["session_id"].as_str().unwrap();
+    server.wait_for_status(session_id, "Ready").await;
+    server
+        .orchestrator
+        .get_game_state(session_id)
+        .await
+        .unwrap()
+}
+
+#[tokio::test(start_paused = true)]
+async fn the_first_turn_goes_to_the_requested_or_seed_derived_seat() {
+    let server = TestServer::new(
+        MockRpc::fulfilled(random_word()),
+        OrchestratorConfig::default(),
+    );
+
+    let explicit = start_vrf_game(
+        &server,
+        json!({ "num_players": 4, "cards_per_player": 7, "starting_player": 3 }),
+    )
+    .await;
+    assert_eq!(explicit.starting_player, 3);
+    assert_eq!(explicit.current_player, 3);
+
+    // Without a request the seat is the VRF value modulo the player count, for every game
+    let expected = (random_word() % U256::from(4)).to::<u8>();
+    for _ in 0..2 {
+        let derived =
+            start_vrf_game(&server, json!({ "num_players": 4, "cards_per_player": 7 })).await;
+        assert_eq!(derived.starting_player, expected);
+        assert_eq!(derived.current_player, expected);
+    }
+
+    let (status, body) = server
+        .post(
+            "/api/game/start",
+            json!({ "num_players": 4, "cards_per_player": 7, "starting_player": 4 }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "INVALID_STARTING_PLAYER");
+}
+
+#[tokio::test(start_paused = true)]
+async fn games_sharing_a_master_draw_get_distinct_reproducible_seeds() {
+    let server = TestServer::new(
+        MockRpc::fulfilled(random_word()),
+        OrchestratorConfig::default(),
+    );
+    let (_, started) = server
+        .post(
+            "/api/game/start",
+            json!({ "num_players": 2, "cards_per_player": 7 }),
+        )
+        .await;
+    let master = started["session_id"].as_str().unwrap().to_string();
+    server.wait_for_status(&master, "Ready").await;
+
+    let mut derived = Vec::new();
+    for _ in 0..2 {
+        let (status, started) = server
+            .post(
+                "/api/game/start",
+                json!({ "num_players": 2, "cards_per_player": 7, "master_session": master }),
+            )
+            .await;
+        assert_eq!(status, StatusCode::OK, "{}", started);
+        let session_id = started["session_id"].as_str().unwrap().to_string();
+        server.wait_for_status(&session_id, "Ready").await;
+        derived.push(session_id);
+    }
+    // Attached to a derived game, a seed still comes from the one root draw
+    let (_, started) = server
+        .post(
+            "/api/game/start",
+            json!({ "num_players": 2, "cards_per_player": 7, "master_session": derived[0] }),
+        )
+        .await;
+    let grandchild = started["session_id"].as_str().unwrap().to_string();
+    server.wait_for_status(&grandchild, "Ready").await;
+
+    let master_value = random_word().to_be_bytes::<32>();
+    let mut seeds = Vec::new();
+    for session_id in derived.iter().chain([&grandchild]) {
+        let game_state = server
+            .orchestrator
+            .get_game_state(session_id)
+            .await
+            .unwrap();
+        let expected = U256::from_be_bytes(derive_game_seed(master_value, session_id.as_bytes()));
+        assert_eq!(game_state.seed_metadata.value, expected);
+
+        let attached = game_state.seed_metadata.master.unwrap();
+        assert_eq!(attached.master_session, master);
+        assert_eq!(attached.master_value, random_word());
+        assert_eq!(attached.context, *session_id);
+        assert!(game_state.verifiable);
+        seeds.push(expected);
+    }
+    assert_ne!(seeds[0], seeds[1]);
+    assert_ne!(seeds[0], seeds[2]);
+    assert_ne!(seeds[0], random_word());
+
+    let (status, body) = server
+        .post(
+            "/api/game/start",
+            json!({ "num_players": 2, "cards_per_player": 7, "master_session": "missing" }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "INVALID_MASTER_SESSION");
+}
+
+#[tokio::test(start_paused = true)]
+async fn player_info_needs_the_seat_token_and_hides_opponent_cards() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let (session_id, tokens) = server
+        .start_os_game(json!({ "num_players": 3, "cards_per_player": 5, "starting_player": 1 }))
+        .await;
+    assert_eq!(tokens.len(), 3);
+    let uri = format!("/api/game/{}/player/1/info", session_id);
+
+    let (status, body) = server.get(&uri).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["code"], "UNAUTHORIZED");
+    let (status, _) = server.get_as(&uri, &tokens[0]).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, info) = server.get_as(&uri, &tokens[1]).await;
+    assert_eq!(status, StatusCode::OK, "{}", info);
+
+    let fields: Vec<&str> = info
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    for field in [
+        "session_id",
+        "player_id",
+        "hand",
+        "hand_origins",
+        "playable_positions",
+        "opponents",
+        "draw_pile_count",
+        "current_player",
+        "is_my_turn",
+        "direction",
+        "finished",
+    ] {
+        assert!(fields.contains(&field), "missing {}: {}", field, info);
+    }
+    // Only the player's own hand carries cards
+    for field in &fields {
+        assert!(
+            [
+                "session_id",
+                "player_id",
+                "hand",
+                "hand_origins",
+                "playable_positions",
+                "top_discard",
+                "active_color",
+                "opponents",
+                "draw_pile_count",
+                "current_player",
+                "is_my_turn",
+                "direction",
+                "finished",
+            ]
+            .contains(field),
+            "unexpected field {}: {}",
+            field,
+            info
+        );
+    }
+
+    assert_eq!(info["hand"].as_array().unwrap().len(), 5);
+    assert_eq!(info["hand_origins"], json!(vec!["initial"; 5]));
+    assert_eq!(info["draw_pile_count"], 108 - 15);
+    assert_eq!(info["current_player"], 1);
+    assert_eq!(info["is_my_turn"], true);
+    assert_eq!(info["direction"], "clockwise");
+    assert_eq!(info["finished"], false);
+    assert_eq!(
+        info["opponents"],
+        json!([
+            { "player_id": 2, "hand_count": 5 },
+            { "player_id": 0, "hand_count": 5 },
+        ])
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn opponents_are_listed_in_turn_order_for_the_token_holder() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let (session_id, tokens) = server
+        .start_os_game(json!({ "num_players": 4, "cards_per_player": 7, "starting_player": 2 }))
+        .await;
+    let (status, drawn) = server
+        .post(
+            &format!("/api/game/{}/player/2/draw", session_id),
+            json!({ "count": 2 }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", drawn);
+    let opponents = |seat: usize| format!("/api/game/{}/player/{}/opponents", session_id, seat);
+
+    let (status, body) = server.get(&opponents(0)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["code"], "UNAUTHORIZED");
+    let (status, _) = server.get_as(&opponents(0), &tokens[1]).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, from_zero) = server.get_as(&opponents(0), &tokens[0]).await;
+    assert_eq!(status, StatusCode::OK, "{}", from_zero);
+    assert_eq!(from_zero["direction"], "clockwise");
+    assert_eq!(
+        from_zero["opponents"],
+        json!([
+            { "player_id": 1, "hand_count": 7 },
+            { "player_id": 2, "hand_count": 9 },
+            { "player_id": 3, "hand_count": 7 },
+        ])
+    );
+
+    let (status, from_three) = server.get_as(&opponents(3), &tokens[3]).await;
+    assert_eq!(status, StatusCode::OK, "{}", from_three);
+    assert_eq!(
+        from_three["opponents"],
+        json!([
+            { "player_id": 0, "hand_count": 7 },
+            { "player_id": 1, "hand_count": 7 },
+            { "player_id": 2, "hand_count": 9 },
+        ])
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn an_opponents_window_keeps_the_nearest_seats() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let (session_id, tokens) = server
+        .start_os_game(json!({ "num_players": 6, "cards_per_player": 5 }))
+        .await;
+    let opponents = |window: usize| {
+        format!(
+            "/api/game/{}/player/0/opponents?window={}",
+            session_id, window
+        )
+    };
+    let seats = |body: &Value| -> Vec<u64> {
+        body["opponents"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|opponent| opponent["player_id"].as_u64().unwrap())
+            .collect()
+    };
+
+    let (status, narrow) = server.get_as(&opponents(1), &tokens[0]).await;
+    assert_eq!(status, StatusCode::OK, "{}", narrow);
+    assert_eq!(seats(&narrow), [1, 5]);
+    let (_, wider) = server.get_as(&opponents(2), &tokens[0]).await;
+    assert_eq!(seats(&wider), [1, 2, 4, 5]);
+
+    // Clamped to the table: the same list as asking without a window
+    let (_, everyone) = server
+        .get_as(
+            &format!("/api/game/{}/player/0/opponents", session_id),
+            &tokens[0],
+        )
+        .await;
+    let (status, oversized) = server.get_as(&opponents(50), &tokens[0]).await;
+    assert_eq!(status, StatusCode::OK, "{}", oversized);
+    assert_eq!(seats(&oversized), [1, 2, 3, 4, 5]);
+    assert_eq!(oversized["opponents"], everyone["opponents"]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn a_rematch_keeps_the_seats_and_tokens_but_deals_afresh() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let (original, tokens) = server
+        .start_os_game(json!({ "num_players": 3, "cards_per_player": 7 }))
+        .await;
+    let rematch = format!("/api/game/{}/rematch", original);
+
+    let (status, body) = server.post(&rematch, json!({})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["code"], "GAME_NOT_FINISHED");
+
+    server
+        .orchestrator
+        .update_game_state(&original, |game_state| {
+            game_state.outcome = Some(GameOutcome::Draw {
+                reason: "called off".to_string(),
+            });
+            Ok(())
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    let (status, started) = server.post(&rematch, json!({})).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", started);
+    assert_eq!(started["rematch_of"], original);
+    // The seats keep their tokens, so they aren't handed out again
+    assert!(started.get("player_tokens").is_none(), "{}", started);
+    let session_id = started["session_id"].as_str().unwrap();
+    assert_ne!(session_id, original);
+
+    let (_, before) = server.get(&format!("/api/game/{}", original)).await;
+    let (_, after) = server.get(&format!("/api/game/{}", session_id)).await;
+    let hands = after["game_state"]["player_hands"].as_array().unwrap();
+    assert_eq!(hands.len(), 3);
+    assert!(hands.iter().all(|hand| hand.as_array().unwrap().len() == 7));
+    assert_ne!(
+        after["game_state"]["player_hands"],
+        before["game_state"]["player_hands"]
+    );
+
+    for (seat, token) in tokens.iter().enumerate() {
+        let uri = format!("/api/game/{}/player/{}/info", session_id, seat);
+        let (status, info) = server.get_as(&uri, token).await;
+        assert_eq!(status, StatusCode::OK, "{}", info);
+        let (status, _) = server.get_as(&uri, &tokens[(seat + 1) % 3]).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn drawn_cards_show_up_as_drawn_in_the_private_view() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let (session_id, tokens) = server
+        .start_os_game(json!({ "num_players": 2, "cards_per_player": 5 }))
+        .await;
+    let (status, drawn) = server
+        .post(
+            &format!("/api/game/{}/player/1/draw", session_id),
+            json!({ "count": 2 }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", drawn);
+
+    let info = |seat: usize| format!("/api/game/{}/player/{}/info", session_id, seat);
+    let (_, drew) = server.get_as(&info(1), &tokens[1]).await;
+    assert_eq!(
+        drew["hand_origins"],
+        json!(["initial", "initial", "initial", "initial", "initial", "drawn", "drawn"])
+    );
+    assert_eq!(drew["hand"].as_array().unwrap().len(), 7);
+    let (_, waited) = server.get_as(&info(0), &tokens[0]).await;
+    assert_eq!(waited["hand_origins"], json!(vec!["initial"; 5]));
+}
+
+#[tokio::test(start_paused = true)]
+async fn playable_cards_need_the_seat_token() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let (session_id, tokens) = server
+        .start_os_game(json!({ "num_players": 2, "cards_per_player": 7 }))
+        .await;
+    let uri = format!("/api/game/{}/player/0/playable", session_id);
+
+    let (status, _) = server.get(&uri).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = server.get_as(&uri, &tokens[1]).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // Nothing is discarded yet, so the whole hand is playable
+    let (status, playable) = server.get_as(&uri, &tokens[0]).await;
+    assert_eq!(status, StatusCode::OK, "{}", playable);
+    assert_eq!(playable["positions"], json!([0, 1, 2, 3, 4, 5, 6]));
+    assert_eq!(playable["cards"].as_array().unwrap().len(), 7);
+}
+
+/// POST `body` verbatim as JSON
+fn raw_post(uri: &str, body: impl Into<Body>) -> Request<Body> {
+    Request::post(uri)
+        .header("content-type", "application/json")
+        .body(body.into())
+        .unwrap()
+}
+
+#[tokio::test(start_paused = true)]
+async fn oversized_bodies_get_a_structured_413() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let name = "x".repeat(64 * 1024);
+    let body = json!({ "num_players": 2, "cards_per_player": 7, "name": name }).to_string();
+
+    let (status, error) = server.send(raw_post("/api/game/start", body)).await;
+
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(error["code"], "PAYLOAD_TOO_LARGE");
+    assert!(error["message"].is_string());
+}
+
+#[tokio::test(start_paused = true)]
+async fn malformed_json_gets_a_structured_400() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+
+    let (status, error) = server
+        .send(raw_post("/api/game/start", "{\"num_players\": 2,"))
+        .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error["code"], "MALFORMED_JSON");
+    assert!(error["message"].is_string());
+}
+
+#[tokio::test(start_paused = true)]
+async fn os_seeded_games_deal_differently_and_carry_no_proof() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let mut deals = Vec::new();
+
+    for _ in 0..2 {
+        let (session_id, _) = server
+            .start_os_game(json!({ "num_players": 4, "cards_per_player": 7 }))
+            .await;
+        let (_, status) = server
+            .get(&format!("/api/game/{}/status", session_id))
+            .await;
+        assert_eq!(status["status"], "Ready", "{}", status);
+        assert_eq!(status["verifiable"], false, "{}", status);
+
+        let (_, raw) = server
+            .get(&format!("/api/game/{}?format=raw", session_id))
+            .await;
+        deals.push(raw["game_state"]["draw_pile"].clone());
+    }
+
+    // 80 shuffled cards colliding would mean the OS RNG isn't being used
+    assert_ne!(deals[0], deals[1]);
+    assert_eq!(server.prover.proofs.load(Ordering::SeqCst), 0);
+    assert!(server.sink.uploads.lock().unwrap().is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn format_raw_returns_deck_indices_and_js_returns_strings() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let (session_id, _) = server
+        .start_os_game(json!({ "num_players": 2, "cards_per_player": 7 }))
+        .await;
+
+    let (status, raw) = server
+        .get(&format!("/api/game/{}?format=raw", session_id))
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", raw);
+    let (_, js) = server
+        .get(&format!("/api/game/{}?format=js", session_id))
+        .await;
+
+    for field in ["draw_pile", "discard_pile"] {
+        let raw = raw["game_state"][field].as_array().unwrap();
+        let js = js["game_state"][field].as_array().unwrap();
+        assert_eq!(raw.len(), js.len(), "{}", field);
+        assert!(raw
+            .iter()
+            .all(|card| card.as_u64().is_some_and(|card| card < 108)));
+        assert!(js.iter().all(Value::is_string));
+    }
+    let raw_hands = raw["game_state"]["player_hands"].as_array().unwrap();
+    let js_hands = js["game_state"]["player_hands"].as_array().unwrap();
+    assert_eq!(raw_hands.len(), 2);
+    for (raw_hand, js_hand) in raw_hands.iter().zip(js_hands) {
+        assert_eq!(raw_hand.as_array().unwrap().len(), 7);
+        assert!(raw_hand.as_array().unwrap().iter().all(Value::is_u64));
+        assert!(js_hand.as_array().unwrap().iter().all(Value::is_string));
+    }
+
+    let (_, hand) = server
+        .get(&format!(
+            "/api/game/{}/player/1/hand?format=raw",
+            session_id
+        ))
+        .await;
+    assert_eq!(hand["cards"], raw_hands[1]);
+    let (_, hand) = server
+        .get(&format!("/api/game/{}/player/1/hand", session_id))
+        .await;
+    assert_eq!(hand["cards"], js_hands[1]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn the_deal_animation_follows_the_round_robin_order() {
+    let server = TestServer::new(
+        MockRpc::default(),
+        OrchestratorConfig {
+            deal_timing_ms: 75,
+            ..OrchestratorConfig::default()
+        },
+    );
+    let (session_id, _) = server
+        .start_os_game(json!({ "num_players": 3, "cards_per_player": 5 }))
+        .await;
+
+    let (status, state) = server
+        .get(&format!(
+            "/api/game/{}?include_deal=true&format=raw",
+            session_id
+        ))
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", state);
+    assert_eq!(state["deal"]["deal_timing_ms"], 75);
+
+    let events = state["deal"]["events"].as_array().unwrap();
+    let hands = state["game_state"]["player_hands"].as_array().unwrap();
+    assert_eq!(events.len(), 3 * 5);
+    for (position, event) in events.iter().enumerate() {
+        assert_eq!(event["player_id"], position % 3, "{}", event);
+        assert_eq!(
+            event["card"],
+            hands[position % 3][position / 3],
+            "{}",
+            event
+        );
+    }
+
+    let (_, lean) = server.get(&format!("/api/game/{}", session_id)).await;
+    assert!(lean.get("deal").is_none(), "{}", lean);
+}
+
+#[tokio::test(start_paused = true)]
+async fn a_fresh_game_has_an_empty_discard_window() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let (session_id, _) = server
+        .start_os_game(json!({ "num_players": 2, "cards_per_player": 7 }))
+        .await;
+
+    for uri in ["discard", "discard?count=5"] {
+        let (status, discard) = server
+            .get(&format!("/api/game/{}/{}", session_id, uri))
+            .await;
+        assert_eq!(status, StatusCode::OK, "{}", discard);
+        assert_eq!(discard["cards"], json!([]));
+        assert_eq!(discard["pile_size"], 0);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn a_custom_theme_persists_from_start_to_state() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let (themed, _) = server
+        .start_os_game(json!({ "num_players": 2, "cards_per_player": 7, "theme": "neon" }))
+        .await;
+    let (plain, _) = server
+        .start_os_game(json!({ "num_players": 2, "cards_per_player": 7 }))
+        .await;
+
+    for (session_id, theme) in [(&themed, "neon"), (&plain, "classic")] {
+        let (_, state) = server.get(&format!("/api/game/{}", session_id)).await;
+        assert_eq!(state["game_state"]["theme"], theme);
+        let (_, state) = server
+            .get(&format!("/api/game/{}?format=raw", session_id))
+            .await;
+        assert_eq!(state["game_state"]["theme"], theme);
+        let (_, status) = server
+            .get(&format!("/api/game/{}/status", session_id))
+            .await;
+        assert_eq!(status["theme"], theme);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn pretty_responses_are_indented_and_the_default_is_compact() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let uri = "/api/proof/manifest";
+
+    let (status, compact) = server.get_text(uri).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(!compact.contains('\n'), "{}", compact);
+
+    let (status, pretty) = server.get_text(&format!("{}?pretty=true", uri)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(pretty.starts_with("{\n  \""), "{}", pretty);
+
+    // Same document either way, and anything but `true` stays compact
+    let parse = |body: &str| serde_json::from_str::<Value>(body).unwrap();
+    assert_eq!(parse(&pretty), parse(&compact));
+    let (_, unparseable) = server.get_text(&format!("{}?pretty=yes", uri)).await;
+    assert!(!unparseable.contains('\n'), "{}", unparseable);
+    let (_, explicit) = server.get_text(&format!("{}?pretty=false", uri)).await;
+    assert!(!explicit.contains('\n'), "{}", explicit);
+}
+
+#[tokio::test(start_paused = true)]
+async fn large_responses_are_gzipped_when_the_client_accepts_it() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let (session_id, _) = server
+        .start_os_game(json!({ "num_players": 4, "cards_per_player": 7 }))
+        .await;
+
+    let encoding = |uri: String| {
+        let router = server.router.clone();
+        async move {
+            let request = Request::get(uri)
+                .header("accept-encoding", "gzip")
+                .body(Body::empty())
+                .unwrap();
+            let response = router.oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            response
+                .headers()
+                .get("content-encoding")
+                .map(|value| value.to_str().unwrap().to_string())
+        }
+    };
+
+    assert_eq!(
+        encoding(format!("/api/game/{}", session_id))
+            .await
+            .as_deref(),
+        Some("gzip")
+    );
+    // Tiny bodies aren't worth compressing
+    assert_eq!(encoding("/health".to_string()).await, None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn manifest_reports_the_current_image_id() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+
+    for _ in 0..2 {
+        let (status, manifest) = server.get("/api/proof/manifest").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(manifest["image_id"], IMAGE_ID);
+        assert_eq!(manifest["program_version"], "test");
+        assert_eq!(manifest["hash_algorithm"], "sha256");
+    }
+    // Derived when the orchestrator was built, not per request
+    assert_eq!(server.prover.manifests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn image_id_is_served_before_any_game_exists() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+
+    for _ in 0..2 {
+        let (status, body) = server.get("/api/proof/image-id").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, json!({ "image_id": IMAGE_ID }));
+    }
+    assert_eq!(server.prover.manifests.load(Ordering::SeqCst), 1);
+    assert_eq!(server.prover.proofs.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn the_verifying_key_is_served_as_hex_beside_its_image_id() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+
+    let (status, body) = server.get("/api/proof/vk").await;
+
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["image_id"], IMAGE_ID);
+    assert_eq!(body["encoding"], "bincode-legacy");
+    assert_eq!(body["vk"], format!("0x{}", "11".repeat(32)));
+}
+
+#[tokio::test]
+async fn the_served_vk_is_the_generator_key() {
+    let serving = Arc::new(ProofGenerator::new().unwrap());
+    let orchestrator = Arc::new(GameOrchestrator::with_services(
+        mock_chain(&MockRpc::default()),
+        OrchestratorConfig::default(),
+        serving.clone(),
+        Arc::new(MockSink::default()),
+    ));
+    let router = build_router(orchestrator);
+
+    let response = router
+        .oneshot(Request::get("/api/proof/vk").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = serde_json::from_slice(
+        &axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap(),
+    )
+    .unwrap();
+
+    let vk = hex::decode(body["vk"].as_str().unwrap()).unwrap();
+    assert_eq!(vk, serving.export_vk().unwrap());
+    assert_eq!(body["image_id"], serving.image_id());
+}
+
+#[tokio::test]
+async fn image_id_matches_a_freshly_set_up_generator() {
+    let serving = Arc::new(ProofGenerator::new().unwrap());
+    let orchestrator = Arc::new(GameOrchestrator::with_services(
+        mock_chain(&MockRpc::default()),
+        OrchestratorConfig::default(),
+        serving,
+        Arc::new(MockSink::default()),
+    ));
+    let router = build_router(orchestrator);
+
+    let response = router
+        .oneshot(
+            Request::get("/api/proof/image-id")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = serde_json::from_slice(
+        &axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap(),
+    )
+    .unwrap();
+
+    let fresh = ProofGenerator::new().unwrap();
+    assert_eq!(body["image_id"], fresh.image_id());
+}
+
+#[tokio::test(start_paused = true)]
+async fn draw_counts_outside_the_allowed_range_are_rejected() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let (session_id, _) = server
+        .start_os_game(json!({ "num_players": 2, "cards_per_player": 7 }))
+        .await;
+    let uri = format!("/api/game/{}/player/0/draw", session_id);
+
+    for count in [0, 21, 255] {
+        let (status, error) = server.post(&uri, json!({ "count": count })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "count {}", count);
+        assert_eq!(error["code"], "INVALID_COUNT");
+    }
+
+    let (status, drawn) = server.post(&uri, json!({ "count": 20 })).await;
+    assert_eq!(status, StatusCode::OK, "{}", drawn);
+    assert_eq!(drawn["cards"].as_array().unwrap().len(), 20);
+    assert_eq!(drawn["draw_pile_count"], 108 - 14 - 20);
+}
+
+#[tokio::test(start_paused = true)]
+async fn draw_pile_commitment_verifies_against_the_stored_deal() {
+    let server = TestServer::new(
+        MockRpc::fulfilled(random_word()),
+        OrchestratorConfig::default(),
+    );
+    let (_, started) = server
+        .post(
+            "/api/game/start",
+            json!({ "num_players": 3, "cards_per_player": 7 }),
+        )
+        .await;
+    let session_id = started["session_id"].as_str().unwrap().to_string();
+    server.wait_for_status(&session_id, "Ready").await;
+
+    let (status, check) = server
+        .get(&format!("/api/game/{}/verify/draw-pile", session_id))
+        .await;
+
+    assert_eq!(status, StatusCode::OK, "{}", check);
+    assert_eq!(check["matches"], true);
+    assert_eq!(check["committed"], check["computed"]);
+    assert_eq!(check["committed"].as_str().unwrap().len(), 66);
+}
+
+#[tokio::test(start_paused = true)]
+async fn proofs_download_as_a_json_attachment() {
+    let server = TestServer::new(
+        MockRpc::fulfilled(random_word()),
+        OrchestratorConfig::default(),
+    );
+    let (_, started) = server
+        .post(
+            "/api/game/start",
+            json!({ "num_players": 2, "cards_per_player": 7 }),
+        )
+        .await;
+    let session_id = started["session_id"].as_str().unwrap().to_string();
+    server.wait_for_status(&session_id, "Ready").await;
+
+    let request = Request::get(format!("/api/game/{}/proof/download", session_id))
+        .body(Body::empty())
+        .unwrap();
+    let response = server.router.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    assert_eq!(
+        response.headers()[header::CONTENT_DISPOSITION],
+        format!("attachment; filename=\"proof_{}.json\"", session_id).as_str()
+    );
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let proof: ProofOutput = serde_json::from_slice(&body).unwrap();
+    assert_eq!(proof.image_id, IMAGE_ID);
+    let public_values =
+        PublicValuesStruct::abi_decode(&hex::decode(&proof.pub_inputs).unwrap()).unwrap();
+    assert_eq!(public_values.no_of_players, 2);
+    assert_eq!(public_values.seed.0, random_word().to_be_bytes::<32>());
+}
+
+#[tokio::test(start_paused = true)]
+async fn games_without_a_proof_have_nothing_to_download() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let (session_id, _) = server
+        .start_os_game(json!({ "num_players": 2, "cards_per_player": 7 }))
+        .await;
+
+    let (status, body) = server
+        .get(&format!("/api/game/{}/proof/download", session_id))
+        .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["code"], "GAME_UNVERIFIABLE");
+
+    let (status, _) = server.get("/api/game/missing/proof/download").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test(start_paused = true)]
+async fn a_proof_is_only_served_once_it_has_a_cid() {
+    let dead_letter_dir =
+        std::env::temp_dir().join(format!("zunno-api-dead-letters-{}", std::process::id()));
+    std::fs::remove_dir_all(&dead_letter_dir).ok();
+    let config = OrchestratorConfig {
+        ipfs_dead_letter_dir: Some(dead_letter_dir.clone()),
+        finalize_max_retries: 0,
+        ..OrchestratorConfig::default()
+    };
+    let sink = MockSink {
+        upload_failures: AtomicUsize::new(1),
+        ..MockSink::default()
+    };
+    let server = TestServer::with_sink(MockRpc::fulfilled(random_word()), config, sink);
+
+    // The first game's upload gives up and is dead-lettered; the second is pinned
+    let mut sessions = Vec::new();
+    for _ in 0..2 {
+        let (_, started) = server
+            .post(
+                "/api/game/start",
+                json!({ "num_players": 2, "cards_per_player": 7 }),
+            )
+            .await;
+        let session_id = started["session_id"].as_str().unwrap().to_string();
+        server.wait_for_status(&session_id, "Ready").await;
+        sessions.push(session_id);
+    }
+    std::fs::remove_dir_all(&dead_letter_dir).ok();
+    let (unpinned, pinned) = (&sessions[0], &sessions[1]);
+
+    for uri in [
+        format!("/api/game/{}/proof", unpinned),
+        format!("/api/game/{}/proof/download", unpinned),
+    ] {
+        let (status, body) = server.get(&uri).await;
+        assert_eq!(status, StatusCode::CONFLICT, "{}", body);
+        assert_eq!(body["code"], "PROOF_NOT_AVAILABLE");
+    }
+
+    let (status, proof) = server.get(&format!("/api/game/{}/proof", pinned)).await;
+    assert_eq!(status, StatusCode::OK, "{}", proof);
+    assert_eq!(proof["proof_cid"], format!("bafy-{}", pinned));
+    assert_eq!(*server.sink.uploads.lock().unwrap(), vec![pinned.clone()]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn include_proof_adds_the_cid_only_when_asked() {
+    let server = TestServer::new(
+        MockRpc::fulfilled(random_word()),
+        OrchestratorConfig::default(),
+    );
+    let (_, started) = server
+        .post(
+            "/api/game/start",
+            json!({ "num_players": 2, "cards_per_player": 7 }),
+        )
+        .await;
+    let session_id = started["session_id"].as_str().unwrap().to_string();
+    server.wait_for_status(&session_id, "Ready").await;
+
+    let (status, with_proof) = server
+        .get(&format!("/api/game/{}?include_proof=true", session_id))
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", with_proof);
+    assert_eq!(with_proof["proof_cid"], format!("bafy-{}", session_id));
+    assert_eq!(with_proof["proof_pinned_by"], json!(["mock"]));
+
+    for uri in [
+        format!("/api/game/{}", session_id),
+        format!("/api/game/{}?include_proof=false", session_id),
+    ] {
+        let (status, lean) = server.get(&uri).await;
+        assert_eq!(status, StatusCode::OK, "{}", lean);
+        assert!(lean.get("proof_cid").is_none(), "{}", lean);
+        assert!(lean.get("proof_pinned_by").is_none(), "{}", lean);
+        assert_eq!(lean["game_state"], with_proof["game_state"]);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn include_proof_is_harmless_for_games_without_a_proof() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+    let (dealt, _) = server
+        .start_os_game(json!({ "num_players": 2, "cards_per_player": 7 }))
+        .await;
+
+    let (status, state) = server
+        .get(&format!("/api/game/{}?include_proof=true", dealt))
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", state);
+    assert!(state.get("proof_cid").is_none(), "{}", state);
+    assert!(state.get("proof_pinned_by").is_none(), "{}", state);
+
+    // Still waiting on its VRF: the flag changes nothing about the answer
+    let rpc = MockRpc::fulfilled(random_word());
+    rpc.set("eth_getLogs", json!([]));
+    let server = TestServer::new(rpc, OrchestratorConfig::default());
+    let (_, started) = server
+        .post(
+            "/api/game/start",
+            json!({ "num_players": 2, "cards_per_player": 7 }),
+        )
+        .await;
+    let waiting = started["session_id"].as_str().unwrap();
+    server.wait_for_status(waiting, "WaitingForVRF").await;
+    let plain = server.get(&format!("/api/game/{}", waiting)).await;
+    let flagged = server
+        .get(&format!("/api/game/{}?include_proof=true", waiting))
+        .await;
+    assert_eq!(plain.0, StatusCode::CONFLICT);
+    assert_eq!(flagged, plain);
+}
+
+#[tokio::test(start_paused = true)]
+async fn the_discard_endpoint_respects_the_visibility_policy() {
+    let server = TestServer::new(MockRpc::default(), OrchestratorConfig::default());
+
+    for (visibility, expected) in [
+        ("full", json!(["3G", "0B", "4B"])),
+        ("top_only", json!(["4B"])),
+    ] {
+        let (session_id, _) = server
+            .start_os_game(json!({
+                "num_players": 2,
+                "cards_per_player": 7,
+                "discard_visibility": visibility,
+            }))
+            .await;
+        server
+            .orchestrator
+            .update_game_state(&session_id, |game_state| {
+                game_state.discard_pile = vec![30, 50, 57];
+                Ok(())
+            })
+            .await
+            .unwrap()
+            .unwrap();
+
+        let (status, discard) = server
+            .get(&format!("/api/game/{}/discard?count=3", session_id))
+            .await;
+        assert_eq!(status, StatusCode::OK, "{}", discard);
+        assert_eq!(discard["cards"], expected, "{}", visibility);
+        assert_eq!(discard["pile_size"], 3);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn a_submitted_proof_is_stored_only_if_it_matches_the_deal() {
+    let dead_letter_dir =
+        std::env::temp_dir().join(format!("zunno-api-submissions-{}", std::process::id()));
+    std::fs::remove_dir_all(&dead_letter_dir).ok();
+    let config = OrchestratorConfig {
+        admin_token: Some("admin-secret".to_string()),
+        ipfs_dead_letter_dir: Some(dead_letter_dir.clone()),
+        finalize_max_retries: 0,
+        ..OrchestratorConfig::default()
+    };
+    let sink = MockSink {
+        upload_failures: AtomicUsize::new(1),
+        ..MockSink::default()
+    };
+    let server = TestServer::with_sink(MockRpc::fulfilled(random_word()), config, sink);
+
+    // The upload gives up, leaving a dealt game with no stored proof
+    let (_, started) = server
+        .post(
+            "/api/game/start",
+            json!({ "num_players": 2, "cards_per_player": 7 }),
+        )
+        .await;
+    let session_id = started["session_id"].as_str().unwrap().to_string();
+    server.wait_for_status(&session_id, "Ready").await;
+    std::fs::remove_dir_all(&dead_letter_dir).ok();
+    let game = server
+        .orchestrator
+        .get_game_state(&session_id)
+        .await
+        .unwrap();
+    assert_eq!(game.proof_cid, None);
+
+    let submit = |proof: ProofOutput| {
+        let request = Request::post(format!("/api/game/{}/proof", session_id))
+            .header("content-type", "application/json")
+            .header("x-admin-token", "admin-secret")
+            .body(Body::from(serde_json::to_vec(&proof).unwrap()))
+            .unwrap();
+        server.send(request)
+    };
+    let matching = ProofOutput {
+        proof: format!("0x{}", "ab".repeat(2048)),
+        image_id: IMAGE_ID.to_string(),
+        pub_inputs: game.public_values.clone(),
+        conversion: ProofConversion::default(),
+    };
+
+    let other_deal = ProofInput::builder()
+        .num_players(2)
+        .cards_per_player(7)
+        .seed([0x17; 32])
+        .build()
+        .unwrap();
+    let (status, body) = submit(ProofOutput {
+        pub_inputs: committed_public_values(&other_deal),
+        ..matching.clone()
+    })
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", body);
+    assert_eq!(body["code"], "PROOF_MISMATCH");
+
+    let (status, body) = submit(ProofOutput {
+        image_id: format!("0x{}", "22".repeat(32)),
+        ..matching.clone()
+    })
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", body);
+    assert_eq!(body["code"], "PROOF_MISMATCH");
+
+    // A foreign conversion would pick the proofType zkVerify checks the proof as
+    let (status, body) = submit(ProofOutput {
+        conversion: ProofConversion {
+            proof_type: "risc0".to_string(),
+            ..ProofConversion::default()
+        },
+        ..matching.clone()
+    })
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", body);
+    assert_eq!(body["code"], "PROOF_MISMATCH");
+    assert_eq!(body["details"]["conversion"]["proof_type"], "risc0");
+
+    let (status, body) = server.get(&format!("/api/game/{}/proof", session_id)).await;
+    assert_eq!(status, StatusCode::CONFLICT, "{}", body);
+    assert_eq!(body["code"], "PROOF_NOT_AVAILABLE");
+
+    let (status, body) = submit(matching.clone()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    assert_eq!(body["proof_cid"], format!("bafy-{}", session_id));
+
+    let (status, body) = server.get(&format!("/api/game/{}/proof", session_id)).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+
+    // A second submission is turned away now the game has its proof
+    let (status, body) = submit(matching).await;
+    assert_eq!(status, StatusCode::CONFLICT, "{}", body);
+    assert_eq!(body["code"], "PROOF_ALREADY_STORED");
+}
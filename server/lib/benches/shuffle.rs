@@ -0,0 +1,57 @@
+// lib/benches/shuffle.rs
+//
+// Shuffle + distribute throughput, without proving or IPFS.
+// Run with `cargo bench -p zunnogame-lib`.
+
+use alloy_primitives::U256;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use zunnogame_lib::{perform_shuffle, perform_shuffle_batch, shuffle_into, DECK_SIZE};
+
+const PARAMS: [(u8, u8); 4] = [(2, 7), (4, 7), (6, 10), (10, 10)];
+
+fn bench_shuffle(c: &mut Criterion) {
+    let mut group = c.benchmark_group("shuffle_and_distribute");
+
+    for (num_players, cards_per_player) in PARAMS {
+        let label = format!("{}x{}", num_players, cards_per_player);
+
+        group.bench_with_input(
+            BenchmarkId::new("perform_shuffle", &label),
+            &(num_players, cards_per_player),
+            |b, &(p, c)| {
+                let mut seed = [0u8; 32];
+                b.iter(|| {
+                    seed[0] = seed[0].wrapping_add(1);
+                    black_box(perform_shuffle(p, c, black_box(seed)).unwrap())
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("shuffle_into", &label),
+            &(num_players, cards_per_player),
+            |b, &(p, c)| {
+                let mut seed = [0u8; 32];
+                let mut deck = Vec::with_capacity(DECK_SIZE);
+                b.iter(|| {
+                    seed[0] = seed[0].wrapping_add(1);
+                    black_box(shuffle_into(&mut deck, black_box(seed), p, c).unwrap())
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("perform_shuffle_batch_1000", &label),
+            &(num_players, cards_per_player),
+            |b, &(p, c)| {
+                let seeds: Vec<U256> = (1..=1000u64).map(U256::from).collect();
+                b.iter(|| black_box(perform_shuffle_batch(black_box(&seeds), p, c).unwrap()))
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_shuffle);
+criterion_main!(benches);
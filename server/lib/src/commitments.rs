@@ -0,0 +1,297 @@
+// lib/src/commitments.rs
+//
+// Host-side copies of the commitments the zkVM program builds, so they can be
+// recomputed without running the prover.
+
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    derive_shuffle_seed, perform_shuffle, PublicValuesStruct, SeedDerivation, ShuffleOutcome,
+};
+
+/// Per-player salt: `SHA256(ZUNNO_PLAYER_SALT_V1 || seed || player_id)`
+pub fn player_salt(seed: &[u8; 32], player_id: u8) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"ZUNNO_PLAYER_SALT_V1");
+    hasher.update(seed);
+    hasher.update([player_id]);
+    hasher.finalize().into()
+}
+
+/// Hash of every hand, in player order
+///
+/// Salted: `SHA256(player_salt || cards)`. Unsalted: `SHA256(cards)`, cheaper to verify but
+/// guessable for small hands, so only suitable for public games.
+pub fn hash_player_hands(seed: &[u8; 32], player_hands: &[Vec<u8>], salted: bool) -> Vec<Vec<u8>> {
+    player_hands
+        .iter()
+        .enumerate()
+        .map(|(player_id, player_cards)| {
+            let mut hasher = Sha256::new();
+            if salted {
+                hasher.update(player_salt(seed, player_id as u8));
+            }
+            hasher.update(player_cards);
+            hasher.finalize().to_vec()
+        })
+        .collect()
+}
+
+/// Draw pile commitment: `SHA256(ZUNNO_DRAW_PILE_V1 || seed || draw_pile)`
+pub fn compute_draw_pile_hash(seed: &[u8; 32], draw_pile: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"ZUNNO_DRAW_PILE_V1");
+    hasher.update(seed);
+    hasher.update(draw_pile);
+    hasher.finalize().into()
+}
+
+/// Merkle leaf for one card: `SHA256(ZUNNO_CARD_LEAF_V1 || seed || position_le || card)`
+pub fn card_leaf(seed: &[u8; 32], position: usize, card: u8) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"ZUNNO_CARD_LEAF_V1");
+    hasher.update(seed); // Bind to game seed
+    hasher.update((position as u64).to_le_bytes());
+    hasher.update([card]);
+    hasher.finalize().into()
+}
+
+/// Root over `leaves` with sorted-pair `ZUNNO_MERKLE_NODE_V1` nodes; odd nodes pair with themselves
+pub fn build_merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.len() == 1 {
+        return leaves[0];
+    }
+
+    let mut current_layer = leaves.to_vec();
+
+    while current_layer.len() > 1 {
+        let mut next_layer = Vec::new();
+
+        for chunk in current_layer.chunks(2) {
+            let left = chunk[0];
+            let right = if chunk.len() == 2 { chunk[1] } else { chunk[0] };
+
+            let mut hasher = Sha256::new();
+            hasher.update(b"ZUNNO_MERKLE_NODE_V1");
+            if left <= right {
+                hasher.update(left);
+                hasher.update(right);
+            } else {
+                hasher.update(right);
+                hasher.update(left);
+            }
+
+            next_layer.push(hasher.finalize().into());
+        }
+
+        current_layer = next_layer;
+    }
+
+    current_layer[0]
+}
+
+/// Merkle root over every card of a dealt deck, in deal order
+pub fn compute_merkle_root(seed: &[u8; 32], deck: &[u8]) -> [u8; 32] {
+    let leaves: Vec<[u8; 32]> = deck
+        .iter()
+        .enumerate()
+        .map(|(position, &card)| card_leaf(seed, position, card))
+        .collect();
+    build_merkle_root(&leaves)
+}
+
+/// Public values the program commits for a deal
+///
+/// `seed` is the raw VRF value; `outcome` must be the deal from
+/// `derive_shuffle_seed(seed, instance_salt)`. Deal-only proofs commit a zero root.
+pub fn build_public_values(
+    num_players: u8,
+    cards_per_player: u8,
+    seed: [u8; 32],
+    instance_salt: [u8; 32],
+    outcome: &ShuffleOutcome,
+    salted_hands: bool,
+    build_merkle: bool,
+) -> PublicValuesStruct {
+    let merkle_root = if build_merkle {
+        compute_merkle_root(&seed, &outcome.full_deck())
+    } else {
+        [0u8; 32]
+    };
+
+    PublicValuesStruct {
+        no_of_players: num_players,
+        cards_per_player,
+        initial_hands_hash: hash_player_hands(&seed, &outcome.player_hands, salted_hands)
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+        draw_pile_hash: compute_draw_pile_hash(&seed, &outcome.draw_pile).into(),
+        merkle_root: merkle_root.into(),
+        seed: seed.into(),
+        instance_salt: instance_salt.into(),
+        salted_hands,
+        merkle_built: build_merkle,
+        master_seed: [0u8; 32].into(),
+        seed_context: Default::default(),
+    }
+}
+
+/// Record that `seed` was derived from a master VRF value rather than drawn on its own
+///
+/// Verifiers recompute `seed` as `keccak256(master_seed || seed_context)`; games with their
+/// own VRF request keep a zero `master_seed` and an empty context.
+pub fn commit_seed_derivation(public_values: &mut PublicValuesStruct, derivation: &SeedDerivation) {
+    public_values.master_seed = derivation.master_value.into();
+    public_values.seed_context = derivation.context.clone().into();
+}
+
+/// Deal from `seed` and return the `initial_hands_hash` values the program commits
+pub fn compute_hand_hashes(
+    seed: [u8; 32],
+    instance_salt: [u8; 32],
+    num_players: u8,
+    cards_per_player: u8,
+    salted: bool,
+) -> Result<Vec<Vec<u8>>> {
+    let outcome = perform_shuffle(
+        num_players,
+        cards_per_player,
+        derive_shuffle_seed(seed, instance_salt),
+    )?;
+    Ok(hash_player_hands(&seed, &outcome.player_hands, salted))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+
+    const SEED: [u8; 32] = [0x11; 32];
+
+    fn hands() -> Vec<Vec<u8>> {
+        vec![vec![0, 1, 2], vec![50, 75, 104]]
+    }
+
+    #[test]
+    fn salted_hand_hashes_match_known_bytes() {
+        let hashes = hash_player_hands(&SEED, &hands(), true);
+
+        assert_eq!(
+            hashes.iter().map(hex::encode).collect::<Vec<_>>(),
+            [
+                "011e519a5b3848687a5d43fec5006a96c3a7b564ad9c5bc382d5ca55bc349f4d",
+                "a58312ff5a61c61bb07e608b392734a6cc6ca88f1022be3eeff722c2d3b6773c",
+            ]
+        );
+    }
+
+    #[test]
+    fn unsalted_hand_hashes_are_plain_sha256() {
+        let hashes = hash_player_hands(&SEED, &hands(), false);
+
+        assert_eq!(
+            hashes.iter().map(hex::encode).collect::<Vec<_>>(),
+            [
+                "ae4b3280e56e2faf83f414a6e3dabe9d5fbe18976544c05fed121accb85b53fc",
+                "b54c79fcba6046e18119069b9ca764794d12752614c990b47d3a2ea3492074d8",
+            ]
+        );
+        assert_ne!(hashes, hash_player_hands(&[0x22; 32], &hands(), true));
+    }
+
+    #[test]
+    fn draw_pile_hash_matches_known_bytes() {
+        assert_eq!(
+            hex::encode(compute_draw_pile_hash(&SEED, &[3, 4, 5, 107])),
+            "6ef6920c6af7d38f1d9a5ca30a34ea0880265aee909f08625c6f9185692e100e"
+        );
+        assert_eq!(
+            hex::encode(compute_draw_pile_hash(&SEED, &[])),
+            "1e21d4787c5df950fdbcc54769a7de6909886517e40006e5d52e56494a8648cd"
+        );
+    }
+
+    #[test]
+    fn draw_pile_hash_matches_the_committed_public_values() {
+        let outcome = perform_shuffle(2, 7, derive_shuffle_seed(SEED, [0u8; 32])).unwrap();
+        let public_values = build_public_values(2, 7, SEED, [0u8; 32], &outcome, true, true);
+
+        let computed = compute_draw_pile_hash(&SEED, &outcome.draw_pile);
+
+        assert_eq!(public_values.draw_pile_hash, computed);
+        // Any change to the pile, order included, changes the commitment
+        let mut reordered = outcome.draw_pile.clone();
+        reordered.swap(0, 1);
+        assert_ne!(compute_draw_pile_hash(&SEED, &reordered), computed);
+    }
+
+    #[test]
+    fn host_hand_hashes_match_the_committed_public_values() {
+        let salt = [0x33; 32];
+        let outcome = perform_shuffle(3, 7, derive_shuffle_seed(SEED, salt)).unwrap();
+        let public_values = build_public_values(3, 7, SEED, salt, &outcome, true, false);
+
+        let hashes = compute_hand_hashes(SEED, salt, 3, 7, true).unwrap();
+
+        assert_eq!(hashes.len(), 3);
+        assert_eq!(
+            hashes,
+            public_values
+                .initial_hands_hash
+                .iter()
+                .map(|hash| hash.to_vec())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            hashes,
+            hash_player_hands(&SEED, &outcome.player_hands, true)
+        );
+    }
+
+    #[test]
+    fn both_hashing_modes_commit_their_own_consistent_hashes() {
+        let salt = [0x44; 32];
+        let outcome = perform_shuffle(4, 7, derive_shuffle_seed(SEED, salt)).unwrap();
+
+        let salted = build_public_values(4, 7, SEED, salt, &outcome, true, false);
+        let plain = build_public_values(4, 7, SEED, salt, &outcome, false, false);
+
+        assert!(salted.salted_hands);
+        assert!(!plain.salted_hands);
+        for (mode, public_values) in [(true, &salted), (false, &plain)] {
+            let recomputed = compute_hand_hashes(SEED, salt, 4, 7, mode).unwrap();
+            let committed: Vec<Vec<u8>> = public_values
+                .initial_hands_hash
+                .iter()
+                .map(|hash| hash.to_vec())
+                .collect();
+            assert_eq!(committed, recomputed, "salted_hands = {}", mode);
+        }
+        // Same deal, different commitments, seat by seat
+        for (salted_hash, plain_hash) in salted
+            .initial_hands_hash
+            .iter()
+            .zip(&plain.initial_hands_hash)
+        {
+            assert_ne!(salted_hash, plain_hash);
+        }
+        // Only the hand hashes depend on the mode
+        assert_eq!(salted.draw_pile_hash, plain.draw_pile_hash);
+    }
+
+    #[test]
+    fn unsalted_hashes_ignore_the_seed_but_salted_ones_do_not() {
+        let hands = hands();
+
+        assert_eq!(
+            hash_player_hands(&SEED, &hands, false),
+            hash_player_hands(&[0x22; 32], &hands, false)
+        );
+        assert_ne!(
+            hash_player_hands(&SEED, &hands, true),
+            hash_player_hands(&[0x22; 32], &hands, true)
+        );
+    }
+}
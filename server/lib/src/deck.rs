@@ -0,0 +1,81 @@
+// lib/src/deck.rs
+//
+// The one definition of the deck, shared by the zkVM program and the backend.
+
+/// Cards in a full UNO deck; card ids are `0..DECK_SIZE`
+pub const DECK_SIZE: usize = 108;
+
+// Card ids are stored as `u8`
+const _: () = assert!(DECK_SIZE <= u8::MAX as usize + 1);
+
+/// Card names by deck index; matches the JavaScript frontend's `PACK_OF_CARDS` exactly
+///
+/// Typed with `DECK_SIZE`, so a table of the wrong length fails to compile.
+pub const PACK_OF_CARDS: [&str; DECK_SIZE] = [
+    "0R", "1R", "1R", "2R", "2R", "3R", "3R", "4R", "4R", "5R", "5R", "6R", "6R", "7R", "7R", "8R",
+    "8R", "9R", "9R", "skipR", "skipR", "_R", "_R", "D2R", "D2R", "0G", "1G", "1G", "2G", "2G",
+    "3G", "3G", "4G", "4G", "5G", "5G", "6G", "6G", "7G", "7G", "8G", "8G", "9G", "9G", "skipG",
+    "skipG", "_G", "_G", "D2G", "D2G", "0B", "1B", "1B", "2B", "2B", "3B", "3B", "4B", "4B", "5B",
+    "5B", "6B", "6B", "7B", "7B", "8B", "8B", "9B", "9B", "skipB", "skipB", "_B", "_B", "D2B",
+    "D2B", "0Y", "1Y", "1Y", "2Y", "2Y", "3Y", "3Y", "4Y", "4Y", "5Y", "5Y", "6Y", "6Y", "7Y",
+    "7Y", "8Y", "8Y", "9Y", "9Y", "skipY", "skipY", "_Y", "_Y", "D2Y", "D2Y", "W", "W", "W", "W",
+    "D4W", "D4W", "D4W", "D4W",
+];
+
+/// Reset `deck` to the unshuffled deck `0..DECK_SIZE`
+pub fn fill_deck(deck: &mut Vec<u8>) {
+    deck.clear();
+    deck.extend(0..DECK_SIZE as u8);
+}
+
+/// Unshuffled deck `0..DECK_SIZE`
+pub fn new_deck() -> Vec<u8> {
+    let mut deck = Vec::with_capacity(DECK_SIZE);
+    fill_deck(&mut deck);
+    deck
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_new_deck_holds_every_card_id_in_order() {
+        let deck = new_deck();
+
+        assert_eq!(deck.len(), DECK_SIZE);
+        assert_eq!(deck.len(), PACK_OF_CARDS.len());
+        assert!(deck.iter().enumerate().all(|(i, &card)| card as usize == i));
+    }
+
+    #[test]
+    fn filling_resets_any_buffer() {
+        let mut deck = vec![7, 7, 7];
+        fill_deck(&mut deck);
+        assert_eq!(deck, new_deck());
+
+        deck.reverse();
+        deck.truncate(10);
+        fill_deck(&mut deck);
+        assert_eq!(deck, new_deck());
+    }
+
+    #[test]
+    fn the_card_table_has_a_standard_deck() {
+        let count = |suffix: char| {
+            PACK_OF_CARDS
+                .iter()
+                .filter(|name| name.ends_with(suffix))
+                .count()
+        };
+        for suffix in ['R', 'G', 'B', 'Y'] {
+            assert_eq!(count(suffix), 25, "{}", suffix);
+        }
+        assert_eq!(count('W'), 8);
+        assert_eq!(
+            PACK_OF_CARDS.iter().filter(|name| **name == "D4W").count(),
+            4
+        );
+        assert!(PACK_OF_CARDS.iter().all(|name| !name.is_empty()));
+    }
+}
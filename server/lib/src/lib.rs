@@ -1,3 +1,4 @@
+use alloy_primitives::{keccak256, U256};
 use alloy_sol_types::sol;
 use anyhow::{anyhow, Result};
 use rand::seq::SliceRandom;
@@ -5,6 +6,16 @@ use rand::SeedableRng;
 use rand_chacha::ChaCha20Rng;
 use serde::{Deserialize, Serialize};
 
+pub mod commitments;
+pub mod deck;
+
+pub use commitments::{
+    build_merkle_root, build_public_values, card_leaf, commit_seed_derivation,
+    compute_draw_pile_hash, compute_hand_hashes, compute_merkle_root, hash_player_hands,
+    player_salt,
+};
+pub use deck::{fill_deck, new_deck, DECK_SIZE, PACK_OF_CARDS};
+
 sol! {
     struct PublicValuesStruct {
         uint8 no_of_players;
@@ -13,12 +24,62 @@ sol! {
         bytes32 draw_pile_hash;
         bytes32 merkle_root;
         bytes32 seed;
+        bytes32 instance_salt;
+        bool salted_hands;
+        bool merkle_built;
+        bytes32 master_seed;
+        bytes seed_context;
     }
 }
 
-pub const DECK_SIZE: usize = 108;
+/// Version of the zkVM shuffle program and its public-values layout
+pub const PROGRAM_VERSION: &str = "0.5.0";
+/// Version of the domain-separated commitments (`ZUNNO_*_V1` tags)
+pub const COMMITMENT_VERSION: &str = "v1";
+/// Hash function used for hand, draw pile and Merkle commitments
+pub const COMMITMENT_HASH: &str = "sha256";
+
 pub const MAX_PLAYERS: u8 = 10;
 pub const MAX_CARDS_PER_PLAYER: u8 = 20;
+/// Minimum number of cards that must remain in the draw pile after dealing
+pub const MIN_DRAW_PILE: usize = 1;
+
+/// A single problem with a set of game parameters
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+#[serde(tag = "code", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ValidationIssue {
+    #[error("Invalid number of players: {num_players} (must be 1-{max})")]
+    InvalidPlayerCount { num_players: u8, max: u8 },
+
+    #[error("Invalid cards per player: {cards_per_player} (must be 1-{max})")]
+    InvalidCardsPerPlayer { cards_per_player: u8, max: u8 },
+
+    #[error("Not enough cards: need {needed} but deck has {deck_size}")]
+    DeckOverflow { needed: usize, deck_size: usize },
+
+    #[error("Draw pile too small: {remaining} card(s) left after dealing (minimum {min})")]
+    DrawPileTooSmall { remaining: usize, min: usize },
+}
+
+/// A way a dealt game breaks the deck invariants the zkVM program relies on
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DealViolation {
+    #[error("Dealt deck is not a permutation of 0..{deck_size}")]
+    NotAPermutation { deck_size: usize },
+
+    #[error("Dealt {actual} hand(s) for {expected} player(s)")]
+    HandCount { expected: usize, actual: usize },
+
+    #[error("Player {player} holds {actual} card(s) instead of {expected}")]
+    HandSize {
+        player: usize,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("Draw pile holds {actual} card(s) but its count says {recorded}")]
+    DrawPileCount { recorded: u64, actual: usize },
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShuffleOutcome {
@@ -27,13 +88,156 @@ pub struct ShuffleOutcome {
     pub draw_pile_count: u64,
 }
 
+impl ShuffleOutcome {
+    /// Whole dealt deck in proof order: every hand in player order, then the draw pile
+    pub fn full_deck(&self) -> Vec<u8> {
+        let mut deck = Vec::with_capacity(DECK_SIZE);
+        for hand in &self.player_hands {
+            deck.extend_from_slice(hand);
+        }
+        deck.extend_from_slice(&self.draw_pile);
+        deck
+    }
+
+    /// Check the deal against the parameters it was made for
+    ///
+    /// Hands and draw pile together must hold every card exactly once, with `num_players`
+    /// hands of exactly `cards_per_player` cards. Reports the first violation found.
+    pub fn check_invariants(
+        &self,
+        num_players: u8,
+        cards_per_player: u8,
+    ) -> std::result::Result<(), DealViolation> {
+        if self.player_hands.len() != num_players as usize {
+            return Err(DealViolation::HandCount {
+                expected: num_players as usize,
+                actual: self.player_hands.len(),
+            });
+        }
+
+        if let Some((player, hand)) = self
+            .player_hands
+            .iter()
+            .enumerate()
+            .find(|(_, hand)| hand.len() != cards_per_player as usize)
+        {
+            return Err(DealViolation::HandSize {
+                player,
+                expected: cards_per_player as usize,
+                actual: hand.len(),
+            });
+        }
+
+        if self.draw_pile_count != self.draw_pile.len() as u64 {
+            return Err(DealViolation::DrawPileCount {
+                recorded: self.draw_pile_count,
+                actual: self.draw_pile.len(),
+            });
+        }
+
+        if !is_valid_permutation(&self.full_deck(), DECK_SIZE) {
+            return Err(DealViolation::NotAPermutation {
+                deck_size: DECK_SIZE,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether `deck` holds each card `0..expected_len` exactly once
+///
+/// The single definition of a valid shuffle, used by the zkVM program and host-side checks.
+pub fn is_valid_permutation(deck: &[u8], expected_len: usize) -> bool {
+    if deck.len() != expected_len {
+        return false;
+    }
+
+    let mut seen = vec![false; expected_len];
+    deck.iter().all(|&card| match seen.get_mut(card as usize) {
+        Some(slot) if !*slot => {
+            *slot = true;
+            true
+        }
+        _ => false,
+    })
+}
+
+/// Effective shuffle seed: `keccak256(vrf_value || instance_salt)`
+///
+/// Lets several server instances sharing one VRF contract deal different games from the
+/// same randomness while each instance stays reproducible.
+pub fn derive_shuffle_seed(vrf_value: [u8; 32], instance_salt: [u8; 32]) -> [u8; 32] {
+    let mut preimage = [0u8; 64];
+    preimage[..32].copy_from_slice(&vrf_value);
+    preimage[32..].copy_from_slice(&instance_salt);
+    keccak256(preimage).0
+}
+
+/// Per-game seed drawn from a shared master VRF value: `keccak256(master_value || context)`
+///
+/// One on-chain draw can seed a whole batch of games; a distinct `context` per game (the
+/// backend uses the session id) keeps every seed unique and reproducible.
+pub fn derive_game_seed(master_value: [u8; 32], context: &[u8]) -> [u8; 32] {
+    let mut preimage = Vec::with_capacity(32 + context.len());
+    preimage.extend_from_slice(&master_value);
+    preimage.extend_from_slice(context);
+    keccak256(preimage).0
+}
+
+/// Master value and context a game seed was derived from, committed in public values
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeedDerivation {
+    pub master_value: [u8; 32],
+    pub context: Vec<u8>,
+}
+
+impl SeedDerivation {
+    /// The derived game seed, see `derive_game_seed`
+    pub fn seed(&self) -> [u8; 32] {
+        derive_game_seed(self.master_value, &self.context)
+    }
+}
+
+/// Seed bytes for a `U256` VRF value — the one host/guest conversion
+///
+/// The contract and RPC hand out seeds as `U256`, while the zkVM program reads
+/// `[u8; 32]` and `ChaCha20Rng::from_seed` consumes those bytes as-is. Both sides
+/// must agree on the byte order or they silently deal different games, so the
+/// contract is fixed here: big-endian, matching `bytes32` in Solidity and
+/// `PublicValuesStruct::seed`. Use this rather than calling `to_be_bytes` directly.
+pub fn u256_to_bytes32(value: U256) -> [u8; 32] {
+    let bytes = value.to_be_bytes::<32>();
+    debug_assert_eq!(
+        U256::from_be_bytes(bytes),
+        value,
+        "seed conversion must round-trip"
+    );
+    bytes
+}
+
+/// Inverse of [`u256_to_bytes32`] (big-endian)
+pub fn bytes32_to_u256(bytes: [u8; 32]) -> U256 {
+    U256::from_be_bytes(bytes)
+}
+
 /// Fisher-Yates shuffle
 pub fn shuffle_deck(deck: &mut [u8], seed: [u8; 32]) {
     let mut rng = ChaCha20Rng::from_seed(seed);
     deck.shuffle(&mut rng);
 }
 
+/// Seat that deck position `card_index` is dealt to: round-robin, player 0 first
+///
+/// Every committed hand hash depends on this mapping, so it must never change.
+pub const fn deal_seat(card_index: usize, num_players: usize) -> usize {
+    card_index % num_players
+}
+
 /// Efficient card distribution using iterators
+///
+/// Deals the first `num_players * cards_per_player` cards one at a time around the table
+/// (see `deal_seat`): player `p` gets deck positions `p, p + num_players, ...` in order.
 pub fn distribute_cards(deck: &[u8], num_players: u8, cards_per_player: u8) -> Vec<Vec<u8>> {
     let mut player_hands =
         vec![Vec::with_capacity(cards_per_player as usize); num_players as usize];
@@ -42,49 +246,160 @@ pub fn distribute_cards(deck: &[u8], num_players: u8, cards_per_player: u8) -> V
         .take((num_players as usize) * (cards_per_player as usize))
         .enumerate()
         .for_each(|(i, &card)| {
-            let player_index = i % (num_players as usize);
-            player_hands[player_index].push(card);
+            player_hands[deal_seat(i, num_players as usize)].push(card);
         });
 
     player_hands
 }
 
 /// Validate game parameters
+///
+/// Fails fast: applies the rules of `validate_game_params_all` but reports only the first
+/// issue.
 pub fn validate_game_params(num_players: u8, cards_per_player: u8) -> Result<()> {
+    validate_game_params_all(num_players, cards_per_player).map_err(|issues| {
+        let issue = issues
+            .into_iter()
+            .next()
+            .expect("a failed check has an issue");
+        anyhow!(issue.to_string())
+    })
+}
+
+/// Validate game parameters, collecting every violation instead of stopping at the first
+pub fn validate_game_params_all(
+    num_players: u8,
+    cards_per_player: u8,
+) -> std::result::Result<(), Vec<ValidationIssue>> {
+    let mut issues = Vec::new();
+
     if num_players == 0 || num_players > MAX_PLAYERS {
-        return Err(anyhow!(
-            "Invalid number of players: {} (must be 1-{})",
+        issues.push(ValidationIssue::InvalidPlayerCount {
             num_players,
-            MAX_PLAYERS
-        ));
+            max: MAX_PLAYERS,
+        });
     }
 
     if cards_per_player == 0 || cards_per_player > MAX_CARDS_PER_PLAYER {
-        return Err(anyhow!(
-            "Invalid cards per player: {} (must be 1-{})",
+        issues.push(ValidationIssue::InvalidCardsPerPlayer {
             cards_per_player,
-            MAX_CARDS_PER_PLAYER
-        ));
+            max: MAX_CARDS_PER_PLAYER,
+        });
     }
 
     let total_cards_needed = (num_players as usize) * (cards_per_player as usize);
-    if total_cards_needed >= DECK_SIZE {
-        return Err(anyhow!(
-            "Not enough cards: need {} for {} players with {} cards each (deck has {})",
-            total_cards_needed,
-            num_players,
-            cards_per_player,
-            DECK_SIZE
-        ));
+    if total_cards_needed > DECK_SIZE {
+        issues.push(ValidationIssue::DeckOverflow {
+            needed: total_cards_needed,
+            deck_size: DECK_SIZE,
+        });
+    } else if DECK_SIZE - total_cards_needed < MIN_DRAW_PILE {
+        issues.push(ValidationIssue::DrawPileTooSmall {
+            remaining: DECK_SIZE - total_cards_needed,
+            min: MIN_DRAW_PILE,
+        });
+    }
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(issues)
     }
+}
+
+/// Minimal dealt game: hands and draw pile, free of any backend or chain types
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameStateCore {
+    pub player_hands: Vec<Vec<u8>>,
+    pub draw_pile: Vec<u8>,
+    /// Seed the deck was shuffled with, after mixing in the instance salt
+    pub shuffle_seed: [u8; 32],
+}
 
-    Ok(())
+/// Deal a game from a VRF value exactly as a server with no `INSTANCE_SALT` would
+pub fn deal_game(num_players: u8, cards_per_player: u8, seed: U256) -> Result<GameStateCore> {
+    deal_game_salted(num_players, cards_per_player, seed, [0u8; 32])
+}
+
+/// Deal a game from a VRF value and an instance salt, matching the backend's finalization
+pub fn deal_game_salted(
+    num_players: u8,
+    cards_per_player: u8,
+    seed: U256,
+    instance_salt: [u8; 32],
+) -> Result<GameStateCore> {
+    let shuffle_seed = derive_shuffle_seed(u256_to_bytes32(seed), instance_salt);
+    let outcome = perform_shuffle(num_players, cards_per_player, shuffle_seed)?;
+
+    Ok(GameStateCore {
+        player_hands: outcome.player_hands,
+        draw_pile: outcome.draw_pile,
+        shuffle_seed,
+    })
 }
 
 pub fn perform_shuffle(
     num_players: u8,
     cards_per_player: u8,
     seed: [u8; 32],
+) -> Result<ShuffleOutcome> {
+    let mut deck = Vec::with_capacity(DECK_SIZE);
+    shuffle_into(&mut deck, seed, num_players, cards_per_player)
+}
+
+/// Shuffle one deck per seed, reusing the deck buffer across deals
+///
+/// Seeds are used as-is (big-endian bytes, no instance salt). With the `parallel` feature
+/// the batch is split across rayon threads, each with its own buffer; results keep the
+/// order of `seeds` either way.
+pub fn perform_shuffle_batch(
+    seeds: &[U256],
+    num_players: u8,
+    cards_per_player: u8,
+) -> Result<Vec<ShuffleOutcome>> {
+    validate_game_params(num_players, cards_per_player)?;
+
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+
+        seeds
+            .par_iter()
+            .map_init(
+                || Vec::with_capacity(DECK_SIZE),
+                |deck, &seed| {
+                    shuffle_into(deck, u256_to_bytes32(seed), num_players, cards_per_player)
+                },
+            )
+            .collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        let mut deck = Vec::with_capacity(DECK_SIZE);
+        seeds
+            .iter()
+            .map(|&seed| {
+                shuffle_into(
+                    &mut deck,
+                    u256_to_bytes32(seed),
+                    num_players,
+                    cards_per_player,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Same as `perform_shuffle`, but builds the deck in a caller-provided buffer
+///
+/// The buffer is cleared and refilled on each call, so repeated shuffles
+/// (benchmarks, simulations) don't re-allocate the deck.
+pub fn shuffle_into(
+    deck: &mut Vec<u8>,
+    seed: [u8; 32],
+    num_players: u8,
+    cards_per_player: u8,
 ) -> Result<ShuffleOutcome> {
     match validate_game_params(num_players, cards_per_player) {
         Ok(_) => (),
@@ -92,13 +407,13 @@ pub fn perform_shuffle(
     }
 
     // Create deck
-    let mut deck: Vec<u8> = (0..DECK_SIZE as u8).collect();
+    fill_deck(deck);
 
     //shuffle deck
-    shuffle_deck(&mut deck, seed);
+    shuffle_deck(deck, seed);
 
     // Distribute cards
-    let player_hands = distribute_cards(&deck, num_players, cards_per_player);
+    let player_hands = distribute_cards(deck, num_players, cards_per_player);
 
     // Create draw pile from remaining cards
     let total_cards_needed = (num_players as usize) * (cards_per_player as usize);
@@ -115,3 +430,400 @@ pub fn perform_shuffle(
 
     Ok(outcome)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seed(byte: u8) -> [u8; 32] {
+        [byte; 32]
+    }
+
+    fn assert_same_deal(actual: &ShuffleOutcome, expected: &ShuffleOutcome) {
+        assert_eq!(actual.player_hands, expected.player_hands);
+        assert_eq!(actual.draw_pile, expected.draw_pile);
+        assert_eq!(actual.draw_pile_count, expected.draw_pile_count);
+    }
+
+    #[test]
+    fn reused_buffer_deals_like_a_fresh_deck() {
+        let mut deck = Vec::new();
+
+        // Each deal leaves the buffer shuffled, and the next one must not depend on it
+        for (byte, num_players, cards_per_player) in [(1, 2, 7), (2, 4, 7), (3, 10, 10), (4, 1, 20)]
+        {
+            let reused =
+                shuffle_into(&mut deck, seed(byte), num_players, cards_per_player).unwrap();
+            let fresh = perform_shuffle(num_players, cards_per_player, seed(byte)).unwrap();
+
+            assert_same_deal(&reused, &fresh);
+            assert_eq!(deck.len(), DECK_SIZE);
+        }
+    }
+
+    #[test]
+    fn instance_salts_change_the_deal() {
+        let vrf_value = U256::from(0x5eed_u64);
+
+        let plain = deal_game_salted(4, 7, vrf_value, [0u8; 32]).unwrap();
+        let salted = deal_game_salted(4, 7, vrf_value, [1u8; 32]).unwrap();
+        let other = deal_game_salted(4, 7, vrf_value, [2u8; 32]).unwrap();
+
+        assert_ne!(salted.shuffle_seed, plain.shuffle_seed);
+        assert_ne!(salted.player_hands, plain.player_hands);
+        assert_ne!(salted.player_hands, other.player_hands);
+    }
+
+    #[test]
+    fn the_same_salt_deals_the_same_game() {
+        let vrf_value = U256::from(0x5eed_u64);
+        let salt = [7u8; 32];
+
+        let first = deal_game_salted(4, 7, vrf_value, salt).unwrap();
+        let second = deal_game_salted(4, 7, vrf_value, salt).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(
+            first.shuffle_seed,
+            derive_shuffle_seed(u256_to_bytes32(vrf_value), salt)
+        );
+        // No salt means the plain `deal_game` deal
+        assert_eq!(
+            deal_game(4, 7, vrf_value).unwrap(),
+            deal_game_salted(4, 7, vrf_value, [0u8; 32]).unwrap()
+        );
+    }
+
+    #[test]
+    fn validation_reports_every_broken_rule() {
+        assert_eq!(
+            validate_game_params_all(0, 21),
+            Err(vec![
+                ValidationIssue::InvalidPlayerCount {
+                    num_players: 0,
+                    max: MAX_PLAYERS
+                },
+                ValidationIssue::InvalidCardsPerPlayer {
+                    cards_per_player: 21,
+                    max: MAX_CARDS_PER_PLAYER
+                },
+            ])
+        );
+        assert_eq!(
+            validate_game_params_all(11, 20),
+            Err(vec![
+                ValidationIssue::InvalidPlayerCount {
+                    num_players: 11,
+                    max: MAX_PLAYERS
+                },
+                ValidationIssue::DeckOverflow {
+                    needed: 220,
+                    deck_size: DECK_SIZE
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn dealing_the_whole_deck_leaves_no_draw_pile() {
+        assert_eq!(
+            validate_game_params_all(6, 18),
+            Err(vec![ValidationIssue::DrawPileTooSmall {
+                remaining: 0,
+                min: MIN_DRAW_PILE
+            }])
+        );
+        assert!(validate_game_params(9, 12).is_err());
+        assert_eq!(validate_game_params_all(10, 10), Ok(()));
+        assert!(validate_game_params(1, MAX_CARDS_PER_PLAYER).is_ok());
+    }
+
+    #[test]
+    fn valid_params_leave_at_least_one_card_to_draw() {
+        for num_players in 0..=MAX_PLAYERS + 1 {
+            for cards_per_player in 0..=MAX_CARDS_PER_PLAYER + 1 {
+                let dealt = num_players as usize * cards_per_player as usize;
+                let expected = (1..=MAX_PLAYERS).contains(&num_players)
+                    && (1..=MAX_CARDS_PER_PLAYER).contains(&cards_per_player)
+                    && dealt + MIN_DRAW_PILE <= DECK_SIZE;
+                assert_eq!(
+                    validate_game_params(num_players, cards_per_player).is_ok(),
+                    expected,
+                    "{} players, {} cards",
+                    num_players,
+                    cards_per_player
+                );
+            }
+        }
+
+        // Only the first of the two issues is reported
+        let message = validate_game_params(0, 21).unwrap_err().to_string();
+        assert_eq!(message, "Invalid number of players: 0 (must be 1-10)");
+        let message = validate_game_params(2, 21).unwrap_err().to_string();
+        assert_eq!(message, "Invalid cards per player: 21 (must be 1-20)");
+    }
+
+    #[test]
+    fn reused_buffer_rejects_invalid_params() {
+        let mut deck = Vec::new();
+
+        assert!(shuffle_into(&mut deck, seed(1), 0, 7).is_err());
+        assert!(shuffle_into(&mut deck, seed(1), 2, MAX_CARDS_PER_PLAYER + 1).is_err());
+    }
+
+    #[test]
+    fn every_card_once_is_a_valid_permutation() {
+        let ordered: Vec<u8> = (0..DECK_SIZE as u8).collect();
+        let mut reversed = ordered.clone();
+        reversed.reverse();
+
+        assert!(is_valid_permutation(&ordered, DECK_SIZE));
+        assert!(is_valid_permutation(&reversed, DECK_SIZE));
+        assert!(is_valid_permutation(
+            &perform_shuffle(4, 7, seed(9)).unwrap().full_deck(),
+            DECK_SIZE
+        ));
+    }
+
+    #[test]
+    fn a_duplicate_card_is_not_a_permutation() {
+        let mut deck: Vec<u8> = (0..DECK_SIZE as u8).collect();
+        // 107 goes missing as 0 appears twice, keeping the length right
+        deck[107] = 0;
+
+        assert!(!is_valid_permutation(&deck, DECK_SIZE));
+    }
+
+    #[test]
+    fn a_missing_or_out_of_range_card_is_not_a_permutation() {
+        let mut deck: Vec<u8> = (0..DECK_SIZE as u8).collect();
+        deck[50] = DECK_SIZE as u8;
+
+        assert!(!is_valid_permutation(&deck, DECK_SIZE));
+        assert!(!is_valid_permutation(&deck[..DECK_SIZE - 1], DECK_SIZE - 1));
+    }
+
+    #[test]
+    fn a_wrong_length_deck_is_not_a_permutation() {
+        let deck: Vec<u8> = (0..DECK_SIZE as u8).collect();
+        let mut longer = deck.clone();
+        longer.push(0);
+
+        assert!(!is_valid_permutation(&deck[..DECK_SIZE - 1], DECK_SIZE));
+        assert!(!is_valid_permutation(&longer, DECK_SIZE));
+        assert!(!is_valid_permutation(&[], DECK_SIZE));
+        assert!(is_valid_permutation(&[], 0));
+    }
+
+    #[test]
+    fn seed_bytes_round_trip_through_u256() {
+        let arbitrary = U256::from_be_bytes(std::array::from_fn::<u8, 32, _>(|i| i as u8 * 7));
+
+        for value in [U256::ZERO, U256::from(1u8), U256::MAX, arbitrary] {
+            assert_eq!(bytes32_to_u256(u256_to_bytes32(value)), value);
+        }
+    }
+
+    #[test]
+    fn seed_bytes_are_big_endian() {
+        let bytes = u256_to_bytes32(U256::from(1u8));
+
+        assert_eq!(bytes[31], 1);
+        assert_eq!(bytes[..31], [0u8; 31]);
+        assert_eq!(u256_to_bytes32(U256::from(0x0102u16))[30..], [1, 2]);
+    }
+
+    #[test]
+    fn batch_deals_match_individual_shuffles() {
+        let seeds: Vec<U256> = (0u8..20)
+            .map(|byte| U256::from_be_bytes(seed(byte.wrapping_mul(37))))
+            .chain([U256::ZERO, U256::MAX])
+            .collect();
+
+        for (num_players, cards_per_player) in [(2, 7), (4, 7), (10, 10)] {
+            let batch = perform_shuffle_batch(&seeds, num_players, cards_per_player).unwrap();
+
+            assert_eq!(batch.len(), seeds.len());
+            for (outcome, &seed) in batch.iter().zip(&seeds) {
+                let single =
+                    perform_shuffle(num_players, cards_per_player, u256_to_bytes32(seed)).unwrap();
+                assert_same_deal(outcome, &single);
+            }
+        }
+    }
+
+    #[test]
+    fn batch_shuffles_validate_params_even_without_seeds() {
+        assert!(perform_shuffle_batch(&[], 2, 7).unwrap().is_empty());
+        assert!(perform_shuffle_batch(&[], 0, 7).is_err());
+        assert!(perform_shuffle_batch(&[U256::from(1u8)], 11, 10).is_err());
+    }
+
+    // The deal order below is part of every committed hand hash; these must never change
+
+    #[test]
+    fn deal_seats_go_round_robin_from_player_zero() {
+        let seats = |num_players| {
+            (0..12)
+                .map(|i| deal_seat(i, num_players))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(seats(1), [0; 12]);
+        assert_eq!(seats(2), [0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1]);
+        assert_eq!(seats(3), [0, 1, 2, 0, 1, 2, 0, 1, 2, 0, 1, 2]);
+        assert_eq!(seats(4), [0, 1, 2, 3, 0, 1, 2, 3, 0, 1, 2, 3]);
+        assert_eq!(seats(10), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1]);
+    }
+
+    #[test]
+    fn an_ordered_deck_is_dealt_to_exact_hands() {
+        let deck = new_deck();
+
+        assert_eq!(distribute_cards(&deck, 1, 5), [vec![0, 1, 2, 3, 4]]);
+        assert_eq!(
+            distribute_cards(&deck, 2, 3),
+            [vec![0, 2, 4], vec![1, 3, 5]]
+        );
+        assert_eq!(
+            distribute_cards(&deck, 3, 2),
+            [vec![0, 3], vec![1, 4], vec![2, 5]]
+        );
+        assert_eq!(
+            distribute_cards(&deck, 4, 7),
+            [
+                vec![0, 4, 8, 12, 16, 20, 24],
+                vec![1, 5, 9, 13, 17, 21, 25],
+                vec![2, 6, 10, 14, 18, 22, 26],
+                vec![3, 7, 11, 15, 19, 23, 27],
+            ]
+        );
+        let ten = distribute_cards(&deck, 10, 10);
+        assert_eq!(ten[0], [0, 10, 20, 30, 40, 50, 60, 70, 80, 90]);
+        assert_eq!(ten[9], [9, 19, 29, 39, 49, 59, 69, 79, 89, 99]);
+    }
+
+    #[test]
+    fn hands_follow_deck_positions_not_card_values() {
+        let reversed: Vec<u8> = new_deck().into_iter().rev().collect();
+
+        assert_eq!(
+            distribute_cards(&reversed, 3, 3),
+            [vec![107, 104, 101], vec![106, 103, 100], vec![105, 102, 99]]
+        );
+        // Cards past the dealt ones stay out of every hand
+        assert_eq!(
+            distribute_cards(&[5, 6, 7, 8, 9], 2, 2),
+            [vec![5, 7], vec![6, 8]]
+        );
+    }
+
+    #[test]
+    fn game_seeds_hash_the_master_value_and_context() {
+        let mut preimage = seed(0x3c).to_vec();
+        preimage.extend_from_slice(b"session-a");
+
+        assert_eq!(
+            derive_game_seed(seed(0x3c), b"session-a"),
+            keccak256(preimage).0
+        );
+    }
+
+    #[test]
+    fn each_context_draws_its_own_reproducible_seed() {
+        let first = derive_game_seed(seed(0x3c), b"session-a");
+
+        assert_eq!(derive_game_seed(seed(0x3c), b"session-a"), first);
+        assert_ne!(derive_game_seed(seed(0x3c), b"session-b"), first);
+        assert_ne!(derive_game_seed(seed(0x3d), b"session-a"), first);
+        assert_ne!(first, seed(0x3c));
+
+        let derivation = SeedDerivation {
+            master_value: seed(0x3c),
+            context: b"session-a".to_vec(),
+        };
+        assert_eq!(derivation.seed(), first);
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Player and card counts the validator accepts
+    fn game_params() -> impl Strategy<Value = (u8, u8)> {
+        (1..=MAX_PLAYERS, 1..=MAX_CARDS_PER_PLAYER).prop_filter(
+            "parameters must validate",
+            |&(num_players, cards_per_player)| {
+                validate_game_params(num_players, cards_per_player).is_ok()
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn every_deal_holds_its_invariants(
+            (num_players, cards_per_player) in game_params(),
+            seed in any::<[u8; 32]>(),
+        ) {
+            let outcome = perform_shuffle(num_players, cards_per_player, seed).unwrap();
+
+            prop_assert_eq!(outcome.check_invariants(num_players, cards_per_player), Ok(()));
+            prop_assert_eq!(
+                outcome.draw_pile.len(),
+                DECK_SIZE - num_players as usize * cards_per_player as usize
+            );
+        }
+
+        #[test]
+        fn the_same_seed_always_deals_the_same(
+            (num_players, cards_per_player) in game_params(),
+            seed in any::<[u8; 32]>(),
+        ) {
+            let first = perform_shuffle(num_players, cards_per_player, seed).unwrap();
+            let second = perform_shuffle(num_players, cards_per_player, seed).unwrap();
+
+            prop_assert_eq!(first.player_hands, second.player_hands);
+            prop_assert_eq!(first.draw_pile, second.draw_pile);
+        }
+
+        #[test]
+        fn a_duplicated_card_breaks_the_permutation(
+            (num_players, cards_per_player) in game_params(),
+            seed in any::<[u8; 32]>(),
+        ) {
+            let mut outcome = perform_shuffle(num_players, cards_per_player, seed).unwrap();
+            outcome.player_hands[0][0] = outcome.draw_pile[0];
+
+            prop_assert_eq!(
+                outcome.check_invariants(num_players, cards_per_player),
+                Err(DealViolation::NotAPermutation { deck_size: DECK_SIZE })
+            );
+        }
+
+        #[test]
+        fn a_deal_fails_the_check_for_other_parameters(
+            (num_players, cards_per_player) in game_params(),
+            seed in any::<[u8; 32]>(),
+        ) {
+            let outcome = perform_shuffle(num_players, cards_per_player, seed).unwrap();
+
+            prop_assert_eq!(
+                outcome.check_invariants(num_players + 1, cards_per_player),
+                Err(DealViolation::HandCount {
+                    expected: num_players as usize + 1,
+                    actual: num_players as usize,
+                })
+            );
+            prop_assert_eq!(
+                outcome.check_invariants(num_players, cards_per_player + 1),
+                Err(DealViolation::HandSize {
+                    player: 0,
+                    expected: cards_per_player as usize + 1,
+                    actual: cards_per_player as usize,
+                })
+            );
+        }
+    }
+}